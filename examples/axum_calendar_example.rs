@@ -18,7 +18,7 @@ use google_workspace_apis::calendar::{
     prelude::*,
 };
 use std::{collections::HashMap, sync::Arc};
-use tokio::sync::Mutex;
+use tokio::sync::RwLock;
 
 use axum::{
     extract::{Query, State},
@@ -35,14 +35,16 @@ use reqwest::StatusCode;
 
 #[derive(Clone)]
 pub struct AppState {
-    pub google_client: Arc<Mutex<Option<GoogleClient>>>,
+    // The client is only set after the OAuth redirect, the lock is just held to clone it.
+    // GoogleClient itself is cheap to clone and shares its token between clones.
+    pub google_client: Arc<RwLock<Option<GoogleClient>>>,
 }
 
 #[tokio::main]
 async fn main() {
     // We use this to reuse the same client over multiple requests
     let state = AppState {
-        google_client: Arc::new(Mutex::new(None)),
+        google_client: Arc::new(RwLock::new(None)),
     };
     let app = Router::new()
         .route("/", axum::routing::get(|| async { "Hello, World!" }))
@@ -118,16 +120,14 @@ pub async fn handle_google_oauth_redirect(
     // The last parameter ditctates wether the client will refresh the token automatically using
     // the refresh token if it expires
     let new_client = GoogleClient::new(client_credentials, access_token, true);
-    let mut guard = state.google_client.lock().await;
-    *guard = Some(new_client);
+    *state.google_client.write().await = Some(new_client);
     StatusCode::OK
 }
 
 async fn get_calendar_events(State(state): State<AppState>) -> Json<Vec<Event>> {
-    // Create the request builder and immediately drop the lock
-    let mut google_client_guard = state.google_client.lock().await;
-    let client = google_client_guard.as_mut().unwrap();
-    let events = CalendarEventsClient::new(client)
+    // Clone the client so the lock isn't held during the request
+    let client = state.google_client.read().await.clone().unwrap();
+    let events = CalendarEventsClient::new(&client)
         .get_events("primary")
         .single_events(true)
         .max_results(10)
@@ -141,19 +141,12 @@ async fn get_calendar_events(State(state): State<AppState>) -> Json<Vec<Event>>
 }
 
 async fn insert_calendar_event(State(state): State<AppState>) {
-    let mut google_client_guard = state.google_client.lock().await;
-    let client = google_client_guard.as_mut().unwrap();
-    let start: EventDateTime = EventDateTime {
-        date: Some("2025-07-28".to_string()),
-        date_time: None,
-        time_zone: None,
-    };
-    let end: EventDateTime = EventDateTime {
-        date: Some("2025-07-28".to_string()),
-        date_time: None,
-        time_zone: None,
-    };
-    CalendarEventsClient::new(client)
+    let client = state.google_client.read().await.clone().unwrap();
+    let day = chrono::NaiveDate::from_ymd_opt(2025, 7, 28).unwrap();
+    let start = EventDateTime::all_day(day);
+    // The end date of an all-day event is exclusive
+    let end = EventDateTime::all_day(day.succ_opt().unwrap());
+    CalendarEventsClient::new(&client)
         .insert_event("primary", start, end)
         .set_summary("test_insert")
         .request()
@@ -162,9 +155,8 @@ async fn insert_calendar_event(State(state): State<AppState>) {
 }
 
 async fn update_calendar_event(State(state): State<AppState>) {
-    let mut google_client_guard = state.google_client.lock().await;
-    let client = google_client_guard.as_mut().unwrap();
-    CalendarEventsClient::new(client)
+    let client = state.google_client.read().await.clone().unwrap();
+    CalendarEventsClient::new(&client)
         .patch_event("primary", "3is75fqkn1uhcg5nu4bevdkfpe")
         .set_summary("Gaat niet door")
         .set_description("bah")
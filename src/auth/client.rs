@@ -1,11 +1,22 @@
-use std::{str::FromStr, sync::Arc};
+use std::{
+    str::FromStr,
+    sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
 
-use anyhow::Error;
 use chrono::{DateTime, Utc};
+use reqwest::header::{HeaderValue, AUTHORIZATION};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use super::refresh_acces_token;
+use super::{
+    refresh_acces_token_at,
+    service_account::{get_service_account_token, ServiceAccountCredentials},
+    token_store::TokenStore,
+};
+use crate::{
+    error::GoogleApiError,
+    utils::{endpoints::ApiEndpoints, retry::RetryPolicy},
+};
 
 #[derive(Debug, JsonSchema, Clone, Default, Serialize, Deserialize)]
 pub struct AccessToken {
@@ -93,30 +104,65 @@ pub struct ClientCredentials {
     pub refresh_token: String,
 }
 
+/// Client shared by all API request builders.
+///
+/// The client is cheap to clone and `Send + Sync`: clones share the same token, so a single
+/// client can serve many concurrent requests, e.g. stored directly in the state of a web server.
+/// When the token expires only one request refreshes it, concurrent requests wait for that
+/// refresh instead of starting their own.
+///
+/// Configuration (`endpoints`, `retry_policy`, token store and refresh handlers) belongs to each
+/// clone, set it up before sharing the client.
 #[derive(Clone, Default)]
 pub struct GoogleClient {
-    pub client_credentials: ClientCredentials,
-    pub access_token: Option<ClientTokenData>,
     pub req_client: reqwest::Client,
     pub auto_refresh_token: bool,
+    pub retry_policy: RetryPolicy,
+    pub endpoints: ApiEndpoints,
+    /// Set for clients created with `from_service_account`, tokens are minted with a signed JWT
+    /// instead of a refresh token.
+    pub service_account: Option<ServiceAccountCredentials>,
+    state: Arc<TokenState>,
     refresh_handlers: Vec<Arc<dyn TokenRefreshHandler>>,
+    token_store: Option<BoundTokenStore>,
+}
+
+/// Token state shared between clones of a `GoogleClient`.
+#[derive(Default)]
+struct TokenState {
+    credentials: RwLock<ClientCredentials>,
+    token: RwLock<Option<ClientTokenData>>,
+    /// Held while refreshing, so concurrent requests don't all refresh the same token.
+    refresh: tokio::sync::Mutex<()>,
+}
+
+/// A token store together with the key the client's token is stored under.
+#[derive(Clone)]
+struct BoundTokenStore {
+    store: Arc<dyn TokenStore>,
+    key: String,
 }
 
 impl std::fmt::Debug for GoogleClient {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let token = self.access_token();
         f.debug_struct("GoogleClient")
-            .field("access_token", &self.access_token)
+            .field("access_token", &"[REDACTED]")
             .field("refresh_token", &"[REDACTED]")
-            .field(
-                "token_expiry",
-                &self.access_token.as_ref().unwrap().expires_on,
-            )
-            .field("client_id", &self.client_credentials.client_id)
+            .field("token_expiry", &token.map(|t| t.expires_on))
+            .field("client_id", &self.client_credentials().client_id)
             .field("client_secret", &"[REDACTED]")
+            .field("service_account", &self.service_account)
+            .field("retry_policy", &self.retry_policy)
+            .field("endpoints", &self.endpoints)
             .field(
                 "refresh_handlers",
                 &format!("[{} handlers]", self.refresh_handlers.len()),
             )
+            .field(
+                "token_store_key",
+                &self.token_store.as_ref().map(|bound| &bound.key),
+            )
             .finish()
     }
 }
@@ -139,13 +185,98 @@ impl GoogleClient {
         access_token: AccessToken,
         auto_refresh_token: bool,
     ) -> Self {
-        let client = build_default_reqwest_client(&access_token.access_token);
-        Self {
+        Self::with_token(
             client_credentials,
-            access_token: Some(access_token.into()),
-            req_client: client,
+            Some(access_token.into()),
             auto_refresh_token,
+        )
+    }
+
+    fn with_token(
+        client_credentials: ClientCredentials,
+        token: Option<ClientTokenData>,
+        auto_refresh_token: bool,
+    ) -> Self {
+        Self {
+            req_client: build_default_reqwest_client(),
+            auto_refresh_token,
+            retry_policy: RetryPolicy::default(),
+            endpoints: ApiEndpoints::default(),
+            service_account: None,
+            state: Arc::new(TokenState {
+                credentials: RwLock::new(client_credentials),
+                token: RwLock::new(token),
+                refresh: tokio::sync::Mutex::new(()),
+            }),
             refresh_handlers: Vec::new(),
+            token_store: None,
+        }
+    }
+
+    /// Creates a client from the token stored under `key`, or returns `None` if nothing is
+    /// stored yet and the user has to sign in first.
+    ///
+    /// The stored refresh token is used for `client_credentials`, an expired access token is
+    /// refreshed on the first request and every new token is saved back to the store.
+    pub fn from_token_store(
+        mut client_credentials: ClientCredentials,
+        store: Arc<dyn TokenStore>,
+        key: &str,
+    ) -> Result<Option<Self>, GoogleApiError> {
+        let Some(token) = store.load(key)? else {
+            return Ok(None);
+        };
+        if !token.refresh_token.is_empty() {
+            client_credentials.refresh_token = token.refresh_token.clone();
+        }
+        let mut client = Self::with_token(client_credentials, Some(token), true);
+        client.set_token_store(store, key);
+        Ok(Some(client))
+    }
+
+    /// Creates a client that authenticates as a service account.
+    ///
+    /// No request is made here: the first API request signs a JWT and exchanges it for an
+    /// access token, which is re-minted the same way whenever it expires.
+    /// The key's `token_uri` is used as token endpoint when present.
+    pub fn from_service_account(credentials: ServiceAccountCredentials) -> Self {
+        let client_credentials = ClientCredentials {
+            client_id: credentials.key.client_id.clone(),
+            ..Default::default()
+        };
+        let mut client = Self::with_token(client_credentials, None, true);
+        if !credentials.key.token_uri.is_empty() {
+            client.endpoints.oauth_token = credentials.key.token_uri.clone();
+        }
+        client.service_account = Some(credentials);
+        client
+    }
+
+    /// Replaces the base URLs of the APIs and the OAuth token endpoint used by this client.
+    pub fn set_endpoints(&mut self, endpoints: ApiEndpoints) {
+        self.endpoints = endpoints;
+    }
+
+    /// Replaces the retry policy used for every request sent with this client.
+    /// Use `RetryPolicy::none()` to disable retries.
+    pub fn set_retry_policy(&mut self, policy: RetryPolicy) {
+        self.retry_policy = policy;
+    }
+
+    /// Saves every new token to `store` under `key` from now on.
+    /// Call `persist_token` to also save the current token.
+    pub fn set_token_store(&mut self, store: Arc<dyn TokenStore>, key: &str) {
+        self.token_store = Some(BoundTokenStore {
+            store,
+            key: key.to_string(),
+        });
+    }
+
+    /// Saves the current token to the token store, if one is set.
+    pub fn persist_token(&self) -> Result<(), GoogleApiError> {
+        match (&self.token_store, self.access_token()) {
+            (Some(bound), Some(token)) => bound.store.save(&bound.key, &token),
+            _ => Ok(()),
         }
     }
 
@@ -156,9 +287,28 @@ impl GoogleClient {
         self.refresh_handlers.push(Arc::new(handler));
     }
 
-    pub async fn refresh_access_token_check(&mut self) -> Result<(), Error> {
+    /// The current access token, `None` for a service account that didn't mint one yet.
+    pub fn access_token(&self) -> Option<ClientTokenData> {
+        read(&self.state.token).clone()
+    }
+
+    /// Replaces the access token of this client and all its clones.
+    pub fn set_access_token(&self, token: ClientTokenData) {
+        *write(&self.state.token) = Some(token);
+    }
+
+    /// The OAuth client credentials, including the latest refresh token.
+    pub fn client_credentials(&self) -> ClientCredentials {
+        read(&self.state.credentials).clone()
+    }
+
+    pub async fn refresh_access_token_check(&self) -> Result<(), GoogleApiError> {
         if self.auto_refresh_token && !self.is_access_token_valid() {
-            self.update_access_token().await?;
+            let _refreshing = self.state.refresh.lock().await;
+            // Another request may have refreshed the token while we were waiting
+            if !self.is_access_token_valid() {
+                self.refresh_token().await?;
+            }
         }
         Ok(())
     }
@@ -172,30 +322,109 @@ impl GoogleClient {
     }
 
     pub fn is_access_token_valid(&self) -> bool {
-        if let Some(token_data) = &self.access_token {
+        if let Some(token_data) = read(&self.state.token).as_ref() {
             let now = chrono::Utc::now();
             return now < token_data.expires_on;
         }
         false
     }
 
-    pub async fn update_access_token(&mut self) -> Result<(), Error> {
-        let new_token = refresh_acces_token(&self.client_credentials).await?;
-        self.access_token = Some(new_token.clone().into());
-        let client = build_default_reqwest_client(&new_token.access_token);
-        self.req_client = client;
+    /// Gets a new access token, by signing a new JWT for service accounts or with the refresh
+    /// token otherwise.
+    ///
+    /// A rotated refresh token replaces the one in `client_credentials`. The new token is saved to
+    /// the token store if one is set, a failure to save is returned as
+    /// `GoogleApiError::Storage` after the client itself was updated.
+    pub async fn update_access_token(&self) -> Result<(), GoogleApiError> {
+        let _refreshing = self.state.refresh.lock().await;
+        self.refresh_token().await
+    }
+
+    /// Refreshes the token, the caller has to hold the `refresh` lock.
+    async fn refresh_token(&self) -> Result<(), GoogleApiError> {
+        let new_token = match &self.service_account {
+            Some(credentials) => {
+                get_service_account_token(&self.endpoints.oauth_token, credentials).await?
+            }
+            None => {
+                let credentials = self.client_credentials();
+                refresh_acces_token_at(&self.endpoints.oauth_token, &credentials).await?
+            }
+        };
+        if !new_token.refresh_token.is_empty() {
+            write(&self.state.credentials).refresh_token = new_token.refresh_token.clone();
+        }
+        let token_data = ClientTokenData::from(new_token);
+        self.set_access_token(token_data.clone());
 
-        for handler in &mut self.refresh_handlers {
+        for handler in &self.refresh_handlers {
             handler.on_token_refresh(
-                self.access_token.as_ref().unwrap().access_token.clone(),
-                self.access_token.as_ref().unwrap().refresh_token.clone(),
-                self.access_token.as_ref().unwrap().expires_on,
+                token_data.access_token.clone(),
+                token_data.refresh_token.clone(),
+                token_data.expires_on,
             );
         }
-        Ok(())
+        self.persist_token()
+    }
+
+    /// Builds and sends the request, retrying it according to the retry policy.
+    /// The access token is refreshed first if needed and sent as bearer token, unless the
+    /// request already has an `Authorization` header.
+    /// Non-2xx responses are returned as a `GoogleApiError`.
+    ///
+    /// This can be used to call endpoints that don't have a request builder yet:
+    /// ```rust
+    /// let req = client.req_client.get("https://www.googleapis.com/calendar/v3/colors");
+    /// let colors: serde_json::Value = client.send(req).await?.json().await?;
+    /// ```
+    pub async fn send(
+        &self,
+        builder: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, GoogleApiError> {
+        let (client, request) = builder.build_split();
+        let mut request = request?;
+        if !request.headers().contains_key(AUTHORIZATION) {
+            self.refresh_access_token_check().await?;
+            if let Some(token) = self.access_token() {
+                let value = HeaderValue::from_str(&format!("Bearer {}", token.access_token))
+                    .map_err(|e| {
+                        GoogleApiError::Credentials(format!("Invalid access token: {e}"))
+                    })?;
+                request.headers_mut().insert(AUTHORIZATION, value);
+            }
+        }
+
+        let retryable = self.retry_policy.allows_method(request.method());
+        let mut attempt = 1;
+        loop {
+            let next_request = if retryable { request.try_clone() } else { None };
+            let error = match client.execute(request).await {
+                Ok(res) if res.status().is_success() => return Ok(res),
+                Ok(res) => GoogleApiError::from_response(res).await,
+                Err(e) => GoogleApiError::Request(e),
+            };
+            match (next_request, self.retry_policy.retry_delay(&error, attempt)) {
+                (Some(next_request), Some(delay)) => {
+                    tokio::time::sleep(delay).await;
+                    request = next_request;
+                    attempt += 1;
+                }
+                _ => return Err(error),
+            }
+        }
     }
 }
 
+// The locks are never held across an await or a user callback, so a poisoned lock still holds
+// consistent data.
+fn read<T>(lock: &RwLock<T>) -> RwLockReadGuard<'_, T> {
+    lock.read().unwrap_or_else(|e| e.into_inner())
+}
+
+fn write<T>(lock: &RwLock<T>) -> RwLockWriteGuard<'_, T> {
+    lock.write().unwrap_or_else(|e| e.into_inner())
+}
+
 pub trait TokenRefreshHandler: Send + Sync {
     fn on_token_refresh(&self, new_token: String, refresh_token: String, new_expiry: DateTime<Utc>);
 }
@@ -210,12 +439,8 @@ pub fn get_validity_token_secs(datetime_str: &str) -> i64 {
     seconds_valid.num_seconds()
 }
 
-fn build_default_reqwest_client(token: &str) -> reqwest::Client {
+fn build_default_reqwest_client() -> reqwest::Client {
     let mut headers = reqwest::header::HeaderMap::new();
-    headers.insert(
-        reqwest::header::AUTHORIZATION,
-        format!("Bearer {token}").parse().unwrap(),
-    );
     headers.insert(reqwest::header::ACCEPT, "application/json".parse().unwrap());
     headers.insert(
         reqwest::header::CONTENT_TYPE,
@@ -0,0 +1,94 @@
+#![allow(clippy::unwrap_used)]
+
+use chrono::{Duration, Utc};
+
+use super::client::{AccessToken, ClientCredentials, ClientTokenData, GoogleClient};
+use crate::utils::endpoints::ApiEndpoints;
+
+fn expired_client(server_url: &str) -> GoogleClient {
+    let mut client = GoogleClient::new(
+        ClientCredentials {
+            refresh_token: "rtok".into(),
+            ..Default::default()
+        },
+        AccessToken {
+            access_token: "expired".into(),
+            expires_in: -10,
+            ..Default::default()
+        },
+        true,
+    );
+    client.set_endpoints(ApiEndpoints::with_base_url(server_url));
+    client
+}
+
+#[test]
+fn google_client_is_send_sync_and_clone() {
+    fn assert_shareable<T: Send + Sync + Clone + 'static>() {}
+    assert_shareable::<GoogleClient>();
+}
+
+#[tokio::test]
+async fn concurrent_requests_refresh_the_token_once() {
+    let mut server = mockito::Server::new_async().await;
+    let refresh = server
+        .mock("POST", "/token")
+        .with_status(200)
+        .with_body(r#"{"access_token": "fresh", "expires_in": 3600}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let api = server
+        .mock("GET", "/calendar/v3/colors")
+        .match_header("authorization", "Bearer fresh")
+        .with_status(200)
+        .with_body("{}")
+        .expect(8)
+        .create_async()
+        .await;
+
+    let client = expired_client(&server.url());
+    let url = format!("{}/calendar/v3/colors", server.url());
+    let requests: Vec<_> = (0..8)
+        .map(|_| {
+            let client = client.clone();
+            let url = url.clone();
+            tokio::spawn(async move {
+                let req = client.req_client.get(&url);
+                client.send(req).await.map(|res| res.status())
+            })
+        })
+        .collect();
+    for request in requests {
+        assert_eq!(request.await.unwrap().unwrap(), 200);
+    }
+
+    refresh.assert_async().await;
+    api.assert_async().await;
+}
+
+#[tokio::test]
+async fn clones_share_the_refreshed_token() {
+    let mut server = mockito::Server::new_async().await;
+    let _refresh = server
+        .mock("POST", "/token")
+        .with_status(200)
+        .with_body(r#"{"access_token": "fresh", "expires_in": 3600}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = expired_client(&server.url());
+    let clone = client.clone();
+    client.refresh_access_token_check().await.unwrap();
+
+    assert!(clone.is_access_token_valid());
+    assert_eq!(clone.access_token().unwrap().access_token, "fresh");
+
+    clone.set_access_token(ClientTokenData {
+        access_token: "manual".into(),
+        expires_on: Utc::now() + Duration::seconds(60),
+        refresh_token: String::new(),
+    });
+    assert_eq!(client.access_token().unwrap().access_token, "manual");
+}
@@ -0,0 +1,313 @@
+use std::time::Duration;
+
+use futures::{stream::FuturesUnordered, StreamExt};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+    net::{TcpListener, TcpStream},
+};
+
+use super::{
+    client::{ClientCredentials, GoogleClient},
+    get_acces_token_with_verifier_at,
+    oauth_url::{OAuthUrl, OAuthUrlBuilder, Prompt},
+    scopes::Scope,
+};
+use crate::{error::GoogleApiError, utils::endpoints::ApiEndpoints};
+
+const SUCCESS_PAGE: &str =
+    "<html><body><h1>Signed in</h1><p>You can close this window and return to the terminal.</p></body></html>";
+const FAILURE_PAGE: &str =
+    "<html><body><h1>Sign in failed</h1><p>Return to the terminal for details.</p></body></html>";
+/// How long a single connection may take to send its request line and headers.
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Desktop style OAuth login for CLI tools.
+///
+/// Starts a temporary HTTP listener on `127.0.0.1`, prints (and optionally opens) the consent
+/// URL, waits for Google to redirect back with the authorization code and exchanges it for a
+/// ready to use `GoogleClient`.
+/// The OAuth client has to be of the "Desktop app" type, which allows loopback redirect URIs on
+/// any port.
+///
+/// Requires the `loopback` feature.
+///
+/// # Example
+/// ```rust
+/// let mut client = LoopbackFlow::new(client_id, client_secret, vec![Scope::Calendar])
+///     .run()
+///     .await?;
+/// // Store client.client_credentials().refresh_token to skip the login next time
+/// ```
+#[derive(Clone)]
+pub struct LoopbackFlow {
+    client_id: String,
+    client_secret: String,
+    scopes: Vec<Scope>,
+    port: u16,
+    open_browser: bool,
+    login_hint: Option<String>,
+    timeout: Duration,
+    endpoints: ApiEndpoints,
+}
+
+impl LoopbackFlow {
+    pub fn new(client_id: &str, client_secret: &str, scopes: Vec<Scope>) -> Self {
+        Self {
+            client_id: client_id.to_string(),
+            client_secret: client_secret.to_string(),
+            scopes,
+            port: 0,
+            open_browser: true,
+            login_hint: None,
+            timeout: Duration::from_secs(300),
+            endpoints: ApiEndpoints::default(),
+        }
+    }
+
+    /// Listen on this port instead of a random free one.
+    pub fn port(mut self, port: u16) -> Self {
+        self.port = port;
+        self
+    }
+
+    /// Try to open the consent URL in the default browser. Enabled by default, the URL is
+    /// printed either way.
+    pub fn open_browser(mut self, open: bool) -> Self {
+        self.open_browser = open;
+        self
+    }
+
+    /// Prefill the email address of the user signing in.
+    pub fn login_hint(mut self, hint: &str) -> Self {
+        self.login_hint = Some(hint.to_string());
+        self
+    }
+
+    /// Give up when the user hasn't been redirected back within this time. Defaults to five
+    /// minutes.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Use other OAuth endpoints, the returned client uses them as well.
+    pub fn endpoints(mut self, endpoints: ApiEndpoints) -> Self {
+        self.endpoints = endpoints;
+        self
+    }
+
+    /// Starts the listener and builds the consent URL, without waiting for the redirect.
+    pub async fn start(self) -> Result<PendingLogin, GoogleApiError> {
+        let listener = TcpListener::bind(("127.0.0.1", self.port))
+            .await
+            .map_err(|e| {
+                GoogleApiError::Credentials(format!("Failed to start loopback listener: {e}"))
+            })?;
+        let port = listener
+            .local_addr()
+            .map_err(|e| {
+                GoogleApiError::Credentials(format!("Failed to start loopback listener: {e}"))
+            })?
+            .port();
+        let redirect_uri = format!("http://127.0.0.1:{port}");
+
+        let mut builder = OAuthUrlBuilder::new(&self.client_id, &redirect_uri)
+            .auth_url(&self.endpoints.oauth_auth)
+            .scopes(self.scopes)
+            // Make sure Google returns a refresh token, also when the user consented before
+            .prompt(Prompt::Consent);
+        if let Some(hint) = &self.login_hint {
+            builder = builder.login_hint(hint);
+        }
+
+        Ok(PendingLogin {
+            listener,
+            oauth: builder.build(),
+            redirect_uri,
+            client_id: self.client_id,
+            client_secret: self.client_secret,
+            open_browser: self.open_browser,
+            timeout: self.timeout,
+            endpoints: self.endpoints,
+        })
+    }
+
+    /// Runs the whole login: prints the consent URL and returns the client once the user
+    /// signed in.
+    pub async fn run(self) -> Result<GoogleClient, GoogleApiError> {
+        let pending = self.start().await?;
+        println!(
+            "Open this URL in your browser to sign in:\n\n{}\n",
+            pending.url()
+        );
+        if pending.open_browser {
+            open_in_browser(pending.url());
+        }
+        pending.finish().await
+    }
+}
+
+/// A started `LoopbackFlow`, waiting for the user to be redirected back.
+pub struct PendingLogin {
+    listener: TcpListener,
+    oauth: OAuthUrl,
+    redirect_uri: String,
+    client_id: String,
+    client_secret: String,
+    open_browser: bool,
+    timeout: Duration,
+    endpoints: ApiEndpoints,
+}
+
+impl PendingLogin {
+    /// The consent URL to show to the user.
+    pub fn url(&self) -> &str {
+        &self.oauth.url
+    }
+
+    /// The `http://127.0.0.1:{port}` redirect URI the listener is bound to.
+    pub fn redirect_uri(&self) -> &str {
+        &self.redirect_uri
+    }
+
+    /// Waits for the redirect, validates the `state`, exchanges the code and returns the client.
+    pub async fn finish(self) -> Result<GoogleClient, GoogleApiError> {
+        let code = tokio::time::timeout(self.timeout, self.wait_for_code())
+            .await
+            .map_err(|_| {
+                GoogleApiError::Credentials(
+                    "Timed out waiting for the redirect after signing in".to_string(),
+                )
+            })??;
+
+        let code_verifier = self.oauth.code_verifier.clone().unwrap_or_default();
+        let token = get_acces_token_with_verifier_at(
+            &self.endpoints.oauth_token,
+            &code,
+            &code_verifier,
+            &self.client_secret,
+            &self.client_id,
+            &self.redirect_uri,
+        )
+        .await?;
+
+        let credentials = ClientCredentials {
+            client_id: self.client_id,
+            client_secret: self.client_secret,
+            redirect_uri: self.redirect_uri,
+            refresh_token: token.refresh_token.clone(),
+        };
+        let mut client = GoogleClient::new(credentials, token, true);
+        client.set_endpoints(self.endpoints);
+        Ok(client)
+    }
+
+    /// Accepts connections until the redirect of this flow arrives and returns its code.
+    /// Connections are read concurrently, so an idle preconnect of the browser doesn't block
+    /// the redirect.
+    async fn wait_for_code(&self) -> Result<String, GoogleApiError> {
+        let mut connections = FuturesUnordered::new();
+        loop {
+            tokio::select! {
+                accepted = self.listener.accept() => {
+                    let (stream, _) = accepted.map_err(|e| {
+                        GoogleApiError::Credentials(format!("Failed to accept redirect: {e}"))
+                    })?;
+                    connections.push(read_connection(stream));
+                }
+                Some((mut stream, params)) = connections.next() => {
+                    let Some(params) = params else {
+                        // Not the redirect, e.g. the browser asking for a favicon
+                        respond(&mut stream, "404 Not Found", "").await;
+                        continue;
+                    };
+                    let get = |key: &str| {
+                        params
+                            .iter()
+                            .find(|(k, _)| k == key)
+                            .map(|(_, v)| v.as_str())
+                    };
+                    if !self.oauth.verify_state(get("state").unwrap_or_default()) {
+                        // Not started by this flow, keep waiting for the real redirect
+                        respond(&mut stream, "400 Bad Request", FAILURE_PAGE).await;
+                        continue;
+                    }
+
+                    let result = validate(get("error"), get("code"));
+                    let page = if result.is_ok() {
+                        SUCCESS_PAGE
+                    } else {
+                        FAILURE_PAGE
+                    };
+                    respond(&mut stream, "200 OK", page).await;
+                    return result;
+                }
+            }
+        }
+    }
+}
+
+fn validate(error: Option<&str>, code: Option<&str>) -> Result<String, GoogleApiError> {
+    if let Some(error) = error {
+        return Err(GoogleApiError::Credentials(format!(
+            "Authorization was denied: {error}"
+        )));
+    }
+    code.map(str::to_string).ok_or_else(|| {
+        GoogleApiError::Credentials("The redirect didn't contain a code".to_string())
+    })
+}
+
+/// Reads a connection, giving up after `READ_TIMEOUT`.
+async fn read_connection(mut stream: TcpStream) -> (TcpStream, Option<Vec<(String, String)>>) {
+    let params = tokio::time::timeout(READ_TIMEOUT, read_redirect_params(&mut stream))
+        .await
+        .ok()
+        .flatten();
+    (stream, params)
+}
+
+/// Reads the request line and returns its query parameters if it is the OAuth redirect.
+async fn read_redirect_params(stream: &mut TcpStream) -> Option<Vec<(String, String)>> {
+    let mut reader = BufReader::new(stream);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).await.ok()?;
+    // Drain the headers so the browser doesn't see a reset connection
+    let mut line = String::new();
+    while reader.read_line(&mut line).await.ok()? > 2 {
+        line.clear();
+    }
+
+    let target = request_line.split_whitespace().nth(1)?;
+    let url = reqwest::Url::parse(&format!("http://127.0.0.1{target}")).ok()?;
+    let params: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+    params
+        .iter()
+        .any(|(k, _)| k == "code" || k == "error")
+        .then_some(params)
+}
+
+async fn respond(stream: &mut TcpStream, status: &str, body: &str) {
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    // The login doesn't depend on the browser receiving the page
+    let _ = stream.write_all(response.as_bytes()).await;
+    let _ = stream.shutdown().await;
+}
+
+fn open_in_browser(url: &str) {
+    #[cfg(target_os = "macos")]
+    let result = std::process::Command::new("open").arg(url).spawn();
+    #[cfg(target_os = "windows")]
+    let result = std::process::Command::new("rundll32")
+        .args(["url.dll,FileProtocolHandler", url])
+        .spawn();
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    let result = std::process::Command::new("xdg-open").arg(url).spawn();
+
+    if result.is_err() {
+        println!("Could not open a browser, copy the URL above instead.");
+    }
+}
@@ -0,0 +1,124 @@
+#![allow(clippy::unwrap_used)]
+
+use std::{collections::HashMap, time::Duration};
+
+use super::{loopback::LoopbackFlow, scopes::Scope};
+use crate::{error::GoogleApiError, utils::endpoints::ApiEndpoints};
+
+fn query_params(url: &str) -> HashMap<String, String> {
+    reqwest::Url::parse(url)
+        .unwrap()
+        .query_pairs()
+        .into_owned()
+        .collect()
+}
+
+#[tokio::test]
+async fn redirect_is_captured_and_exchanged() {
+    let mut server = mockito::Server::new_async().await;
+    let token = server
+        .mock("POST", "/token")
+        .match_body(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("code".into(), "the-code".into()),
+            mockito::Matcher::Regex("code_verifier=".into()),
+        ]))
+        .with_status(200)
+        .with_body(r#"{"access_token": "atok", "refresh_token": "rtok", "expires_in": 3599}"#)
+        .create_async()
+        .await;
+
+    let pending = LoopbackFlow::new("cid", "secret", vec![Scope::Calendar])
+        .open_browser(false)
+        .endpoints(ApiEndpoints::with_base_url(&server.url()))
+        .start()
+        .await
+        .unwrap();
+    let params = query_params(pending.url());
+    assert_eq!(params["redirect_uri"], pending.redirect_uri());
+    let redirect = format!(
+        "{}/?state={}&code=the-code&scope=calendar",
+        pending.redirect_uri(),
+        params["state"]
+    );
+
+    let browser = tokio::spawn(async move {
+        // The browser asking for a favicon first must not end the flow
+        let favicon = reqwest::get(format!(
+            "{}/favicon.ico",
+            redirect.split("/?").next().unwrap()
+        ))
+        .await
+        .unwrap();
+        assert_eq!(favicon.status(), 404);
+        let res = reqwest::get(redirect).await.unwrap();
+        assert_eq!(res.status(), 200);
+        assert!(res.text().await.unwrap().contains("Signed in"));
+    });
+
+    let client = pending.finish().await.unwrap();
+    browser.await.unwrap();
+
+    assert!(client.is_access_token_valid());
+    assert_eq!(client.access_token().unwrap().access_token, "atok");
+    assert_eq!(client.client_credentials().refresh_token, "rtok");
+    assert_eq!(
+        client.endpoints.oauth_token,
+        format!("{}/token", server.url())
+    );
+    token.assert_async().await;
+}
+
+#[tokio::test]
+async fn mismatched_state_is_rejected_and_flow_keeps_waiting() {
+    let pending = LoopbackFlow::new("cid", "secret", vec![Scope::Calendar])
+        .open_browser(false)
+        .timeout(Duration::from_millis(500))
+        .start()
+        .await
+        .unwrap();
+    let redirect = format!("{}/?state=forged&code=the-code", pending.redirect_uri());
+
+    let browser = tokio::spawn(async move { reqwest::get(redirect).await.unwrap().status() });
+
+    let err = pending.finish().await.unwrap_err();
+    assert!(matches!(err, GoogleApiError::Credentials(message) if message.contains("Timed out")));
+    assert_eq!(browser.await.unwrap(), 400);
+}
+
+#[tokio::test]
+async fn idle_connection_does_not_block_the_redirect() {
+    let mut server = mockito::Server::new_async().await;
+    server
+        .mock("POST", "/token")
+        .with_status(200)
+        .with_body(r#"{"access_token": "atok", "refresh_token": "rtok", "expires_in": 3599}"#)
+        .create_async()
+        .await;
+
+    let pending = LoopbackFlow::new("cid", "secret", vec![Scope::Calendar])
+        .open_browser(false)
+        .timeout(Duration::from_secs(5))
+        .endpoints(ApiEndpoints::with_base_url(&server.url()))
+        .start()
+        .await
+        .unwrap();
+    let address = pending
+        .redirect_uri()
+        .trim_start_matches("http://")
+        .to_string();
+    let redirect = format!(
+        "{}/?state={}&code=the-code",
+        pending.redirect_uri(),
+        query_params(pending.url())["state"]
+    );
+
+    let browser = tokio::spawn(async move {
+        // A preconnect that never sends a request
+        let _idle = tokio::net::TcpStream::connect(address).await.unwrap();
+        reqwest::get(redirect).await.unwrap().status()
+    });
+
+    let client = pending.finish().await.unwrap();
+    assert_eq!(browser.await.unwrap(), 200);
+    assert_eq!(client.access_token().unwrap().access_token, "atok");
+}
@@ -1,9 +1,30 @@
-use anyhow::{anyhow, Error};
 use client::{AccessToken, ClientCredentials};
+use oauth_url::encode_url;
 use scopes::Scope;
 
+use crate::{
+    error::{GoogleApiError, GoogleErrorDetails},
+    utils::{endpoints::ApiEndpoints, request::decode_response},
+};
+
 pub mod client;
+#[cfg(test)]
+mod client_tests;
+/// Loopback OAuth flow for CLI tools, requires the `loopback` feature.
+#[cfg(feature = "loopback")]
+pub mod loopback;
+#[cfg(all(test, feature = "loopback"))]
+mod loopback_tests;
+pub mod oauth_url;
+#[cfg(test)]
+mod oauth_url_tests;
 pub mod scopes;
+pub mod service_account;
+#[cfg(test)]
+mod service_account_tests;
+pub mod token_store;
+#[cfg(test)]
+mod token_store_tests;
 
 /// Helper function to generate the OAuth URL for Google authentication.
 /// # Example:
@@ -30,18 +51,25 @@ pub mod scopes;
 /// The result has to be handled in the web application to capture the authorization code
 /// Google will redirect to the specified `redirect_uri` with the authorization code as a query
 /// parameter.
+///
+/// The URL always asks for offline access with `prompt=consent` and carries no `state` or PKCE
+/// challenge. Use `oauth_url::OAuthUrlBuilder` for control over these parameters.
 pub fn get_oauth_url(client_id: &str, redirect_uri: &str, scopes: Vec<Scope>) -> String {
-    let base_url = "https://accounts.google.com/o/oauth2/auth";
-    format!(
-        "{}?client_id={}&redirect_uri={}&response_type=code&scope={}&access_type=offline&prompt=consent",
-        base_url,
-        client_id,
-        redirect_uri,
-        scopes
-            .iter()
-            .map(|s| s.as_str())
-            .collect::<Vec<&str>>()
-            .join(" ")
+    let scope = scopes
+        .iter()
+        .map(|s| s.as_str())
+        .collect::<Vec<&str>>()
+        .join(" ");
+    encode_url(
+        &ApiEndpoints::default().oauth_auth,
+        &[
+            ("client_id", client_id.to_string()),
+            ("redirect_uri", redirect_uri.to_string()),
+            ("response_type", "code".to_string()),
+            ("scope", scope),
+            ("access_type", "offline".to_string()),
+            ("prompt", "consent".to_string()),
+        ],
     )
 }
 
@@ -67,16 +95,6 @@ pub fn get_oauth_url(client_id: &str, redirect_uri: &str, scopes: Vec<Scope>) ->
 ///    .await
 ///    .unwrap();
 ///
-///    let valid_until =
-///        chrono::Utc::now() + chrono::Duration::seconds(access_token.expires_in as i64);
-///    //Optional save the refresh token to the database or cache
-///    save_refresh_token(
-///        &access_token.refresh_token,
-///        &access_token.access_token,
-///        Some(valid_until),
-///    )
-///    .expect("Failed to save refresh token");
-///
 ///    let client_credentials = ClientCredentials {
 ///        redirect_uri: google_cfg.google_redirect_uri.to_string(),
 ///        client_id: google_cfg.google_client_id.to_string(),
@@ -84,9 +102,13 @@ pub fn get_oauth_url(client_id: &str, redirect_uri: &str, scopes: Vec<Scope>) ->
 ///        refresh_token: access_token.refresh_token.clone(),
 ///    };
 ///
-///    let new_client = GoogleClient::new(client_credentials, access_token);
-///    let mut guard = state.google_client.lock().await;
-///    *guard = Some(new_client);
+///    let mut new_client = GoogleClient::new(client_credentials, access_token, true);
+///    // Optional: persist the token, refreshed and rotated tokens are saved automatically.
+///    // On the next start use `GoogleClient::from_token_store` instead of signing in again.
+///    new_client.set_token_store(state.token_store.clone(), "default");
+///    new_client.persist_token().expect("Failed to save token");
+///
+///    *state.google_client.write().await = Some(new_client);
 ///    println!("Google client initialized successfully");
 ///    StatusCode::OK
 ///}
@@ -96,59 +118,135 @@ pub async fn get_acces_token(
     client_secret: &str,
     client_id: &str,
     redirect_uri: &str,
-) -> Result<AccessToken, Error> {
-    let url = "https://oauth2.googleapis.com/token";
-    let params = [
+) -> Result<AccessToken, GoogleApiError> {
+    get_acces_token_at(
+        &ApiEndpoints::default().oauth_token,
+        code,
+        client_secret,
+        client_id,
+        redirect_uri,
+    )
+    .await
+}
+
+/// Same as `get_acces_token`, but exchanges the code at the given token endpoint,
+/// e.g. `client.endpoints.oauth_token` for a client pointed at a mock server.
+pub async fn get_acces_token_at(
+    token_url: &str,
+    code: &str,
+    client_secret: &str,
+    client_id: &str,
+    redirect_uri: &str,
+) -> Result<AccessToken, GoogleApiError> {
+    exchange_code(
+        token_url,
+        code,
+        None,
+        client_secret,
+        client_id,
+        redirect_uri,
+    )
+    .await
+}
+
+/// Same as `get_acces_token`, for consent URLs built with a PKCE challenge.
+/// `code_verifier` is the `OAuthUrl::code_verifier` generated together with the URL.
+pub async fn get_acces_token_with_verifier(
+    code: &str,
+    code_verifier: &str,
+    client_secret: &str,
+    client_id: &str,
+    redirect_uri: &str,
+) -> Result<AccessToken, GoogleApiError> {
+    get_acces_token_with_verifier_at(
+        &ApiEndpoints::default().oauth_token,
+        code,
+        code_verifier,
+        client_secret,
+        client_id,
+        redirect_uri,
+    )
+    .await
+}
+
+/// Same as `get_acces_token_with_verifier`, but exchanges the code at the given token endpoint.
+pub async fn get_acces_token_with_verifier_at(
+    token_url: &str,
+    code: &str,
+    code_verifier: &str,
+    client_secret: &str,
+    client_id: &str,
+    redirect_uri: &str,
+) -> Result<AccessToken, GoogleApiError> {
+    exchange_code(
+        token_url,
+        code,
+        Some(code_verifier),
+        client_secret,
+        client_id,
+        redirect_uri,
+    )
+    .await
+}
+
+async fn exchange_code(
+    token_url: &str,
+    code: &str,
+    code_verifier: Option<&str>,
+    client_secret: &str,
+    client_id: &str,
+    redirect_uri: &str,
+) -> Result<AccessToken, GoogleApiError> {
+    let mut params = vec![
         ("code", code),
         ("client_id", client_id),
         ("client_secret", client_secret),
         ("redirect_uri", redirect_uri),
         ("grant_type", "authorization_code"),
     ];
+    if let Some(verifier) = code_verifier {
+        params.push(("code_verifier", verifier));
+    }
 
     let client = reqwest::Client::new();
-    let res = client.post(url).form(&params).send().await;
-
-    match res {
-        Ok(response) => {
-            if response.status().is_success() {
-                let json: serde_json::Value = response.json().await?;
-                Ok(
-                    serde_json::from_value(json.clone()).unwrap_or_else(|_| AccessToken {
-                        token_type: json["token_type"].as_str().unwrap_or_default().to_string(),
-                        access_token: json["access_token"]
-                            .as_str()
-                            .unwrap_or_default()
-                            .to_string(),
-                        expires_in: json["expires_in"].as_i64().unwrap_or(0),
-                        refresh_token: json["refresh_token"]
-                            .as_str()
-                            .unwrap_or_default()
-                            .to_string(),
-                        refresh_token_expires_in: json["x_refresh_token_expires_in"]
-                            .as_i64()
-                            .unwrap_or(0),
-                        scope: json["scope"].as_str().unwrap_or_default().to_string(),
-                    }),
-                )
-            } else {
-                let status = response.status();
-                let error_body = response.text().await.unwrap_or_else(|_| "Unable to read error body".to_string());
-                Err(anyhow::anyhow!(
-                    "Failed to retrieve access token: {} - {}",
-                    status,
-                    error_body
-                ))
-            }
-        }
-        Err(e) => Err(anyhow::anyhow!(e)),
-    }
+    let response = client.post(token_url).form(&params).send().await?;
+
+    let json: serde_json::Value = decode_response(response).await?;
+    Ok(
+        serde_json::from_value(json.clone()).unwrap_or_else(|_| AccessToken {
+            token_type: json["token_type"].as_str().unwrap_or_default().to_string(),
+            access_token: json["access_token"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+            expires_in: json["expires_in"].as_i64().unwrap_or(0),
+            refresh_token: json["refresh_token"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+            refresh_token_expires_in: json["x_refresh_token_expires_in"].as_i64().unwrap_or(0),
+            scope: json["scope"].as_str().unwrap_or_default().to_string(),
+        }),
+    )
 }
 
+/// Exchanges the refresh token for a new access token.
+///
+/// A `400`/`401` from the token endpoint (e.g. `invalid_grant` for a revoked refresh token) is
+/// returned as `GoogleApiError::AuthExpired`, since the user has to go through the consent
+/// screen again.
 pub async fn refresh_acces_token(
     client_credentials: &ClientCredentials,
-) -> Result<AccessToken, anyhow::Error> {
-    let url = "https://oauth2.googleapis.com/token";
+) -> Result<AccessToken, GoogleApiError> {
+    refresh_acces_token_at(&ApiEndpoints::default().oauth_token, client_credentials).await
+}
+
+/// Same as `refresh_acces_token`, but refreshes at the given token endpoint.
+/// `GoogleClient` uses its configured `endpoints.oauth_token`.
+pub async fn refresh_acces_token_at(
+    token_url: &str,
+    client_credentials: &ClientCredentials,
+) -> Result<AccessToken, GoogleApiError> {
     let params = [
         ("client_id", client_credentials.client_id.clone()),
         ("client_secret", client_credentials.client_secret.clone()),
@@ -157,37 +255,34 @@ pub async fn refresh_acces_token(
     ];
 
     let client = reqwest::Client::new();
-    let res = client.post(url).form(&params).send();
-
-    match res.await {
-        Ok(response) => {
-            if response.status().is_success() {
-                let json: serde_json::Value = response.json().await?;
-                // Google may return a new refresh token during rotation
-                // If present, use the new one; otherwise, keep the existing one
-                let refresh_token = json["refresh_token"]
-                    .as_str()
-                    .map(|s| s.to_string())
-                    .unwrap_or_else(|| client_credentials.refresh_token.clone());
-                
-                let token = AccessToken {
-                    token_type: json["token_type"].as_str().unwrap_or_default().to_string(),
-                    access_token: json["access_token"]
-                        .as_str()
-                        .unwrap_or_default()
-                        .to_string(),
-                    expires_in: json["expires_in"].as_i64().unwrap_or(0),
-                    refresh_token,
-                    refresh_token_expires_in: 0,
-                    scope: json["scope"].as_str().unwrap_or_default().to_string(),
-                };
-                Ok(token)
-            } else {
-                let status = response.status();
-                let error_body = response.text().await.unwrap_or_else(|_| "Unable to read error body".to_string());
-                Err(anyhow!("Failed to refresh token: {} - {}", status, error_body))
-            }
-        }
-        Err(e) => Err(anyhow!("Request error: {e}")),
+    let response = client.post(token_url).form(&params).send().await?;
+
+    let status = response.status();
+    if status == reqwest::StatusCode::BAD_REQUEST || status == reqwest::StatusCode::UNAUTHORIZED {
+        let body = response.text().await.unwrap_or_default();
+        return Err(GoogleApiError::AuthExpired(GoogleErrorDetails::parse(
+            status.as_u16(),
+            &body,
+        )));
     }
+
+    let json: serde_json::Value = decode_response(response).await?;
+    // Google may return a new refresh token during rotation
+    // If present, use the new one; otherwise, keep the existing one
+    let refresh_token = json["refresh_token"]
+        .as_str()
+        .map(|s| s.to_string())
+        .unwrap_or_else(|| client_credentials.refresh_token.clone());
+
+    Ok(AccessToken {
+        token_type: json["token_type"].as_str().unwrap_or_default().to_string(),
+        access_token: json["access_token"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+        expires_in: json["expires_in"].as_i64().unwrap_or(0),
+        refresh_token,
+        refresh_token_expires_in: 0,
+        scope: json["scope"].as_str().unwrap_or_default().to_string(),
+    })
 }
@@ -0,0 +1,280 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use ring::{
+    digest::{digest, SHA256},
+    rand::{SecureRandom, SystemRandom},
+};
+
+use super::scopes::Scope;
+use crate::utils::{compare::constant_time_eq, endpoints::ApiEndpoints};
+
+/// Whether Google should return a refresh token with the access token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessType {
+    Online,
+    Offline,
+}
+
+impl AccessType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            AccessType::Online => "online",
+            AccessType::Offline => "offline",
+        }
+    }
+}
+
+/// The `prompt` shown to the user. Multiple prompts can be combined, except `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Prompt {
+    /// Don't show any screen, fails if the user isn't signed in or hasn't consented yet.
+    None,
+    /// Always show the consent screen, needed to get a new refresh token.
+    Consent,
+    /// Let the user pick an account.
+    SelectAccount,
+}
+
+impl Prompt {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Prompt::None => "none",
+            Prompt::Consent => "consent",
+            Prompt::SelectAccount => "select_account",
+        }
+    }
+}
+
+/// A PKCE code verifier and its S256 code challenge (RFC 7636).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PkceChallenge {
+    /// Secret sent with the token request, keep it until the user is redirected back.
+    pub verifier: String,
+    /// `BASE64URL(SHA256(verifier))`, sent in the consent URL.
+    pub challenge: String,
+}
+
+impl PkceChallenge {
+    /// Generates a random 43 character verifier.
+    pub fn new() -> Self {
+        Self::from_verifier(&random_token())
+    }
+
+    /// Computes the S256 challenge for an existing verifier.
+    pub fn from_verifier(verifier: &str) -> Self {
+        let hash = digest(&SHA256, verifier.as_bytes());
+        Self {
+            verifier: verifier.to_string(),
+            challenge: URL_SAFE_NO_PAD.encode(hash.as_ref()),
+        }
+    }
+}
+
+impl Default for PkceChallenge {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The consent URL built by `OAuthUrlBuilder`, with the values needed to handle the redirect.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OAuthUrl {
+    pub url: String,
+    /// The CSRF `state`, Google sends it back unchanged with the authorization code.
+    pub state: String,
+    /// The PKCE verifier to pass to `get_acces_token_with_verifier`, `None` if PKCE is disabled.
+    pub code_verifier: Option<String>,
+}
+
+impl OAuthUrl {
+    /// Checks the `state` query parameter of the redirect against the generated state.
+    pub fn verify_state(&self, state: &str) -> bool {
+        constant_time_eq(self.state.as_bytes(), state.as_bytes())
+    }
+}
+
+/// Builder for the OAuth consent URL.
+///
+/// Every parameter is percent-encoded. A random CSRF `state` and a PKCE S256 challenge are
+/// generated by default, store them with the session to validate the redirect and exchange
+/// the code.
+///
+/// # Example
+/// ```rust
+/// let oauth = OAuthUrlBuilder::new(client_id, redirect_uri)
+///     .scopes(vec![Scope::Calendar, Scope::Tasks])
+///     .login_hint("jane@example.com")
+///     .prompt(Prompt::Consent)
+///     .build();
+/// session.insert("oauth_state", oauth.state.clone());
+/// session.insert("code_verifier", oauth.code_verifier.clone());
+/// Redirect::to(&oauth.url)
+///
+/// // In the redirect handler
+/// if !oauth.verify_state(&params["state"]) {
+///     return StatusCode::BAD_REQUEST;
+/// }
+/// let token = get_acces_token_with_verifier(
+///     &params["code"],
+///     &code_verifier,
+///     client_secret,
+///     client_id,
+///     redirect_uri,
+/// )
+/// .await?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct OAuthUrlBuilder {
+    auth_url: String,
+    client_id: String,
+    redirect_uri: String,
+    scopes: Vec<String>,
+    state: Option<String>,
+    pkce: Option<PkceChallenge>,
+    access_type: Option<AccessType>,
+    prompt: Vec<Prompt>,
+    login_hint: Option<String>,
+    hd: Option<String>,
+    include_granted_scopes: Option<bool>,
+}
+
+impl OAuthUrlBuilder {
+    /// Creates a builder requesting offline access with PKCE enabled.
+    pub fn new(client_id: &str, redirect_uri: &str) -> Self {
+        Self {
+            auth_url: ApiEndpoints::default().oauth_auth,
+            client_id: client_id.to_string(),
+            redirect_uri: redirect_uri.to_string(),
+            scopes: Vec::new(),
+            state: None,
+            pkce: Some(PkceChallenge::new()),
+            access_type: Some(AccessType::Offline),
+            prompt: Vec::new(),
+            login_hint: None,
+            hd: None,
+            include_granted_scopes: None,
+        }
+    }
+
+    /// Use another consent screen URL, e.g. `client.endpoints.oauth_auth`.
+    pub fn auth_url(mut self, url: &str) -> Self {
+        self.auth_url = url.to_string();
+        self
+    }
+
+    /// Add the scopes to request access to.
+    pub fn scopes(mut self, scopes: Vec<Scope>) -> Self {
+        self.scopes
+            .extend(scopes.iter().map(|s| s.as_str().to_string()));
+        self
+    }
+
+    /// Use this `state` instead of a random one.
+    pub fn state(mut self, state: &str) -> Self {
+        self.state = Some(state.to_string());
+        self
+    }
+
+    /// Use this PKCE pair instead of a random one.
+    pub fn pkce(mut self, pkce: PkceChallenge) -> Self {
+        self.pkce = Some(pkce);
+        self
+    }
+
+    /// Don't send a PKCE challenge.
+    pub fn disable_pkce(mut self) -> Self {
+        self.pkce = None;
+        self
+    }
+
+    pub fn access_type(mut self, access_type: AccessType) -> Self {
+        self.access_type = Some(access_type);
+        self
+    }
+
+    /// Add a prompt, e.g. `Prompt::Consent` to always get a refresh token.
+    pub fn prompt(mut self, prompt: Prompt) -> Self {
+        if !self.prompt.contains(&prompt) {
+            self.prompt.push(prompt);
+        }
+        self
+    }
+
+    /// Prefill the email address or `sub` of the user signing in.
+    pub fn login_hint(mut self, hint: &str) -> Self {
+        self.login_hint = Some(hint.to_string());
+        self
+    }
+
+    /// Only offer accounts of the given Workspace domain.
+    pub fn hd(mut self, domain: &str) -> Self {
+        self.hd = Some(domain.to_string());
+        self
+    }
+
+    /// Incremental authorization: the token also covers previously granted scopes.
+    pub fn include_granted_scopes(mut self, include: bool) -> Self {
+        self.include_granted_scopes = Some(include);
+        self
+    }
+
+    pub fn build(self) -> OAuthUrl {
+        let state = self.state.unwrap_or_else(random_token);
+        let mut params = vec![
+            ("client_id", self.client_id),
+            ("redirect_uri", self.redirect_uri),
+            ("response_type", "code".to_string()),
+            ("scope", self.scopes.join(" ")),
+            ("state", state.clone()),
+        ];
+        if let Some(pkce) = &self.pkce {
+            params.push(("code_challenge", pkce.challenge.clone()));
+            params.push(("code_challenge_method", "S256".to_string()));
+        }
+        if let Some(access_type) = self.access_type {
+            params.push(("access_type", access_type.as_str().to_string()));
+        }
+        if !self.prompt.is_empty() {
+            let prompt: Vec<&str> = self.prompt.iter().map(|p| p.as_str()).collect();
+            params.push(("prompt", prompt.join(" ")));
+        }
+        if let Some(hint) = self.login_hint {
+            params.push(("login_hint", hint));
+        }
+        if let Some(hd) = self.hd {
+            params.push(("hd", hd));
+        }
+        if let Some(include) = self.include_granted_scopes {
+            params.push(("include_granted_scopes", include.to_string()));
+        }
+
+        OAuthUrl {
+            url: encode_url(&self.auth_url, &params),
+            state,
+            code_verifier: self.pkce.map(|p| p.verifier),
+        }
+    }
+}
+
+/// Appends the percent-encoded `params` as query string to `base_url`.
+pub(crate) fn encode_url(base_url: &str, params: &[(&str, String)]) -> String {
+    match reqwest::Url::parse_with_params(base_url, params) {
+        Ok(url) => url.to_string(),
+        // Not an absolute URL, encode the query ourselves
+        Err(_) => {
+            let query = reqwest::Url::parse_with_params("http://localhost", params)
+                .ok()
+                .and_then(|url| url.query().map(str::to_string))
+                .unwrap_or_default();
+            format!("{base_url}?{query}")
+        }
+    }
+}
+
+/// Returns 32 random bytes encoded as URL safe base64 (43 characters).
+fn random_token() -> String {
+    let mut bytes = [0u8; 32];
+    SystemRandom::new()
+        .fill(&mut bytes)
+        .expect("Failed to generate random bytes");
+    URL_SAFE_NO_PAD.encode(bytes)
+}
@@ -0,0 +1,145 @@
+#![allow(clippy::unwrap_used)]
+
+use std::collections::HashMap;
+
+use super::{
+    get_acces_token_with_verifier_at, get_oauth_url,
+    oauth_url::{AccessType, OAuthUrlBuilder, PkceChallenge, Prompt},
+    scopes::Scope,
+};
+
+fn query_params(url: &str) -> HashMap<String, String> {
+    reqwest::Url::parse(url)
+        .unwrap()
+        .query_pairs()
+        .into_owned()
+        .collect()
+}
+
+#[test]
+fn pkce_challenge_matches_rfc7636_example() {
+    let pkce = PkceChallenge::from_verifier("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk");
+    assert_eq!(
+        pkce.challenge,
+        "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM"
+    );
+}
+
+#[test]
+fn generated_state_and_verifier_are_random() {
+    let a = PkceChallenge::new();
+    let b = PkceChallenge::new();
+    assert_eq!(a.verifier.len(), 43);
+    assert_ne!(a.verifier, b.verifier);
+
+    let first = OAuthUrlBuilder::new("cid", "http://localhost/cb").build();
+    let second = OAuthUrlBuilder::new("cid", "http://localhost/cb").build();
+    assert_ne!(first.state, second.state);
+}
+
+#[test]
+fn builder_encodes_all_parameters() {
+    let pkce = PkceChallenge::from_verifier("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk");
+    let oauth = OAuthUrlBuilder::new("cid", "https://example.com/cb?next=/home&x=1")
+        .scopes(vec![Scope::Calendar, Scope::Tasks])
+        .state("s t&a=te")
+        .pkce(pkce)
+        .access_type(AccessType::Online)
+        .prompt(Prompt::Consent)
+        .prompt(Prompt::SelectAccount)
+        .login_hint("jane+test@example.com")
+        .hd("example.com")
+        .include_granted_scopes(true)
+        .build();
+
+    assert!(oauth
+        .url
+        .starts_with("https://accounts.google.com/o/oauth2/auth?"));
+    assert!(!oauth.url.contains("next=/home&x=1"));
+
+    let p = query_params(&oauth.url);
+    assert_eq!(p["client_id"], "cid");
+    assert_eq!(p["redirect_uri"], "https://example.com/cb?next=/home&x=1");
+    assert_eq!(p["response_type"], "code");
+    assert_eq!(
+        p["scope"],
+        "https://www.googleapis.com/auth/calendar https://www.googleapis.com/auth/tasks"
+    );
+    assert_eq!(p["state"], "s t&a=te");
+    assert_eq!(
+        p["code_challenge"],
+        "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM"
+    );
+    assert_eq!(p["code_challenge_method"], "S256");
+    assert_eq!(p["access_type"], "online");
+    assert_eq!(p["prompt"], "consent select_account");
+    assert_eq!(p["login_hint"], "jane+test@example.com");
+    assert_eq!(p["hd"], "example.com");
+    assert_eq!(p["include_granted_scopes"], "true");
+
+    assert_eq!(
+        oauth.code_verifier.as_deref(),
+        Some("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk")
+    );
+    assert!(oauth.verify_state("s t&a=te"));
+    assert!(!oauth.verify_state("s t&a=tf"));
+}
+
+#[test]
+fn builder_defaults_to_offline_without_prompt() {
+    let oauth = OAuthUrlBuilder::new("cid", "http://localhost/cb")
+        .disable_pkce()
+        .build();
+
+    let p = query_params(&oauth.url);
+    assert_eq!(p["access_type"], "offline");
+    assert!(!p.contains_key("prompt"));
+    assert!(!p.contains_key("code_challenge"));
+    assert_eq!(p["state"], oauth.state);
+    assert_eq!(oauth.code_verifier, None);
+}
+
+#[test]
+fn get_oauth_url_encodes_parameters() {
+    let url = get_oauth_url(
+        "cid",
+        "https://example.com/cb?a=1&b=2",
+        vec![Scope::Calendar],
+    );
+
+    let p = query_params(&url);
+    assert_eq!(p["redirect_uri"], "https://example.com/cb?a=1&b=2");
+    assert_eq!(p["scope"], "https://www.googleapis.com/auth/calendar");
+    assert_eq!(p["prompt"], "consent");
+}
+
+#[tokio::test]
+async fn token_exchange_sends_code_verifier() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/token")
+        .match_body(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("grant_type".into(), "authorization_code".into()),
+            mockito::Matcher::UrlEncoded("code".into(), "the-code".into()),
+            mockito::Matcher::UrlEncoded("code_verifier".into(), "the-verifier".into()),
+        ]))
+        .with_status(200)
+        .with_body(r#"{"access_token": "atok", "refresh_token": "rtok", "expires_in": 3599}"#)
+        .create_async()
+        .await;
+
+    let token = get_acces_token_with_verifier_at(
+        &format!("{}/token", server.url()),
+        "the-code",
+        "the-verifier",
+        "secret",
+        "cid",
+        "http://localhost/cb",
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(token.access_token, "atok");
+    assert_eq!(token.refresh_token, "rtok");
+    mock.assert_async().await;
+}
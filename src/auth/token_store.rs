@@ -0,0 +1,165 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use super::client::ClientTokenData;
+use crate::error::GoogleApiError;
+
+/// Persistent storage for the tokens of one or more users or accounts.
+///
+/// A `GoogleClient` with a token store saves every new token after a refresh, including
+/// rotated refresh tokens, under the key it was created with.
+/// Implement this trait to keep tokens in a database or secret manager.
+///
+/// # Example
+/// ```rust
+/// let store = Arc::new(FileTokenStore::new("tokens.json"));
+///
+/// // After the OAuth redirect
+/// let token = get_acces_token(&code, client_secret, client_id, redirect_uri).await?;
+/// let mut client = GoogleClient::new(client_credentials, token, true);
+/// client.set_token_store(store.clone(), "jane@example.com");
+/// client.persist_token()?;
+///
+/// // On the next start
+/// let client = GoogleClient::from_token_store(client_credentials, store, "jane@example.com")?
+///     .expect("user has to sign in first");
+/// ```
+pub trait TokenStore: Send + Sync {
+    /// Returns the token stored under `key`, or `None` if there is none.
+    fn load(&self, key: &str) -> Result<Option<ClientTokenData>, GoogleApiError>;
+
+    /// Stores `token` under `key`, replacing any previous token.
+    fn save(&self, key: &str, token: &ClientTokenData) -> Result<(), GoogleApiError>;
+
+    /// Removes the token stored under `key`, e.g. when the user signs out.
+    fn delete(&self, key: &str) -> Result<(), GoogleApiError>;
+}
+
+/// Keeps tokens in memory, for tests and short lived processes.
+#[derive(Debug, Default)]
+pub struct MemoryTokenStore {
+    tokens: Mutex<HashMap<String, ClientTokenData>>,
+}
+
+impl MemoryTokenStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn tokens(&self) -> std::sync::MutexGuard<'_, HashMap<String, ClientTokenData>> {
+        // The map can't be left in an inconsistent state, so a poisoned lock is still usable
+        self.tokens.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl TokenStore for MemoryTokenStore {
+    fn load(&self, key: &str) -> Result<Option<ClientTokenData>, GoogleApiError> {
+        Ok(self.tokens().get(key).cloned())
+    }
+
+    fn save(&self, key: &str, token: &ClientTokenData) -> Result<(), GoogleApiError> {
+        self.tokens().insert(key.to_string(), token.clone());
+        Ok(())
+    }
+
+    fn delete(&self, key: &str) -> Result<(), GoogleApiError> {
+        self.tokens().remove(key);
+        Ok(())
+    }
+}
+
+/// Stores the tokens of all keys in a single JSON file.
+///
+/// The file is replaced atomically on every change and is only readable by the current user
+/// on Unix. It is created on the first save.
+#[derive(Debug)]
+pub struct FileTokenStore {
+    path: PathBuf,
+    lock: Mutex<()>,
+}
+
+impl FileTokenStore {
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            lock: Mutex::new(()),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn read_all(&self) -> Result<HashMap<String, ClientTokenData>, GoogleApiError> {
+        let json = match std::fs::read_to_string(&self.path) {
+            Ok(json) => json,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(HashMap::new()),
+            Err(e) => return Err(self.error("read", e)),
+        };
+        serde_json::from_str(&json).map_err(|e| self.error("parse", e))
+    }
+
+    fn write_all(&self, tokens: &HashMap<String, ClientTokenData>) -> Result<(), GoogleApiError> {
+        let json = serde_json::to_string_pretty(tokens).map_err(|e| self.error("serialize", e))?;
+        let tmp_path = self.path.with_extension("tmp");
+        write_private(&tmp_path, json.as_bytes()).map_err(|e| self.error("write", e))?;
+        std::fs::rename(&tmp_path, &self.path).map_err(|e| self.error("write", e))
+    }
+
+    fn update<F>(&self, change: F) -> Result<(), GoogleApiError>
+    where
+        F: FnOnce(&mut HashMap<String, ClientTokenData>),
+    {
+        let _guard = self.lock.lock().unwrap_or_else(|e| e.into_inner());
+        let mut tokens = self.read_all()?;
+        change(&mut tokens);
+        self.write_all(&tokens)
+    }
+
+    fn error(&self, action: &str, e: impl std::fmt::Display) -> GoogleApiError {
+        GoogleApiError::Storage(format!(
+            "Failed to {action} token file {}: {e}",
+            self.path.display()
+        ))
+    }
+}
+
+impl TokenStore for FileTokenStore {
+    fn load(&self, key: &str) -> Result<Option<ClientTokenData>, GoogleApiError> {
+        let _guard = self.lock.lock().unwrap_or_else(|e| e.into_inner());
+        Ok(self.read_all()?.remove(key))
+    }
+
+    fn save(&self, key: &str, token: &ClientTokenData) -> Result<(), GoogleApiError> {
+        self.update(|tokens| {
+            tokens.insert(key.to_string(), token.clone());
+        })
+    }
+
+    fn delete(&self, key: &str) -> Result<(), GoogleApiError> {
+        self.update(|tokens| {
+            tokens.remove(key);
+        })
+    }
+}
+
+#[cfg(unix)]
+fn write_private(path: &Path, data: &[u8]) -> std::io::Result<()> {
+    use std::{io::Write, os::unix::fs::OpenOptionsExt};
+
+    let mut file = std::fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .mode(0o600)
+        .open(path)?;
+    file.write_all(data)
+}
+
+#[cfg(not(unix))]
+fn write_private(path: &Path, data: &[u8]) -> std::io::Result<()> {
+    std::fs::write(path, data)
+}
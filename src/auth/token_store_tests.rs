@@ -0,0 +1,118 @@
+#![allow(clippy::unwrap_used)]
+
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+
+use super::{
+    client::{ClientCredentials, ClientTokenData, GoogleClient},
+    token_store::{FileTokenStore, MemoryTokenStore, TokenStore},
+};
+use crate::utils::endpoints::ApiEndpoints;
+
+fn token(access_token: &str, expires_in_secs: i64) -> ClientTokenData {
+    ClientTokenData {
+        access_token: access_token.into(),
+        expires_on: Utc::now() + Duration::seconds(expires_in_secs),
+        refresh_token: "rtok".into(),
+    }
+}
+
+fn temp_path(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!(
+        "google-workspace-apis-{name}-{}-{}.json",
+        std::process::id(),
+        Utc::now().timestamp_nanos_opt().unwrap_or_default()
+    ))
+}
+
+fn assert_round_trip(store: &dyn TokenStore) {
+    assert!(store.load("jane").unwrap().is_none());
+
+    let saved = token("atok", 3600);
+    store.save("jane", &saved).unwrap();
+    store.save("john", &token("other", 3600)).unwrap();
+
+    let loaded = store.load("jane").unwrap().unwrap();
+    assert_eq!(loaded.access_token, saved.access_token);
+    assert_eq!(loaded.refresh_token, saved.refresh_token);
+    assert_eq!(loaded.expires_on, saved.expires_on);
+
+    store.delete("jane").unwrap();
+    assert!(store.load("jane").unwrap().is_none());
+    assert_eq!(store.load("john").unwrap().unwrap().access_token, "other");
+}
+
+#[test]
+fn memory_store_round_trip() {
+    assert_round_trip(&MemoryTokenStore::new());
+}
+
+#[test]
+fn file_store_round_trip_and_persists_across_instances() {
+    let path = temp_path("round-trip");
+    assert_round_trip(&FileTokenStore::new(&path));
+
+    let reopened = FileTokenStore::new(&path);
+    assert_eq!(
+        reopened.load("john").unwrap().unwrap().access_token,
+        "other"
+    );
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[cfg(unix)]
+#[test]
+fn file_store_is_only_readable_by_owner() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let path = temp_path("permissions");
+    FileTokenStore::new(&path)
+        .save("jane", &token("atok", 3600))
+        .unwrap();
+
+    let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+    assert_eq!(mode & 0o777, 0o600);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn from_token_store_returns_none_without_token() {
+    let store = Arc::new(MemoryTokenStore::new());
+    let client = GoogleClient::from_token_store(ClientCredentials::default(), store, "jane");
+    assert!(client.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn refreshed_and_rotated_tokens_are_persisted() {
+    let mut server = mockito::Server::new_async().await;
+    let refresh = server
+        .mock("POST", "/token")
+        .match_body(mockito::Matcher::UrlEncoded(
+            "refresh_token".into(),
+            "rtok".into(),
+        ))
+        .with_status(200)
+        .with_body(r#"{"access_token": "fresh", "refresh_token": "rotated", "expires_in": 3600}"#)
+        .create_async()
+        .await;
+
+    let store = Arc::new(MemoryTokenStore::new());
+    store.save("jane", &token("expired", -10)).unwrap();
+
+    let mut client =
+        GoogleClient::from_token_store(ClientCredentials::default(), store.clone(), "jane")
+            .unwrap()
+            .unwrap();
+    client.set_endpoints(ApiEndpoints::with_base_url(&server.url()));
+    assert_eq!(client.client_credentials().refresh_token, "rtok");
+    assert!(!client.is_access_token_valid());
+
+    client.refresh_access_token_check().await.unwrap();
+
+    let stored = store.load("jane").unwrap().unwrap();
+    assert_eq!(stored.access_token, "fresh");
+    assert_eq!(stored.refresh_token, "rotated");
+    assert_eq!(client.client_credentials().refresh_token, "rotated");
+    refresh.assert_async().await;
+}
@@ -0,0 +1,191 @@
+#![allow(clippy::unwrap_used)]
+
+use reqwest::Method;
+
+use super::{
+    requests::{AclClient, AclRole, AclScopeType},
+    types::{AclRule, AclScope},
+};
+use crate::{
+    calendar::channels::types::Channel,
+    utils::{request::PaginationRequestTrait, test_support::client},
+};
+
+#[test]
+fn scope_constructors_set_type_and_value() {
+    assert_eq!(AclScope::user("a@example.com").type_, AclScopeType::User);
+    assert_eq!(AclScope::group("g@example.com").type_, AclScopeType::Group);
+    assert_eq!(AclScope::domain("example.com").value, "example.com");
+    assert_eq!(AclScope::public(), AclScope::new(AclScopeType::Default, ""));
+    assert_eq!(
+        serde_json::to_value(AclScope::public()).unwrap(),
+        serde_json::json!({"type": "default"})
+    );
+}
+
+#[tokio::test]
+async fn insert_rule_shares_calendar_with_user() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/calendar/v3/calendars/team@group.calendar.google.com/acl")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "sendNotifications".into(),
+            "false".into(),
+        ))
+        .match_body(mockito::Matcher::Json(serde_json::json!({
+            "scope": {"type": "user", "value": "new.hire@example.com"},
+            "role": "writer"
+        })))
+        .with_status(200)
+        .with_body(
+            r#"{"id": "user:new.hire@example.com", "role": "writer", "scope": {"type": "user", "value": "new.hire@example.com"}}"#,
+        )
+        .create_async()
+        .await;
+
+    let gc = client(&server.url());
+    let rule = AclClient::new(&gc)
+        .insert_acl_rule(
+            "team@group.calendar.google.com",
+            AclRole::Writer,
+            AclScope::user("new.hire@example.com"),
+        )
+        .send_notifications(false)
+        .request()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(rule.id, "user:new.hire@example.com");
+    assert_eq!(rule.role, Some(AclRole::Writer));
+    assert_eq!(rule.scope.unwrap().value, "new.hire@example.com");
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn list_rules_follows_pages() {
+    let mut server = mockito::Server::new_async().await;
+    let first = server
+        .mock("GET", "/calendar/v3/calendars/primary/acl")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "showDeleted".into(),
+            "true".into(),
+        ))
+        .with_status(200)
+        .with_body(r#"{"items": [{"id": "user:a@example.com", "role": "owner"}], "nextPageToken": "page2"}"#)
+        .create_async()
+        .await;
+    let second = server
+        .mock("GET", "/calendar/v3/calendars/primary/acl")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("showDeleted".into(), "true".into()),
+            mockito::Matcher::UrlEncoded("pageToken".into(), "page2".into()),
+        ]))
+        .with_status(200)
+        .with_body(r#"{"items": [{"id": "default", "role": "none"}]}"#)
+        .create_async()
+        .await;
+
+    let gc = client(&server.url());
+    let rules = AclClient::new(&gc)
+        .get_acl("primary")
+        .show_deleted(true)
+        .collect_all()
+        .await
+        .unwrap();
+
+    let roles: Vec<_> = rules.iter().map(|r| r.role.clone().unwrap()).collect();
+    assert_eq!(roles, vec![AclRole::Owner, AclRole::None]);
+    first.assert_async().await;
+    second.assert_async().await;
+}
+
+#[test]
+fn patch_update_and_delete_target_the_rule() {
+    let gc = client("https://example.com");
+
+    let patch = AclClient::new(&gc)
+        .patch_acl_rule("primary", "user:a@example.com")
+        .set_role(AclRole::Reader);
+    assert_eq!(patch.request.method, Method::PATCH);
+    assert_eq!(
+        patch.request.url,
+        "https://example.com/calendar/v3/calendars/primary/acl/user:a@example.com"
+    );
+    assert_eq!(
+        serde_json::to_value(patch.rule.as_ref().unwrap()).unwrap(),
+        serde_json::json!({"role": "reader"})
+    );
+
+    let update = AclClient::new(&gc)
+        .update_acl_rule(
+            "primary",
+            "domain:example.com",
+            AclRole::FreeBusyReader,
+            AclScope::domain("example.com"),
+        )
+        .send_notifications(true);
+    assert_eq!(update.request.method, Method::PUT);
+    assert_eq!(
+        update
+            .request
+            .params
+            .get("sendNotifications")
+            .map(String::as_str),
+        Some("true")
+    );
+
+    let list = AclClient::new(&gc).get_acl("primary").max_results(10);
+    assert_eq!(
+        list.request.url,
+        "https://example.com/calendar/v3/calendars/primary/acl"
+    );
+
+    let delete = AclClient::new(&gc).delete_acl_rule("primary", "default");
+    assert_eq!(delete.request.method, Method::DELETE);
+    assert!(delete.rule.is_none());
+}
+
+#[test]
+fn watch_acl_sends_channel() {
+    let gc = client("https://example.com");
+    let watch = AclClient::new(&gc)
+        .watch_acl(
+            "primary",
+            Channel::web_hook("a", "https://example.com/hook"),
+        )
+        .set_ttl(60);
+
+    assert_eq!(watch.request.method, Method::POST);
+    assert_eq!(
+        watch.request.url,
+        "https://example.com/calendar/v3/calendars/primary/acl/watch"
+    );
+    assert!(watch.rule.is_none());
+    assert_eq!(
+        serde_json::to_value(watch.channel.as_ref().unwrap()).unwrap(),
+        serde_json::json!({
+            "id": "a",
+            "type": "web_hook",
+            "address": "https://example.com/hook",
+            "params": {"ttl": "60"}
+        })
+    );
+}
+
+#[test]
+fn unknown_roles_and_scope_types_are_kept() {
+    let rule: AclRule = serde_json::from_str(
+        r#"{"id": "team:a", "role": "commenter", "scope": {"type": "team", "value": "a"}}"#,
+    )
+    .unwrap();
+
+    assert_eq!(rule.role, Some(AclRole::Unknown("commenter".into())));
+    assert_eq!(
+        rule.scope.as_ref().unwrap().type_,
+        AclScopeType::Unknown("team".into())
+    );
+    let value = serde_json::to_value(&rule).unwrap();
+    assert_eq!(value["role"], serde_json::json!("commenter"));
+    assert_eq!(value["scope"]["type"], serde_json::json!("team"));
+}
@@ -0,0 +1,424 @@
+use futures::{Stream, TryStreamExt};
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+
+use crate::{
+    auth::client::GoogleClient,
+    calendar::channels::types::Channel,
+    error::GoogleApiError,
+    utils::{
+        pagination::{paginate, Page},
+        request::{PaginationRequestTrait, Request},
+    },
+};
+
+use super::types::{Acl, AclRule, AclRuleRequest, AclScope};
+pub use super::types::{AclRole, AclScopeType};
+
+/// Indicates that the request builder is not yet initialized with a specific mode.
+pub struct Uninitialized;
+/// Indicates that the request builder is initialized for listing the rules of a calendar.
+/// This struct determines which filters can be applied to the request.
+pub struct AclListMode;
+/// Indicates that the request builder is initialized for retrieving a single rule.
+pub struct AclGetMode;
+/// Indicates that the request builder is initialized for creating a rule.
+pub struct AclInsertMode;
+/// Indicates that the request builder is initialized for patching a rule.
+pub struct AclPatchMode;
+/// Indicates that the request builder is initialized for replacing a rule.
+pub struct AclUpdateMode;
+/// Indicates that the request builder is initialized for deleting a rule.
+pub struct AclDeleteMode;
+/// Indicates that the request builder is initialized for watching the rules of a calendar for
+/// changes.
+pub struct AclWatchMode;
+
+impl AclScope {
+    /// A scope of the given type, `value` is the email address or domain name.
+    pub fn new(type_: AclScopeType, value: &str) -> Self {
+        Self {
+            type_,
+            value: value.to_string(),
+        }
+    }
+
+    /// A single user, by email address.
+    pub fn user(email: &str) -> Self {
+        Self::new(AclScopeType::User, email)
+    }
+
+    /// A Google group, by email address.
+    pub fn group(email: &str) -> Self {
+        Self::new(AclScopeType::Group, email)
+    }
+
+    /// Everyone in a Workspace domain.
+    pub fn domain(domain: &str) -> Self {
+        Self::new(AclScopeType::Domain, domain)
+    }
+
+    /// Everyone, used to make a calendar public.
+    pub fn public() -> Self {
+        Self::new(AclScopeType::Default, "")
+    }
+}
+
+/// Client for the access control list of a calendar, which determines with whom it is shared.
+///
+/// The generic type parameter `T` determines the mode of operation for this client,
+/// which affects which methods are available and what parameters can be set.
+pub struct AclClient<'a, T = Uninitialized> {
+    pub(super) request: Request<'a>,
+    pub(super) rule: Option<AclRuleRequest>,
+    pub(super) channel: Option<Channel>,
+    pub(super) _mode: std::marker::PhantomData<T>,
+}
+
+impl<'a> AclClient<'a, Uninitialized> {
+    /// Creates a new ACL client using the provided Google client for authentication.
+    pub fn new(client: &'a GoogleClient) -> Self {
+        Self {
+            request: Request::new(client),
+            rule: None,
+            channel: None,
+            _mode: std::marker::PhantomData,
+        }
+    }
+
+    /// Get the rules of the access control list of a calendar.
+    ///
+    /// # Arguments
+    ///
+    /// * `calendar_id` - The ID of the calendar
+    pub fn get_acl(self, calendar_id: &str) -> AclClient<'a, AclListMode> {
+        self.into_mode(calendar_id, None, Method::GET, None)
+    }
+
+    /// Get a single rule.
+    ///
+    /// # Arguments
+    ///
+    /// * `calendar_id` - The ID of the calendar
+    /// * `rule_id` - The ID of the rule, e.g. "user:jane@example.com"
+    pub fn get_acl_rule(self, calendar_id: &str, rule_id: &str) -> AclClient<'a, AclGetMode> {
+        self.into_mode(calendar_id, Some(rule_id), Method::GET, None)
+    }
+
+    /// Share a calendar by creating a rule.
+    ///
+    /// # Arguments
+    ///
+    /// * `calendar_id` - The ID of the calendar to share
+    /// * `role` - The access to grant
+    /// * `scope` - Who to grant access to
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // Give a new hire access to the team calendar
+    /// AclClient::new(&client)
+    ///     .insert_acl_rule(
+    ///         "team@group.calendar.google.com",
+    ///         AclRole::Writer,
+    ///         AclScope::user("new.hire@example.com"),
+    ///     )
+    ///     .send_notifications(true)
+    ///     .request()
+    ///     .await?;
+    /// ```
+    pub fn insert_acl_rule(
+        self,
+        calendar_id: &str,
+        role: AclRole,
+        scope: AclScope,
+    ) -> AclClient<'a, AclInsertMode> {
+        let rule = AclRuleRequest {
+            scope: Some(scope),
+            role: Some(role),
+        };
+        self.into_mode(calendar_id, None, Method::POST, Some(rule))
+    }
+
+    /// Patch a rule, only the fields that are set are changed.
+    ///
+    /// # Arguments
+    ///
+    /// * `calendar_id` - The ID of the calendar
+    /// * `rule_id` - The ID of the rule to modify
+    pub fn patch_acl_rule(self, calendar_id: &str, rule_id: &str) -> AclClient<'a, AclPatchMode> {
+        self.into_mode(
+            calendar_id,
+            Some(rule_id),
+            Method::PATCH,
+            Some(AclRuleRequest::default()),
+        )
+    }
+
+    /// Replace a rule.
+    ///
+    /// # Arguments
+    ///
+    /// * `calendar_id` - The ID of the calendar
+    /// * `rule_id` - The ID of the rule to replace
+    /// * `role` - The new role
+    /// * `scope` - The scope of the rule, it can't be changed but the API requires it
+    pub fn update_acl_rule(
+        self,
+        calendar_id: &str,
+        rule_id: &str,
+        role: AclRole,
+        scope: AclScope,
+    ) -> AclClient<'a, AclUpdateMode> {
+        let rule = AclRuleRequest {
+            scope: Some(scope),
+            role: Some(role),
+        };
+        self.into_mode(calendar_id, Some(rule_id), Method::PUT, Some(rule))
+    }
+
+    /// Delete a rule, revoking the access it granted.
+    ///
+    /// # Arguments
+    ///
+    /// * `calendar_id` - The ID of the calendar
+    /// * `rule_id` - The ID of the rule to delete
+    pub fn delete_acl_rule(self, calendar_id: &str, rule_id: &str) -> AclClient<'a, AclDeleteMode> {
+        self.into_mode(calendar_id, Some(rule_id), Method::DELETE, None)
+    }
+
+    /// Watch the rules of a calendar for changes.
+    ///
+    /// # Arguments
+    ///
+    /// * `calendar_id` - The ID of the calendar
+    /// * `channel` - The channel to deliver notifications to, see `Channel::web_hook`
+    pub fn watch_acl(self, calendar_id: &str, channel: Channel) -> AclClient<'a, AclWatchMode> {
+        let mut builder = self.into_mode(calendar_id, Some("watch"), Method::POST, None);
+        builder.channel = Some(channel);
+        builder
+    }
+
+    fn into_mode<M>(
+        self,
+        calendar_id: &str,
+        rule_id: Option<&str>,
+        method: Method,
+        rule: Option<AclRuleRequest>,
+    ) -> AclClient<'a, M> {
+        let mut builder = AclClient {
+            request: self.request,
+            rule,
+            channel: None,
+            _mode: std::marker::PhantomData,
+        };
+        builder.request.url = format!(
+            "{}/calendars/{calendar_id}/acl",
+            builder.request.client.endpoints.calendar
+        );
+        if let Some(rule_id) = rule_id {
+            builder.request.url.push('/');
+            builder.request.url.push_str(rule_id);
+        }
+        builder.request.method = method;
+        builder
+    }
+}
+
+impl<'a, T> AclClient<'a, T> {
+    async fn make_request<R>(&mut self) -> Result<Option<R>, GoogleApiError>
+    where
+        R: DeserializeOwned,
+    {
+        if let Some(rule) = &self.rule {
+            self.request.set_json_body(rule)?;
+        } else if let Some(channel) = &self.channel {
+            self.request.set_json_body(channel)?;
+        }
+        self.request.execute().await.map(Some)
+    }
+
+    fn set_send_notifications(mut self, send: bool) -> Self {
+        self.request
+            .params
+            .insert("sendNotifications".to_string(), send.to_string());
+        self
+    }
+}
+
+impl<'a> PaginationRequestTrait for AclClient<'a, AclListMode> {
+    /// Maximum number of rules to return per page.
+    fn max_results(mut self, max: i64) -> Self {
+        self.request
+            .params
+            .insert("maxResults".to_string(), max.to_string());
+        self
+    }
+
+    /// Page token for pagination. Works with `max_results`.
+    fn page_token(mut self, token: &str) -> Self {
+        self.request
+            .params
+            .insert("pageToken".to_string(), token.to_string());
+        self
+    }
+}
+
+impl<'a> AclClient<'a, AclListMode> {
+    /// Filter if set to true also returns deleted rules, which have the role "none".
+    pub fn show_deleted(mut self, show: bool) -> Self {
+        self.request
+            .params
+            .insert("showDeleted".to_string(), show.to_string());
+        self
+    }
+
+    /// Returns a request result for getting one page of rules.
+    pub async fn request(&mut self) -> Result<Option<Acl>, GoogleApiError> {
+        self.make_request().await
+    }
+
+    /// Stop `stream` and `collect_all` after this many rules in total.
+    pub fn max_items(mut self, max: usize) -> Self {
+        self.request.max_items = Some(max);
+        self
+    }
+
+    /// Returns a stream of all rules, following `nextPageToken` until the last page or until
+    /// `max_items` rules have been yielded.
+    pub fn stream(self) -> impl Stream<Item = Result<AclRule, GoogleApiError>> + 'a {
+        paginate::<Acl>(self.request)
+    }
+
+    /// Fetches every page and returns all rules.
+    pub async fn collect_all(self) -> Result<Vec<AclRule>, GoogleApiError> {
+        self.stream().try_collect().await
+    }
+}
+
+impl Page for Acl {
+    type Item = AclRule;
+
+    fn next_page_token(&self) -> Option<&str> {
+        (!self.next_page_token.is_empty()).then_some(self.next_page_token.as_str())
+    }
+
+    fn into_items(self) -> Vec<AclRule> {
+        self.items
+    }
+}
+
+impl<'a> AclClient<'a, AclGetMode> {
+    /// Returns a request result for getting the rule.
+    pub async fn request(&mut self) -> Result<Option<AclRule>, GoogleApiError> {
+        self.make_request().await
+    }
+}
+
+impl<'a> AclClient<'a, AclInsertMode> {
+    /// Whether to send an email about the calendar sharing change, defaults to true.
+    pub fn send_notifications(self, send: bool) -> Self {
+        self.set_send_notifications(send)
+    }
+
+    /// Executes the request to create the rule.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(AclRule))` - The created rule if successful
+    /// * `Err(GoogleApiError)` - If the request failed or Google returned a non-2xx status
+    pub async fn request(&mut self) -> Result<Option<AclRule>, GoogleApiError> {
+        self.make_request().await
+    }
+}
+
+impl<'a> AclClient<'a, AclPatchMode> {
+    /// Patch the role of the rule.
+    pub fn set_role(mut self, role: AclRole) -> Self {
+        if let Some(ref mut rule) = self.rule {
+            rule.role = Some(role);
+        }
+        self
+    }
+
+    /// Patch the scope of the rule.
+    pub fn set_scope(mut self, scope: AclScope) -> Self {
+        if let Some(ref mut rule) = self.rule {
+            rule.scope = Some(scope);
+        }
+        self
+    }
+
+    /// Whether to send an email about the calendar sharing change, defaults to true.
+    pub fn send_notifications(self, send: bool) -> Self {
+        self.set_send_notifications(send)
+    }
+
+    /// Executes the request to patch the rule.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(AclRule))` - The patched rule if successful
+    /// * `Err(GoogleApiError)` - If the request failed or Google returned a non-2xx status
+    pub async fn request(&mut self) -> Result<Option<AclRule>, GoogleApiError> {
+        self.make_request().await
+    }
+}
+
+impl<'a> AclClient<'a, AclUpdateMode> {
+    /// Whether to send an email about the calendar sharing change, defaults to true.
+    pub fn send_notifications(self, send: bool) -> Self {
+        self.set_send_notifications(send)
+    }
+
+    /// Executes the request to replace the rule.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(AclRule))` - The updated rule if successful
+    /// * `Err(GoogleApiError)` - If the request failed or Google returned a non-2xx status
+    pub async fn request(&mut self) -> Result<Option<AclRule>, GoogleApiError> {
+        self.make_request().await
+    }
+}
+
+impl<'a> AclClient<'a, AclDeleteMode> {
+    /// Executes the request to delete the rule.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), GoogleApiError>` - `Ok(())` if the rule was deleted
+    pub async fn request(&mut self) -> Result<(), GoogleApiError> {
+        self.request.execute_empty().await
+    }
+}
+
+impl<'a> AclClient<'a, AclWatchMode> {
+    /// Set a token that is sent back with every notification, to verify its origin.
+    pub fn set_token(mut self, token: &str) -> Self {
+        if let Some(ref mut channel) = self.channel {
+            channel.token = token.to_string();
+        }
+        self
+    }
+
+    /// Request the channel to expire after this many seconds.
+    pub fn set_ttl(mut self, seconds: u64) -> Self {
+        if let Some(ref mut channel) = self.channel {
+            channel
+                .params
+                .insert("ttl".to_string(), seconds.to_string());
+        }
+        self
+    }
+
+    /// Executes the request to create the channel.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(Channel))` - The created channel with its `resource_id` and expiration
+    /// * `Err(GoogleApiError)` - If the request failed or Google returned a non-2xx status
+    pub async fn request(&mut self) -> Result<Option<Channel>, GoogleApiError> {
+        self.make_request().await
+    }
+}
@@ -0,0 +1,194 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
+pub struct Acl {
+    /**
+     * Type of the collection ("calendar#acl").
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
+    )]
+    pub kind: String,
+
+    /**
+     * ETag of the collection.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
+    )]
+    pub etag: String,
+
+    /**
+     * Token used to access the next page of this result.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize",
+        rename = "nextPageToken"
+    )]
+    pub next_page_token: String,
+
+    /**
+     * Token used at a later point in time to retrieve only the entries that have changed since
+     * this result was returned.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize",
+        rename = "nextSyncToken"
+    )]
+    pub next_sync_token: String,
+
+    /**
+     * List of rules on the access control list.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_vec::deserialize"
+    )]
+    pub items: Vec<AclRule>,
+}
+
+/// A rule granting a user, group, domain or everyone access to a calendar.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
+pub struct AclRule {
+    /**
+     * Type of the resource ("calendar#aclRule").
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
+    )]
+    pub kind: String,
+
+    /**
+     * ETag of the resource.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
+    )]
+    pub etag: String,
+
+    /**
+     * Identifier of the rule, e.g. "user:jane@example.com".
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
+    )]
+    pub id: String,
+
+    /**
+     * The extent to which calendar access is granted by this rule.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<AclScope>,
+
+    /**
+     * The role assigned to the scope.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<AclRole>,
+}
+
+/** The role granted by an ACL rule.
+* See [Google Calendar API
+* documentation](https://developers.google.com/calendar/api/v3/reference/acl)
+*/
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum AclRole {
+    /// No access, also hides free/busy information.
+    None,
+    FreeBusyReader,
+    Reader,
+    Writer,
+    Owner,
+    /// A role that was added to the API after this version of the crate
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl AclRole {
+    pub fn as_str(&self) -> &str {
+        match self {
+            AclRole::None => "none",
+            AclRole::FreeBusyReader => "freeBusyReader",
+            AclRole::Reader => "reader",
+            AclRole::Writer => "writer",
+            AclRole::Owner => "owner",
+            AclRole::Unknown(role) => role,
+        }
+    }
+}
+
+/// Who an ACL rule applies to.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, JsonSchema, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum AclScopeType {
+    /// Everyone, the public scope.
+    #[default]
+    Default,
+    User,
+    Group,
+    Domain,
+    /// A scope type that was added to the API after this version of the crate
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl AclScopeType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            AclScopeType::Default => "default",
+            AclScopeType::User => "user",
+            AclScopeType::Group => "group",
+            AclScopeType::Domain => "domain",
+            AclScopeType::Unknown(type_) => type_,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
+pub struct AclScope {
+    /**
+     * The type of the scope.
+     */
+    #[serde(default, rename = "type")]
+    pub type_: AclScopeType,
+
+    /**
+     * The email address of a user or group, or the name of a domain. Omitted for the "default"
+     * scope.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
+    )]
+    pub value: String,
+}
+
+/// Body for creating, patching or replacing a rule, only the fields that are set are sent.
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Default)]
+pub struct AclRuleRequest {
+    /// The extent to which calendar access is granted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<AclScope>,
+
+    /// The role assigned to the scope
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<AclRole>,
+}
@@ -0,0 +1,166 @@
+#![allow(clippy::unwrap_used)]
+
+use reqwest::Method;
+
+use super::{
+    requests::{AccessRole, CalendarListClient},
+    types::CalendarListEntry,
+};
+use crate::{
+    calendar::channels::types::Channel,
+    utils::{request::PaginationRequestTrait, test_support::client},
+};
+
+#[test]
+fn list_filters_are_set() {
+    let gc = client("https://example.com");
+
+    let builder = CalendarListClient::new(&gc)
+        .get_calendar_list()
+        .min_access_role(AccessRole::Writer)
+        .show_hidden(true)
+        .show_deleted(false)
+        .max_results(25);
+
+    assert_eq!(
+        builder.request.url,
+        "https://example.com/calendar/v3/users/me/calendarList"
+    );
+    assert_eq!(builder.request.method, Method::GET);
+    let p = &builder.request.params;
+    assert_eq!(p.get("minAccessRole").map(String::as_str), Some("writer"));
+    assert_eq!(p.get("showHidden").map(String::as_str), Some("true"));
+    assert_eq!(p.get("showDeleted").map(String::as_str), Some("false"));
+    assert_eq!(p.get("maxResults").map(String::as_str), Some("25"));
+}
+
+#[tokio::test]
+async fn collect_all_returns_entries_of_every_page() {
+    let mut server = mockito::Server::new_async().await;
+    let first = server
+        .mock("GET", "/calendar/v3/users/me/calendarList")
+        .match_query(mockito::Matcher::Missing)
+        .with_status(200)
+        .with_body(
+            r#"{"items": [{"id": "jane@example.com", "summary": "Jane", "primary": true, "accessRole": "owner"}], "nextPageToken": "page2"}"#,
+        )
+        .create_async()
+        .await;
+    let second = server
+        .mock("GET", "/calendar/v3/users/me/calendarList")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "pageToken".into(),
+            "page2".into(),
+        ))
+        .with_status(200)
+        .with_body(
+            r##"{"items": [{"id": "team@group.calendar.google.com", "summary": "Team", "backgroundColor": "#0088aa", "accessRole": "reader"}]}"##,
+        )
+        .create_async()
+        .await;
+
+    let gc = client(&server.url());
+    let calendars = CalendarListClient::new(&gc)
+        .get_calendar_list()
+        .collect_all()
+        .await
+        .unwrap();
+
+    assert_eq!(calendars.len(), 2);
+    assert_eq!(calendars[0].primary, Some(true));
+    assert_eq!(calendars[0].access_role, Some(AccessRole::Owner));
+    assert_eq!(calendars[1].id, "team@group.calendar.google.com");
+    assert_eq!(calendars[1].background_color, "#0088aa");
+    assert_eq!(calendars[1].access_role, Some(AccessRole::Reader));
+    first.assert_async().await;
+    second.assert_async().await;
+}
+
+#[tokio::test]
+async fn insert_sends_id_and_custom_colors() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/calendar/v3/users/me/calendarList")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "colorRgbFormat".into(),
+            "true".into(),
+        ))
+        .match_body(mockito::Matcher::Json(serde_json::json!({
+            "id": "team@group.calendar.google.com",
+            "backgroundColor": "#0088aa",
+            "foregroundColor": "#ffffff",
+            "selected": true
+        })))
+        .with_status(200)
+        .with_body(r#"{"id": "team@group.calendar.google.com"}"#)
+        .create_async()
+        .await;
+
+    let gc = client(&server.url());
+    let entry = CalendarListClient::new(&gc)
+        .insert_calendar_list_entry("team@group.calendar.google.com")
+        .set_colors("#0088aa", "#ffffff")
+        .set_selected(true)
+        .request()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(entry.id, "team@group.calendar.google.com");
+    mock.assert_async().await;
+}
+
+#[test]
+fn patch_and_delete_target_the_entry() {
+    let gc = client("https://example.com");
+
+    let patch = CalendarListClient::new(&gc)
+        .patch_calendar_list_entry("primary")
+        .set_summary_override("Me")
+        .set_hidden(false);
+    assert_eq!(patch.request.method, Method::PATCH);
+    assert_eq!(
+        patch.request.url,
+        "https://example.com/calendar/v3/users/me/calendarList/primary"
+    );
+    let body = serde_json::to_value(patch.entry.as_ref().unwrap()).unwrap();
+    assert_eq!(
+        body,
+        serde_json::json!({"summaryOverride": "Me", "hidden": false})
+    );
+
+    let delete = CalendarListClient::new(&gc).delete_calendar_list_entry("primary");
+    assert_eq!(delete.request.method, Method::DELETE);
+    assert!(delete.entry.is_none());
+}
+
+#[test]
+fn watch_sends_channel() {
+    let gc = client("https://example.com");
+    let watch = CalendarListClient::new(&gc)
+        .watch_calendar_list(Channel::web_hook("a", "https://example.com/hook"))
+        .set_token("secret");
+
+    assert_eq!(watch.request.method, Method::POST);
+    assert_eq!(
+        watch.request.url,
+        "https://example.com/calendar/v3/users/me/calendarList/watch"
+    );
+    assert!(watch.entry.is_none());
+    assert_eq!(watch.channel.unwrap().token, "secret");
+}
+
+#[test]
+fn unknown_access_roles_are_kept() {
+    let entry: CalendarListEntry =
+        serde_json::from_str(r#"{"id": "a@example.com", "accessRole": "commenter"}"#).unwrap();
+
+    assert_eq!(
+        entry.access_role,
+        Some(AccessRole::Unknown("commenter".into()))
+    );
+    assert_eq!(
+        serde_json::to_value(&entry).unwrap()["accessRole"],
+        serde_json::json!("commenter")
+    );
+}
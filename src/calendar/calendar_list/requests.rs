@@ -0,0 +1,448 @@
+use futures::{Stream, TryStreamExt};
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+
+use crate::{
+    auth::client::GoogleClient,
+    calendar::{channels::types::Channel, events::types::EventDefaultReminder},
+    error::GoogleApiError,
+    utils::{
+        pagination::{paginate, Page},
+        request::{PaginationRequestTrait, Request},
+    },
+};
+
+pub use super::types::AccessRole;
+use super::types::{
+    CalendarList, CalendarListEntry, CalendarListEntryRequest, NotificationSettings,
+};
+
+/// Indicates that the request builder is not yet initialized with a specific mode.
+pub struct Uninitialized;
+/// Indicates that the request builder is initialized for listing the calendars of the user.
+/// This struct determines which filters can be applied to the request.
+pub struct CalendarListListMode;
+/// Indicates that the request builder is initialized for retrieving a single entry.
+pub struct CalendarListGetMode;
+/// Indicates that the request builder is initialized for adding an existing calendar to the
+/// list.
+pub struct CalendarListInsertMode;
+/// Indicates that the request builder is initialized for patching an entry.
+pub struct CalendarListPatchMode;
+/// Indicates that the request builder is initialized for removing a calendar from the list.
+pub struct CalendarListDeleteMode;
+/// Indicates that the request builder is initialized for watching the calendar list for
+/// changes.
+pub struct CalendarListWatchMode;
+
+/// Client for the calendar list of the authenticated user: the calendars shown in the
+/// sidebar of Google Calendar, with the user specific settings like colors.
+///
+/// The generic type parameter `T` determines the mode of operation for this client,
+/// which affects which methods are available and what parameters can be set.
+pub struct CalendarListClient<'a, T = Uninitialized> {
+    pub(super) request: Request<'a>,
+    pub(super) entry: Option<CalendarListEntryRequest>,
+    pub(super) channel: Option<Channel>,
+    pub(super) _mode: std::marker::PhantomData<T>,
+}
+
+impl<'a> CalendarListClient<'a, Uninitialized> {
+    /// Creates a new calendar list client using the provided Google client for authentication.
+    pub fn new(client: &'a GoogleClient) -> Self {
+        Self {
+            request: Request::new(client),
+            entry: None,
+            channel: None,
+            _mode: std::marker::PhantomData,
+        }
+    }
+
+    /// Get the calendars on the calendar list of the user.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // Calendars the user can add events to, for a calendar picker
+    /// let calendars = CalendarListClient::new(&client)
+    ///     .get_calendar_list()
+    ///     .min_access_role(AccessRole::Writer)
+    ///     .collect_all()
+    ///     .await?;
+    /// for calendar in calendars {
+    ///     println!("{}: {}", calendar.id, calendar.summary);
+    /// }
+    /// ```
+    pub fn get_calendar_list(self) -> CalendarListClient<'a, CalendarListListMode> {
+        let mut builder = self.into_mode(None);
+        builder.request.url = format!(
+            "{}/users/me/calendarList",
+            builder.request.client.endpoints.calendar
+        );
+        builder.request.method = Method::GET;
+        builder
+    }
+
+    /// Get a single calendar from the calendar list of the user.
+    ///
+    /// # Arguments
+    ///
+    /// * `calendar_id` - The ID of the calendar, "primary" for the main calendar of the user
+    pub fn get_calendar_list_entry(
+        self,
+        calendar_id: &str,
+    ) -> CalendarListClient<'a, CalendarListGetMode> {
+        let mut builder = self.into_mode(None);
+        builder.request.url = format!(
+            "{}/users/me/calendarList/{calendar_id}",
+            builder.request.client.endpoints.calendar
+        );
+        builder.request.method = Method::GET;
+        builder
+    }
+
+    /// Add an existing calendar, e.g. one shared with the user, to the calendar list.
+    ///
+    /// # Arguments
+    ///
+    /// * `calendar_id` - The ID of the calendar to add
+    pub fn insert_calendar_list_entry(
+        self,
+        calendar_id: &str,
+    ) -> CalendarListClient<'a, CalendarListInsertMode> {
+        let mut builder = self.into_mode(Some(CalendarListEntryRequest {
+            id: Some(calendar_id.to_string()),
+            ..Default::default()
+        }));
+        builder.request.url = format!(
+            "{}/users/me/calendarList",
+            builder.request.client.endpoints.calendar
+        );
+        builder.request.method = Method::POST;
+        builder
+    }
+
+    /// Patch the settings of the user for a calendar on the calendar list.
+    ///
+    /// # Arguments
+    ///
+    /// * `calendar_id` - The ID of the calendar to modify
+    pub fn patch_calendar_list_entry(
+        self,
+        calendar_id: &str,
+    ) -> CalendarListClient<'a, CalendarListPatchMode> {
+        let mut builder = self.into_mode(Some(CalendarListEntryRequest::default()));
+        builder.request.url = format!(
+            "{}/users/me/calendarList/{calendar_id}",
+            builder.request.client.endpoints.calendar
+        );
+        builder.request.method = Method::PATCH;
+        builder
+    }
+
+    /// Remove a calendar from the calendar list, the calendar itself is not deleted.
+    ///
+    /// # Arguments
+    ///
+    /// * `calendar_id` - The ID of the calendar to remove
+    pub fn delete_calendar_list_entry(
+        self,
+        calendar_id: &str,
+    ) -> CalendarListClient<'a, CalendarListDeleteMode> {
+        let mut builder = self.into_mode(None);
+        builder.request.url = format!(
+            "{}/users/me/calendarList/{calendar_id}",
+            builder.request.client.endpoints.calendar
+        );
+        builder.request.method = Method::DELETE;
+        builder
+    }
+
+    /// Watch the calendar list for changes, e.g. calendars that are added or removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `channel` - The channel to deliver notifications to, see `Channel::web_hook`
+    pub fn watch_calendar_list(
+        self,
+        channel: Channel,
+    ) -> CalendarListClient<'a, CalendarListWatchMode> {
+        let mut builder = self.into_mode(None);
+        builder.channel = Some(channel);
+        builder.request.url = format!(
+            "{}/users/me/calendarList/watch",
+            builder.request.client.endpoints.calendar
+        );
+        builder.request.method = Method::POST;
+        builder
+    }
+
+    fn into_mode<M>(self, entry: Option<CalendarListEntryRequest>) -> CalendarListClient<'a, M> {
+        CalendarListClient {
+            request: self.request,
+            entry,
+            channel: None,
+            _mode: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, T> CalendarListClient<'a, T> {
+    async fn make_request<R>(&mut self) -> Result<Option<R>, GoogleApiError>
+    where
+        R: DeserializeOwned,
+    {
+        if let Some(entry) = &self.entry {
+            self.request.set_json_body(entry)?;
+        } else if let Some(channel) = &self.channel {
+            self.request.set_json_body(channel)?;
+        }
+        self.request.execute().await.map(Some)
+    }
+}
+
+impl<'a> PaginationRequestTrait for CalendarListClient<'a, CalendarListListMode> {
+    /// Maximum number of entries to return per page.
+    fn max_results(mut self, max: i64) -> Self {
+        self.request
+            .params
+            .insert("maxResults".to_string(), max.to_string());
+        self
+    }
+
+    /// Page token for pagination. Works with `max_results`.
+    fn page_token(mut self, token: &str) -> Self {
+        self.request
+            .params
+            .insert("pageToken".to_string(), token.to_string());
+        self
+    }
+}
+
+impl<'a> CalendarListClient<'a, CalendarListListMode> {
+    /// Only return calendars on which the user has at least this access role.
+    pub fn min_access_role(mut self, role: AccessRole) -> Self {
+        self.request
+            .params
+            .insert("minAccessRole".to_string(), role.as_str().to_string());
+        self
+    }
+
+    /// Filter if set to true also returns calendars hidden from the list.
+    pub fn show_hidden(mut self, show: bool) -> Self {
+        self.request
+            .params
+            .insert("showHidden".to_string(), show.to_string());
+        self
+    }
+
+    /// Filter if set to true also returns calendars removed from the list.
+    pub fn show_deleted(mut self, show: bool) -> Self {
+        self.request
+            .params
+            .insert("showDeleted".to_string(), show.to_string());
+        self
+    }
+
+    /// Returns a request result for getting one page of the calendar list.
+    pub async fn request(&mut self) -> Result<Option<CalendarList>, GoogleApiError> {
+        self.make_request().await
+    }
+
+    /// Stop `stream` and `collect_all` after this many calendars in total.
+    pub fn max_items(mut self, max: usize) -> Self {
+        self.request.max_items = Some(max);
+        self
+    }
+
+    /// Returns a stream of all calendars on the list, following `nextPageToken`
+    /// until the last page or until `max_items` calendars have been yielded.
+    pub fn stream(self) -> impl Stream<Item = Result<CalendarListEntry, GoogleApiError>> + 'a {
+        paginate::<CalendarList>(self.request)
+    }
+
+    /// Fetches every page and returns all calendars on the list.
+    pub async fn collect_all(self) -> Result<Vec<CalendarListEntry>, GoogleApiError> {
+        self.stream().try_collect().await
+    }
+}
+
+impl Page for CalendarList {
+    type Item = CalendarListEntry;
+
+    fn next_page_token(&self) -> Option<&str> {
+        (!self.next_page_token.is_empty()).then_some(self.next_page_token.as_str())
+    }
+
+    fn into_items(self) -> Vec<CalendarListEntry> {
+        self.items
+    }
+}
+
+impl<'a> CalendarListClient<'a, CalendarListGetMode> {
+    /// Returns a request result for getting the calendar list entry.
+    pub async fn request(&mut self) -> Result<Option<CalendarListEntry>, GoogleApiError> {
+        self.make_request().await
+    }
+}
+
+impl<'a> CalendarListClient<'a, CalendarListInsertMode> {
+    /// Set the name the user sees for this calendar.
+    pub fn set_summary_override(self, summary: &str) -> Self {
+        self.modify_entry(|entry| entry.summary_override = Some(summary.to_string()))
+    }
+
+    /// Set the color ID of the calendar, see the calendar section of the colors
+    /// definition.
+    pub fn set_color_id(self, color_id: &str) -> Self {
+        self.modify_entry(|entry| entry.color_id = Some(color_id.to_string()))
+    }
+
+    /// Set a custom color in the hexadecimal format "#0088aa", this overrides the
+    /// color ID and sets `colorRgbFormat=true`.
+    pub fn set_colors(mut self, background: &str, foreground: &str) -> Self {
+        self.request
+            .params
+            .insert("colorRgbFormat".to_string(), "true".to_string());
+        self.modify_entry(|entry| {
+            entry.background_color = Some(background.to_string());
+            entry.foreground_color = Some(foreground.to_string());
+        })
+    }
+
+    /// Set whether the calendar is hidden from the list.
+    pub fn set_hidden(self, hidden: bool) -> Self {
+        self.modify_entry(|entry| entry.hidden = Some(hidden))
+    }
+
+    /// Set whether the events of the calendar are shown in the calendar UI.
+    pub fn set_selected(self, selected: bool) -> Self {
+        self.modify_entry(|entry| entry.selected = Some(selected))
+    }
+
+    /// Set the default reminders of the user for this calendar.
+    pub fn set_default_reminders(self, reminders: Vec<EventDefaultReminder>) -> Self {
+        self.modify_entry(|entry| entry.default_reminders = Some(reminders))
+    }
+
+    /// Set the notifications the user receives for this calendar.
+    pub fn set_notification_settings(self, settings: NotificationSettings) -> Self {
+        self.modify_entry(|entry| entry.notification_settings = Some(settings))
+    }
+
+    /// Executes the request and returns the added calendar list entry.
+    pub async fn request(&mut self) -> Result<Option<CalendarListEntry>, GoogleApiError> {
+        self.make_request().await
+    }
+
+    fn modify_entry<F>(mut self, modifier: F) -> Self
+    where
+        F: FnOnce(&mut CalendarListEntryRequest),
+    {
+        if let Some(ref mut entry) = self.entry {
+            modifier(entry);
+        }
+        self
+    }
+}
+
+impl<'a> CalendarListClient<'a, CalendarListPatchMode> {
+    /// Set the name the user sees for this calendar.
+    pub fn set_summary_override(self, summary: &str) -> Self {
+        self.modify_entry(|entry| entry.summary_override = Some(summary.to_string()))
+    }
+
+    /// Set the color ID of the calendar, see the calendar section of the colors
+    /// definition.
+    pub fn set_color_id(self, color_id: &str) -> Self {
+        self.modify_entry(|entry| entry.color_id = Some(color_id.to_string()))
+    }
+
+    /// Set a custom color in the hexadecimal format "#0088aa", this overrides the
+    /// color ID and sets `colorRgbFormat=true`.
+    pub fn set_colors(mut self, background: &str, foreground: &str) -> Self {
+        self.request
+            .params
+            .insert("colorRgbFormat".to_string(), "true".to_string());
+        self.modify_entry(|entry| {
+            entry.background_color = Some(background.to_string());
+            entry.foreground_color = Some(foreground.to_string());
+        })
+    }
+
+    /// Set whether the calendar is hidden from the list.
+    pub fn set_hidden(self, hidden: bool) -> Self {
+        self.modify_entry(|entry| entry.hidden = Some(hidden))
+    }
+
+    /// Set whether the events of the calendar are shown in the calendar UI.
+    pub fn set_selected(self, selected: bool) -> Self {
+        self.modify_entry(|entry| entry.selected = Some(selected))
+    }
+
+    /// Set the default reminders of the user for this calendar.
+    pub fn set_default_reminders(self, reminders: Vec<EventDefaultReminder>) -> Self {
+        self.modify_entry(|entry| entry.default_reminders = Some(reminders))
+    }
+
+    /// Set the notifications the user receives for this calendar.
+    pub fn set_notification_settings(self, settings: NotificationSettings) -> Self {
+        self.modify_entry(|entry| entry.notification_settings = Some(settings))
+    }
+
+    /// Executes the request and returns the updated calendar list entry.
+    pub async fn request(&mut self) -> Result<Option<CalendarListEntry>, GoogleApiError> {
+        self.make_request().await
+    }
+
+    fn modify_entry<F>(mut self, modifier: F) -> Self
+    where
+        F: FnOnce(&mut CalendarListEntryRequest),
+    {
+        if let Some(ref mut entry) = self.entry {
+            modifier(entry);
+        }
+        self
+    }
+}
+
+impl<'a> CalendarListClient<'a, CalendarListDeleteMode> {
+    /// Executes the request to remove the calendar from the list.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), GoogleApiError>` - `Ok(())` if the calendar was removed
+    pub async fn request(&mut self) -> Result<(), GoogleApiError> {
+        self.request.execute_empty().await
+    }
+}
+
+impl<'a> CalendarListClient<'a, CalendarListWatchMode> {
+    /// Set a token that is sent back with every notification, to verify its origin.
+    pub fn set_token(mut self, token: &str) -> Self {
+        if let Some(ref mut channel) = self.channel {
+            channel.token = token.to_string();
+        }
+        self
+    }
+
+    /// Request the channel to expire after this many seconds.
+    pub fn set_ttl(mut self, seconds: u64) -> Self {
+        if let Some(ref mut channel) = self.channel {
+            channel
+                .params
+                .insert("ttl".to_string(), seconds.to_string());
+        }
+        self
+    }
+
+    /// Executes the request to create the channel.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(Channel))` - The created channel with its `resource_id` and expiration
+    /// * `Err(GoogleApiError)` - If the request failed or Google returned a non-2xx status
+    pub async fn request(&mut self) -> Result<Option<Channel>, GoogleApiError> {
+        self.make_request().await
+    }
+}
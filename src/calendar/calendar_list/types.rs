@@ -0,0 +1,354 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::calendar::{
+    calendars::types::ConferenceProperties, events::types::EventDefaultReminder,
+};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
+pub struct CalendarList {
+    /**
+     * Type of the collection ("calendar#calendarList").
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
+    )]
+    pub kind: String,
+
+    /**
+     * ETag of the collection.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
+    )]
+    pub etag: String,
+
+    /**
+     * Token used to access the next page of this result.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize",
+        rename = "nextPageToken"
+    )]
+    pub next_page_token: String,
+
+    /**
+     * Token used at a later point in time to retrieve only the entries that have changed since
+     * this result was returned.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize",
+        rename = "nextSyncToken"
+    )]
+    pub next_sync_token: String,
+
+    /**
+     * Calendars that are present on the user's calendar list.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_vec::deserialize"
+    )]
+    pub items: Vec<CalendarListEntry>,
+}
+
+/// A calendar on the calendar list of the user, with the settings of that user for it.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
+pub struct CalendarListEntry {
+    /**
+     * Type of the resource ("calendar#calendarListEntry").
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
+    )]
+    pub kind: String,
+
+    /**
+     * ETag of the resource.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
+    )]
+    pub etag: String,
+
+    /**
+     * Identifier of the calendar, use it as `calendar_id` for the other clients.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
+    )]
+    pub id: String,
+
+    /**
+     * Title of the calendar.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
+    )]
+    pub summary: String,
+
+    /**
+     * The summary that the authenticated user has set for this calendar.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize",
+        rename = "summaryOverride"
+    )]
+    pub summary_override: String,
+
+    /**
+     * Description of the calendar.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
+    )]
+    pub description: String,
+
+    /**
+     * Geographic location of the calendar as free-form text.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
+    )]
+    pub location: String,
+
+    /**
+     * The time zone of the calendar.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize",
+        rename = "timeZone"
+    )]
+    pub time_zone: String,
+
+    /**
+     * The color of the calendar, an ID in the calendar section of the colors definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize",
+        rename = "colorId"
+    )]
+    pub color_id: String,
+
+    /**
+     * The main color of the calendar in the hexadecimal format "#0088aa".
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize",
+        rename = "backgroundColor"
+    )]
+    pub background_color: String,
+
+    /**
+     * The foreground color of the calendar in the hexadecimal format "#ffffff".
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize",
+        rename = "foregroundColor"
+    )]
+    pub foreground_color: String,
+
+    /**
+     * Whether the calendar has been hidden from the list.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hidden: Option<bool>,
+
+    /**
+     * Whether the calendar content shows up in the calendar UI.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub selected: Option<bool>,
+
+    /**
+     * Whether the calendar is the primary calendar of the authenticated user.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub primary: Option<bool>,
+
+    /**
+     * Whether this calendar list entry has been deleted from the calendar list.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted: Option<bool>,
+
+    /**
+     * The effective access role that the authenticated user has on the calendar.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "accessRole"
+    )]
+    pub access_role: Option<AccessRole>,
+
+    /**
+     * The default reminders that the authenticated user has for this calendar.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_vec::deserialize",
+        rename = "defaultReminders"
+    )]
+    pub default_reminders: Vec<EventDefaultReminder>,
+
+    /**
+     * The notifications that the authenticated user is receiving for this calendar.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "notificationSettings"
+    )]
+    pub notification_settings: Option<NotificationSettings>,
+
+    /**
+     * Conferencing properties for this calendar.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "conferenceProperties"
+    )]
+    pub conference_properties: Option<ConferenceProperties>,
+}
+
+/// The access role of the user on a calendar, from least to most privileged.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum AccessRole {
+    /// Can only see free/busy information.
+    FreeBusyReader,
+    /// Can see non-private event details.
+    Reader,
+    /// Can create and modify events.
+    Writer,
+    /// Can also manage sharing of the calendar.
+    Owner,
+    /// A role that was added to the API after this version of the crate
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl AccessRole {
+    pub fn as_str(&self) -> &str {
+        match self {
+            AccessRole::FreeBusyReader => "freeBusyReader",
+            AccessRole::Reader => "reader",
+            AccessRole::Writer => "writer",
+            AccessRole::Owner => "owner",
+            AccessRole::Unknown(role) => role,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
+pub struct NotificationSettings {
+    /**
+     * The list of notifications set for this calendar.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_vec::deserialize"
+    )]
+    pub notifications: Vec<CalendarNotificationSetting>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
+pub struct CalendarNotificationSetting {
+    /**
+     * The type of notification. Possible values are: "eventCreation", "eventChange",
+     * "eventCancellation", "eventResponse", "agenda".
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+
+    /**
+     * The method used to deliver the notification, the only possible value is "email".
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
+    )]
+    pub method: String,
+}
+
+/// Body for adding or patching a calendar list entry, only the fields that are set are sent.
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarListEntryRequest {
+    /// Identifier of the calendar, required when adding a calendar to the list
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// The summary that the authenticated user has set for this calendar
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary_override: Option<String>,
+
+    /// The color of the calendar, an ID in the calendar section of the colors definition
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color_id: Option<String>,
+
+    /// The main color of the calendar in the hexadecimal format "#0088aa"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background_color: Option<String>,
+
+    /// The foreground color of the calendar in the hexadecimal format "#ffffff"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foreground_color: Option<String>,
+
+    /// Whether the calendar has been hidden from the list
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hidden: Option<bool>,
+
+    /// Whether the calendar content shows up in the calendar UI
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected: Option<bool>,
+
+    /// The default reminders that the authenticated user has for this calendar
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_reminders: Option<Vec<EventDefaultReminder>>,
+
+    /// The notifications that the authenticated user is receiving for this calendar
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notification_settings: Option<NotificationSettings>,
+}
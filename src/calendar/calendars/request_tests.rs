@@ -0,0 +1,85 @@
+#![allow(clippy::unwrap_used)]
+
+use reqwest::Method;
+
+use super::{requests::CalendarsClient, types::Calendar};
+use crate::{error::GoogleApiError, utils::test_support::client};
+
+#[tokio::test]
+async fn insert_calendar_sends_summary_and_time_zone() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/calendar/v3/calendars")
+        .match_body(mockito::Matcher::Json(serde_json::json!({
+            "summary": "Team holidays",
+            "timeZone": "Europe/Amsterdam"
+        })))
+        .with_status(200)
+        .with_body(r#"{"id": "new@group.calendar.google.com", "summary": "Team holidays", "timeZone": "Europe/Amsterdam"}"#)
+        .create_async()
+        .await;
+
+    let gc = client(&server.url());
+    let calendar = CalendarsClient::new(&gc)
+        .insert_calendar("Team holidays")
+        .set_time_zone("Europe/Amsterdam")
+        .request()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(calendar.id, "new@group.calendar.google.com");
+    assert_eq!(calendar.time_zone, "Europe/Amsterdam");
+    mock.assert_async().await;
+}
+
+#[test]
+fn update_calendar_sends_only_writable_fields() {
+    let gc = client("https://example.com");
+    let calendar: Calendar = serde_json::from_str(
+        r#"{"kind": "calendar#calendar", "etag": "\"1\"", "id": "cal_1", "summary": "Renamed", "timeZone": "UTC"}"#,
+    )
+    .unwrap();
+
+    let builder = CalendarsClient::new(&gc).update_calendar("cal_1", calendar);
+
+    assert_eq!(builder.request.method, Method::PUT);
+    assert_eq!(
+        builder.request.url,
+        "https://example.com/calendar/v3/calendars/cal_1"
+    );
+    assert_eq!(
+        serde_json::to_value(builder.calendar.as_ref().unwrap()).unwrap(),
+        serde_json::json!({"summary": "Renamed", "timeZone": "UTC"})
+    );
+}
+
+#[tokio::test]
+async fn clear_and_delete_calendar() {
+    let mut server = mockito::Server::new_async().await;
+    let clear = server
+        .mock("POST", "/calendar/v3/calendars/primary/clear")
+        .with_status(204)
+        .create_async()
+        .await;
+    let _delete = server
+        .mock("DELETE", "/calendar/v3/calendars/primary")
+        .with_status(400)
+        .with_body(r#"{"error": {"code": 400, "message": "Cannot delete primary calendar."}}"#)
+        .create_async()
+        .await;
+
+    let gc = client(&server.url());
+    CalendarsClient::new(&gc)
+        .clear_calendar("primary")
+        .request()
+        .await
+        .unwrap();
+    let res = CalendarsClient::new(&gc)
+        .delete_calendar("primary")
+        .request()
+        .await;
+
+    assert!(matches!(res, Err(GoogleApiError::Status { .. })));
+    clear.assert_async().await;
+}
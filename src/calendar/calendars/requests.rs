@@ -0,0 +1,288 @@
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+
+use crate::{auth::client::GoogleClient, error::GoogleApiError, utils::request::Request};
+
+use super::types::{Calendar, CalendarRequest, ConferenceProperties};
+
+/// Indicates that the request builder is not yet initialized with a specific mode.
+pub struct Uninitialized;
+/// Indicates that the request builder is initialized for retrieving a calendar.
+pub struct CalendarGetMode;
+/// Indicates that the request builder is initialized for creating a secondary calendar.
+pub struct CalendarInsertMode;
+/// Indicates that the request builder is initialized for patching a calendar.
+pub struct CalendarPatchMode;
+/// Indicates that the request builder is initialized for replacing a calendar.
+pub struct CalendarUpdateMode;
+/// Indicates that the request builder is initialized for deleting a secondary calendar.
+pub struct CalendarDeleteMode;
+/// Indicates that the request builder is initialized for clearing a primary calendar.
+pub struct CalendarClearMode;
+
+/// Client for the metadata of calendars, like the title and time zone.
+///
+/// The settings a user has for a calendar, like its color, are managed with the
+/// `CalendarListClient`.
+///
+/// The generic type parameter `T` determines the mode of operation for this client,
+/// which affects which methods are available and what parameters can be set.
+pub struct CalendarsClient<'a, T = Uninitialized> {
+    pub(super) request: Request<'a>,
+    pub(super) calendar: Option<CalendarRequest>,
+    pub(super) _mode: std::marker::PhantomData<T>,
+}
+
+impl<'a> CalendarsClient<'a, Uninitialized> {
+    /// Creates a new calendars client using the provided Google client for authentication.
+    pub fn new(client: &'a GoogleClient) -> Self {
+        Self {
+            request: Request::new(client),
+            calendar: None,
+            _mode: std::marker::PhantomData,
+        }
+    }
+
+    /// Get the metadata of a calendar.
+    ///
+    /// # Arguments
+    ///
+    /// * `calendar_id` - The ID of the calendar, "primary" for the main calendar of the user
+    pub fn get_calendar(self, calendar_id: &str) -> CalendarsClient<'a, CalendarGetMode> {
+        self.into_mode(calendar_id, Method::GET, None)
+    }
+
+    /// Create a secondary calendar owned by the user.
+    ///
+    /// # Arguments
+    ///
+    /// * `summary` - The title of the new calendar
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let calendar = CalendarsClient::new(&client)
+    ///     .insert_calendar("Team holidays")
+    ///     .set_time_zone("Europe/Amsterdam")
+    ///     .request()
+    ///     .await?
+    ///     .unwrap();
+    /// // Events can be added with calendar.id as calendar_id
+    /// ```
+    pub fn insert_calendar(self, summary: &str) -> CalendarsClient<'a, CalendarInsertMode> {
+        let calendar = CalendarRequest {
+            summary: Some(summary.to_string()),
+            ..Default::default()
+        };
+        let mut builder = self.into_mode("", Method::POST, Some(calendar));
+        builder.request.url = format!("{}/calendars", builder.request.client.endpoints.calendar);
+        builder
+    }
+
+    /// Patch the metadata of a calendar, only the fields that are set are changed.
+    ///
+    /// # Arguments
+    ///
+    /// * `calendar_id` - The ID of the calendar to modify
+    pub fn patch_calendar(self, calendar_id: &str) -> CalendarsClient<'a, CalendarPatchMode> {
+        self.into_mode(calendar_id, Method::PATCH, Some(CalendarRequest::default()))
+    }
+
+    /// Replace the metadata of a calendar, fields missing from `calendar` are cleared.
+    ///
+    /// # Arguments
+    ///
+    /// * `calendar_id` - The ID of the calendar to replace
+    /// * `calendar` - The new version of the calendar, typically fetched with `get_calendar`
+    pub fn update_calendar(
+        self,
+        calendar_id: &str,
+        calendar: Calendar,
+    ) -> CalendarsClient<'a, CalendarUpdateMode> {
+        self.into_mode(calendar_id, Method::PUT, Some(calendar.into()))
+    }
+
+    /// Delete a secondary calendar, use `clear_calendar` for the primary calendar.
+    ///
+    /// # Arguments
+    ///
+    /// * `calendar_id` - The ID of the calendar to delete
+    pub fn delete_calendar(self, calendar_id: &str) -> CalendarsClient<'a, CalendarDeleteMode> {
+        self.into_mode(calendar_id, Method::DELETE, None)
+    }
+
+    /// Delete all events of a primary calendar.
+    ///
+    /// # Arguments
+    ///
+    /// * `calendar_id` - The ID of the primary calendar, usually "primary"
+    pub fn clear_calendar(self, calendar_id: &str) -> CalendarsClient<'a, CalendarClearMode> {
+        let mut builder = self.into_mode(calendar_id, Method::POST, None);
+        builder.request.url.push_str("/clear");
+        builder
+    }
+
+    fn into_mode<M>(
+        self,
+        calendar_id: &str,
+        method: Method,
+        calendar: Option<CalendarRequest>,
+    ) -> CalendarsClient<'a, M> {
+        let mut builder = CalendarsClient {
+            request: self.request,
+            calendar,
+            _mode: std::marker::PhantomData,
+        };
+        builder.request.url = format!(
+            "{}/calendars/{calendar_id}",
+            builder.request.client.endpoints.calendar
+        );
+        builder.request.method = method;
+        builder
+    }
+}
+
+impl<'a, T> CalendarsClient<'a, T> {
+    async fn make_request<R>(&mut self) -> Result<Option<R>, GoogleApiError>
+    where
+        R: DeserializeOwned,
+    {
+        if let Some(calendar) = &self.calendar {
+            self.request.set_json_body(calendar)?;
+        }
+        self.request.execute().await.map(Some)
+    }
+
+    fn modify_calendar<F>(mut self, modifier: F) -> Self
+    where
+        F: FnOnce(&mut CalendarRequest),
+    {
+        if let Some(ref mut calendar) = self.calendar {
+            modifier(calendar);
+        }
+        self
+    }
+}
+
+impl<'a> CalendarsClient<'a, CalendarGetMode> {
+    /// Returns a request result for getting the calendar.
+    pub async fn request(&mut self) -> Result<Option<Calendar>, GoogleApiError> {
+        self.make_request().await
+    }
+}
+
+impl<'a> CalendarsClient<'a, CalendarInsertMode> {
+    /// Sets the description of the new calendar.
+    pub fn set_description(self, descr: &str) -> Self {
+        self.modify_calendar(|calendar| calendar.description = Some(descr.to_string()))
+    }
+
+    /// Sets the location of the new calendar.
+    pub fn set_location(self, location: &str) -> Self {
+        self.modify_calendar(|calendar| calendar.location = Some(location.to_string()))
+    }
+
+    /// Sets the IANA time zone of the new calendar, e.g. "Europe/Amsterdam".
+    pub fn set_time_zone(self, time_zone: &str) -> Self {
+        self.modify_calendar(|calendar| calendar.time_zone = Some(time_zone.to_string()))
+    }
+
+    /// Sets the conference solutions that can be used in the new calendar.
+    pub fn set_conference_properties(self, properties: ConferenceProperties) -> Self {
+        self.modify_calendar(|calendar| calendar.conference_properties = Some(properties))
+    }
+
+    /// Executes the request to create the calendar.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(Calendar))` - The created calendar if successful
+    /// * `Err(GoogleApiError)` - If the request failed or Google returned a non-2xx status
+    pub async fn request(&mut self) -> Result<Option<Calendar>, GoogleApiError> {
+        self.make_request().await
+    }
+}
+
+impl<'a> CalendarsClient<'a, CalendarPatchMode> {
+    /// Patch the title of the calendar.
+    pub fn set_summary(self, summary: &str) -> Self {
+        self.modify_calendar(|calendar| calendar.summary = Some(summary.to_string()))
+    }
+
+    /// Patch the description of the calendar.
+    pub fn set_description(self, descr: &str) -> Self {
+        self.modify_calendar(|calendar| calendar.description = Some(descr.to_string()))
+    }
+
+    /// Patch the location of the calendar.
+    pub fn set_location(self, location: &str) -> Self {
+        self.modify_calendar(|calendar| calendar.location = Some(location.to_string()))
+    }
+
+    /// Patch the IANA time zone of the calendar, e.g. "Europe/Amsterdam".
+    pub fn set_time_zone(self, time_zone: &str) -> Self {
+        self.modify_calendar(|calendar| calendar.time_zone = Some(time_zone.to_string()))
+    }
+
+    /// Patch the conference solutions that can be used in the calendar.
+    pub fn set_conference_properties(self, properties: ConferenceProperties) -> Self {
+        self.modify_calendar(|calendar| calendar.conference_properties = Some(properties))
+    }
+
+    /// Executes the request to patch the calendar.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(Calendar))` - The patched calendar if successful
+    /// * `Err(GoogleApiError)` - If the request failed or Google returned a non-2xx status
+    pub async fn request(&mut self) -> Result<Option<Calendar>, GoogleApiError> {
+        self.make_request().await
+    }
+}
+
+impl<'a> CalendarsClient<'a, CalendarUpdateMode> {
+    /// Executes the request to replace the calendar.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(Calendar))` - The updated calendar if successful
+    /// * `Err(GoogleApiError)` - If the request failed or Google returned a non-2xx status
+    pub async fn request(&mut self) -> Result<Option<Calendar>, GoogleApiError> {
+        self.make_request().await
+    }
+}
+
+impl<'a> CalendarsClient<'a, CalendarDeleteMode> {
+    /// Executes the request to delete the calendar.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), GoogleApiError>` - `Ok(())` if the calendar was deleted
+    pub async fn request(&mut self) -> Result<(), GoogleApiError> {
+        self.request.execute_empty().await
+    }
+}
+
+impl<'a> CalendarsClient<'a, CalendarClearMode> {
+    /// Executes the request to delete all events of the calendar.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), GoogleApiError>` - `Ok(())` if the calendar was cleared
+    pub async fn request(&mut self) -> Result<(), GoogleApiError> {
+        self.request.execute_empty().await
+    }
+}
+
+impl From<Calendar> for CalendarRequest {
+    fn from(calendar: Calendar) -> Self {
+        let non_empty = |value: String| (!value.is_empty()).then_some(value);
+        CalendarRequest {
+            summary: non_empty(calendar.summary),
+            description: non_empty(calendar.description),
+            location: non_empty(calendar.location),
+            time_zone: non_empty(calendar.time_zone),
+            conference_properties: calendar.conference_properties,
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Metadata of a calendar, shared by everyone with access to it.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
+pub struct Calendar {
+    /**
+     * Type of the resource ("calendar#calendar").
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
+    )]
+    pub kind: String,
+
+    /**
+     * ETag of the resource.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
+    )]
+    pub etag: String,
+
+    /**
+     * Identifier of the calendar.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
+    )]
+    pub id: String,
+
+    /**
+     * Title of the calendar.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
+    )]
+    pub summary: String,
+
+    /**
+     * Description of the calendar.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
+    )]
+    pub description: String,
+
+    /**
+     * Geographic location of the calendar as free-form text.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
+    )]
+    pub location: String,
+
+    /**
+     * The time zone of the calendar, formatted as an IANA Time Zone Database name, e.g.
+     * "Europe/Zurich".
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize",
+        rename = "timeZone"
+    )]
+    pub time_zone: String,
+
+    /**
+     * Conferencing properties for this calendar.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "conferenceProperties"
+    )]
+    pub conference_properties: Option<ConferenceProperties>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
+pub struct ConferenceProperties {
+    /**
+     * The types of conference solutions that are supported for this calendar.
+     * Possible values are: "eventHangout", "eventNamedHangout", "hangoutsMeet".
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_vec::deserialize",
+        rename = "allowedConferenceSolutionTypes"
+    )]
+    pub allowed_conference_solution_types: Vec<String>,
+}
+
+/// Body for creating or patching a calendar, only the fields that are set are sent.
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarRequest {
+    /// Title of the calendar, required when creating a calendar
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+
+    /// Description of the calendar
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Geographic location of the calendar as free-form text
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+
+    /// IANA time zone of the calendar, e.g. "Europe/Zurich"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_zone: Option<String>,
+
+    /// Conferencing properties of the calendar
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conference_properties: Option<ConferenceProperties>,
+}
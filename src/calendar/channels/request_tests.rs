@@ -0,0 +1,203 @@
+#![allow(clippy::unwrap_used)]
+
+use chrono::{TimeZone, Utc};
+
+use super::{
+    requests::ChannelsClient,
+    types::{CalendarNotification, Channel, ResourceState},
+};
+use crate::{
+    calendar::events::requests::CalendarEventsClient, error::GoogleApiError,
+    utils::test_support::client,
+};
+
+fn notification_headers() -> Vec<(&'static str, &'static str)> {
+    vec![
+        ("X-Goog-Channel-ID", "channel-1"),
+        ("X-Goog-Message-Number", "7"),
+        ("X-Goog-Resource-ID", "resource-1"),
+        (
+            "X-Goog-Resource-URI",
+            "https://www.googleapis.com/calendar/v3/calendars/primary/events",
+        ),
+        ("X-Goog-Resource-State", "exists"),
+        ("X-Goog-Channel-Token", "secret"),
+        ("X-Goog-Channel-Expiration", "Tue, 19 Nov 2024 01:13:52 GMT"),
+    ]
+}
+
+#[test]
+fn parses_notification_headers_case_insensitively() {
+    let headers = notification_headers()
+        .into_iter()
+        .map(|(k, v)| (k.to_ascii_lowercase(), v));
+    let notification = CalendarNotification::from_headers(headers).unwrap();
+
+    assert_eq!(notification.channel_id, "channel-1");
+    assert_eq!(notification.message_number, 7);
+    assert_eq!(notification.resource_id, "resource-1");
+    assert_eq!(notification.resource_state, ResourceState::Exists);
+    assert_eq!(
+        notification.channel_expiration,
+        Some(Utc.with_ymd_and_hms(2024, 11, 19, 1, 13, 52).unwrap())
+    );
+    assert!(notification.verify_token("secret"));
+    assert!(!notification.verify_token("secreT"));
+    assert!(!notification.verify_token("secret2"));
+}
+
+#[test]
+fn optional_headers_may_be_missing() {
+    let headers = notification_headers()
+        .into_iter()
+        .filter(|(k, _)| !k.starts_with("X-Goog-Channel-Token") && !k.ends_with("Expiration"))
+        .map(|(k, v)| {
+            (
+                k,
+                if k == "X-Goog-Resource-State" {
+                    "sync"
+                } else {
+                    v
+                },
+            )
+        });
+    let notification = CalendarNotification::from_headers(headers).unwrap();
+
+    assert_eq!(notification.resource_state, ResourceState::Sync);
+    assert_eq!(notification.channel_token, None);
+    assert_eq!(notification.channel_expiration, None);
+    assert!(!notification.verify_token("secret"));
+}
+
+#[test]
+fn rejects_missing_or_invalid_headers() {
+    let without_id = notification_headers()
+        .into_iter()
+        .filter(|(k, _)| *k != "X-Goog-Channel-ID");
+    assert!(matches!(
+        CalendarNotification::from_headers(without_id),
+        Err(GoogleApiError::InvalidRequest(message)) if message.contains("X-Goog-Channel-ID")
+    ));
+
+    for (name, value) in [
+        ("X-Goog-Message-Number", "seven"),
+        ("X-Goog-Resource-State", "changed"),
+        ("X-Goog-Channel-Expiration", "tomorrow"),
+    ] {
+        let headers = notification_headers()
+            .into_iter()
+            .map(|(k, v)| (k, if k == name { value } else { v }));
+        assert!(
+            CalendarNotification::from_headers(headers).is_err(),
+            "{name}: {value} should be rejected"
+        );
+    }
+}
+
+#[tokio::test]
+async fn parses_notification_received_by_web_server() {
+    use axum::{http::HeaderMap, http::StatusCode, routing::post, Router};
+
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    let app = Router::new().route(
+        "/webhooks/calendar",
+        post(move |headers: HeaderMap| {
+            let tx = tx.clone();
+            async move {
+                match CalendarNotification::from_header_map(&headers) {
+                    Ok(notification) => {
+                        tx.send(notification).unwrap();
+                        StatusCode::OK
+                    }
+                    Err(_) => StatusCode::BAD_REQUEST,
+                }
+            }
+        }),
+    );
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+    let url = format!("http://{address}/webhooks/calendar");
+    let http = reqwest::Client::new();
+    let mut request = http.post(&url);
+    for (name, value) in notification_headers() {
+        request = request.header(name, value);
+    }
+    assert_eq!(request.send().await.unwrap().status(), 200);
+
+    let notification = rx.recv().await.unwrap();
+    assert_eq!(notification.channel_id, "channel-1");
+    assert_eq!(notification.channel_token.as_deref(), Some("secret"));
+
+    let invalid = http
+        .post(&url)
+        .header("X-Goog-Channel-ID", "channel-1")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(invalid.status(), 400);
+}
+
+#[tokio::test]
+async fn watch_events_creates_channel() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/calendar/v3/calendars/primary/events/watch")
+        .match_body(mockito::Matcher::Json(serde_json::json!({
+            "id": "channel-1",
+            "type": "web_hook",
+            "address": "https://example.com/webhooks/calendar",
+            "token": "secret",
+            "params": {"ttl": "3600"}
+        })))
+        .with_status(200)
+        .with_body(
+            r#"{"kind": "api#channel", "id": "channel-1", "resourceId": "resource-1", "expiration": "1732000000000"}"#,
+        )
+        .create_async()
+        .await;
+
+    let gc = client(&server.url());
+    let channel = CalendarEventsClient::new(&gc)
+        .watch_events(
+            "primary",
+            Channel::web_hook("channel-1", "https://example.com/webhooks/calendar"),
+        )
+        .set_token("secret")
+        .set_ttl(3600)
+        .request()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(channel.resource_id, "resource-1");
+    assert_eq!(
+        channel.expires_at(),
+        Utc.timestamp_millis_opt(1732000000000).single()
+    );
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn stop_channel_sends_ids() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/calendar/v3/channels/stop")
+        .match_body(mockito::Matcher::Json(serde_json::json!({
+            "id": "channel-1",
+            "resourceId": "resource-1"
+        })))
+        .with_status(204)
+        .create_async()
+        .await;
+
+    let gc = client(&server.url());
+    ChannelsClient::new(&gc)
+        .stop_channel("channel-1", "resource-1")
+        .request()
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+}
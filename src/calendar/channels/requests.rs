@@ -0,0 +1,73 @@
+use reqwest::Method;
+
+use crate::{auth::client::GoogleClient, error::GoogleApiError, utils::request::Request};
+
+use super::types::StopChannelRequest;
+
+/// Indicates that the request builder is not yet initialized with a specific mode.
+pub struct Uninitialized;
+/// Indicates that the request builder is initialized for stopping a channel.
+pub struct ChannelStopMode;
+
+/// Client for notification channels created with one of the watch requests, like
+/// `CalendarEventsClient::watch_events`.
+///
+/// The generic type parameter `T` determines the mode of operation for this client,
+/// which affects which methods are available and what parameters can be set.
+pub struct ChannelsClient<'a, T = Uninitialized> {
+    pub(super) request: Request<'a>,
+    pub(super) stop: Option<StopChannelRequest>,
+    pub(super) _mode: std::marker::PhantomData<T>,
+}
+
+impl<'a> ChannelsClient<'a, Uninitialized> {
+    /// Creates a new channels client using the provided Google client for authentication.
+    pub fn new(client: &'a GoogleClient) -> Self {
+        Self {
+            request: Request::new(client),
+            stop: None,
+            _mode: std::marker::PhantomData,
+        }
+    }
+
+    /// Stop receiving notifications on a channel.
+    ///
+    /// # Arguments
+    ///
+    /// * `channel_id` - The `id` of the channel
+    /// * `resource_id` - The `resource_id` returned when the channel was created
+    pub fn stop_channel(
+        self,
+        channel_id: &str,
+        resource_id: &str,
+    ) -> ChannelsClient<'a, ChannelStopMode> {
+        let mut builder = ChannelsClient {
+            request: self.request,
+            stop: Some(StopChannelRequest {
+                id: channel_id.to_string(),
+                resource_id: resource_id.to_string(),
+            }),
+            _mode: std::marker::PhantomData,
+        };
+        builder.request.url = format!(
+            "{}/channels/stop",
+            builder.request.client.endpoints.calendar
+        );
+        builder.request.method = Method::POST;
+        builder
+    }
+}
+
+impl<'a> ChannelsClient<'a, ChannelStopMode> {
+    /// Executes the request to stop the channel.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), GoogleApiError>` - `Ok(())` if the channel was stopped
+    pub async fn request(&mut self) -> Result<(), GoogleApiError> {
+        if let Some(stop) = &self.stop {
+            self.request.set_json_body(stop)?;
+        }
+        self.request.execute_empty().await
+    }
+}
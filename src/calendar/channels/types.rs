@@ -0,0 +1,274 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{error::GoogleApiError, utils::compare::constant_time_eq};
+
+/// A notification channel, used to watch a resource for changes.
+///
+/// Returned by the watch requests, keep `id` and `resource_id` to stop the channel later.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
+pub struct Channel {
+    /**
+     * Identifies this as a notification channel ("api#channel").
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
+    )]
+    pub kind: String,
+
+    /**
+     * A UUID or similar unique string that identifies this channel.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
+    )]
+    pub id: String,
+
+    /**
+     * An opaque ID that identifies the resource being watched on this channel.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize",
+        rename = "resourceId"
+    )]
+    pub resource_id: String,
+
+    /**
+     * A version-specific identifier for the watched resource.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize",
+        rename = "resourceUri"
+    )]
+    pub resource_uri: String,
+
+    /**
+     * An arbitrary string delivered with each notification, e.g. to verify its origin.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
+    )]
+    pub token: String,
+
+    /**
+     * Date and time of notification channel expiration, as a Unix timestamp in milliseconds.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
+    )]
+    pub expiration: String,
+
+    /**
+     * The type of delivery mechanism used for this channel, always "web_hook".
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+
+    /**
+     * The HTTPS address where notifications are delivered for this channel.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
+    )]
+    pub address: String,
+
+    /**
+     * Additional parameters controlling delivery channel behavior, e.g. "ttl" in seconds.
+     */
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub params: HashMap<String, String>,
+}
+
+impl Channel {
+    /// A web hook channel delivering notifications to `address`, which has to be HTTPS.
+    pub fn web_hook(id: &str, address: &str) -> Self {
+        Self {
+            id: id.to_string(),
+            type_: "web_hook".to_string(),
+            address: address.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// The expiration of the channel, if Google returned one.
+    pub fn expires_at(&self) -> Option<DateTime<Utc>> {
+        DateTime::from_timestamp_millis(self.expiration.parse().ok()?)
+    }
+}
+
+/// Body of a `channels.stop` request.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct StopChannelRequest {
+    pub id: String,
+    pub resource_id: String,
+}
+
+/// What happened to the watched resource.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResourceState {
+    /// The first message after the channel was created, no change happened.
+    Sync,
+    /// The resource was created or changed.
+    Exists,
+    /// The resource was deleted.
+    NotExists,
+}
+
+impl ResourceState {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ResourceState::Sync => "sync",
+            ResourceState::Exists => "exists",
+            ResourceState::NotExists => "not_exists",
+        }
+    }
+}
+
+/// A push notification sent by Google to the address of a watch channel.
+///
+/// The notification only tells that something changed, fetch the resource (e.g. with a sync
+/// token) to see what changed. Respond with a 2xx status, otherwise Google retries the
+/// delivery.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalendarNotification {
+    /// `X-Goog-Channel-ID`, the `id` of the channel.
+    pub channel_id: String,
+    /// `X-Goog-Message-Number`, increases with every message on the channel, 1 for `Sync`.
+    pub message_number: u64,
+    /// `X-Goog-Resource-ID`, identifies the watched resource.
+    pub resource_id: String,
+    /// `X-Goog-Resource-URI`, the API URL of the watched resource.
+    pub resource_uri: String,
+    /// `X-Goog-Resource-State`
+    pub resource_state: ResourceState,
+    /// `X-Goog-Channel-Token`, the `token` the channel was created with.
+    pub channel_token: Option<String>,
+    /// `X-Goog-Channel-Expiration`
+    pub channel_expiration: Option<DateTime<Utc>>,
+}
+
+impl CalendarNotification {
+    /// Parses the headers of a notification request. Header names are matched case
+    /// insensitively, so this works with the header types of any web framework.
+    ///
+    /// # Example
+    /// ```rust
+    /// async fn calendar_webhook(headers: HeaderMap) -> StatusCode {
+    ///     let Ok(notification) = CalendarNotification::from_header_map(&headers) else {
+    ///         return StatusCode::BAD_REQUEST;
+    ///     };
+    ///     if !notification.verify_token(&config.channel_token) {
+    ///         return StatusCode::FORBIDDEN;
+    ///     }
+    ///     if notification.resource_state != ResourceState::Sync {
+    ///         sync_events(&notification.channel_id).await;
+    ///     }
+    ///     StatusCode::OK
+    /// }
+    /// ```
+    pub fn from_headers<I, K, V>(headers: I) -> Result<Self, GoogleApiError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let headers: HashMap<String, String> = headers
+            .into_iter()
+            .map(|(k, v)| {
+                (
+                    k.as_ref().to_ascii_lowercase(),
+                    v.as_ref().trim().to_string(),
+                )
+            })
+            .collect();
+        let optional = |name: &str| {
+            headers
+                .get(&name.to_ascii_lowercase())
+                .filter(|v| !v.is_empty())
+                .cloned()
+        };
+        let required = |name: &str| {
+            optional(name).ok_or_else(|| {
+                GoogleApiError::InvalidRequest(format!("Notification is missing the {name} header"))
+            })
+        };
+
+        let message_number = required("X-Goog-Message-Number")?;
+        let message_number = message_number.parse().map_err(|_| {
+            GoogleApiError::InvalidRequest(format!(
+                "Invalid X-Goog-Message-Number header: {message_number}"
+            ))
+        })?;
+        let resource_state = match required("X-Goog-Resource-State")?.as_str() {
+            "sync" => ResourceState::Sync,
+            "exists" => ResourceState::Exists,
+            "not_exists" => ResourceState::NotExists,
+            state => {
+                return Err(GoogleApiError::InvalidRequest(format!(
+                    "Unknown X-Goog-Resource-State header: {state}"
+                )))
+            }
+        };
+        let channel_expiration = match optional("X-Goog-Channel-Expiration") {
+            Some(expiration) => Some(
+                DateTime::parse_from_rfc2822(&expiration)
+                    .map_err(|_| {
+                        GoogleApiError::InvalidRequest(format!(
+                            "Invalid X-Goog-Channel-Expiration header: {expiration}"
+                        ))
+                    })?
+                    .with_timezone(&Utc),
+            ),
+            None => None,
+        };
+
+        Ok(Self {
+            channel_id: required("X-Goog-Channel-ID")?,
+            message_number,
+            resource_id: required("X-Goog-Resource-ID")?,
+            resource_uri: required("X-Goog-Resource-URI")?,
+            resource_state,
+            channel_token: optional("X-Goog-Channel-Token"),
+            channel_expiration,
+        })
+    }
+
+    /// Parses the headers of a notification request from a `http::HeaderMap`, as used by
+    /// axum, reqwest and hyper. Headers that aren't valid UTF-8 are ignored.
+    pub fn from_header_map(headers: &reqwest::header::HeaderMap) -> Result<Self, GoogleApiError> {
+        Self::from_headers(
+            headers
+                .iter()
+                .filter_map(|(name, value)| Some((name.as_str(), value.to_str().ok()?))),
+        )
+    }
+
+    /// Checks the channel token against the token the channel was created with.
+    pub fn verify_token(&self, expected: &str) -> bool {
+        let token = self.channel_token.as_deref().unwrap_or_default();
+        constant_time_eq(token.as_bytes(), expected.as_bytes())
+    }
+}
@@ -0,0 +1,69 @@
+#![allow(clippy::unwrap_used)]
+
+use super::requests::ColorsClient;
+use crate::{
+    calendar::{calendar_list::types::CalendarListEntry, events::types::Event},
+    utils::test_support::client,
+};
+
+#[tokio::test]
+async fn get_colors_resolves_event_and_calendar_colors() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/calendar/v3/colors")
+        .with_status(200)
+        .with_body(
+            r##"{
+                "kind": "calendar#colors",
+                "updated": "2012-02-14T00:00:00.000Z",
+                "calendar": {"1": {"background": "#ac725e", "foreground": "#1d1d1d"}},
+                "event": {"11": {"background": "#dc2127", "foreground": "#1d1d1d"}}
+            }"##,
+        )
+        .create_async()
+        .await;
+
+    let gc = client(&server.url());
+    let colors = ColorsClient::new(&gc)
+        .get_colors()
+        .request()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(colors.event_color("11").unwrap().background, "#dc2127");
+    assert!(colors.calendar_color("11").is_none());
+
+    let calendar = CalendarListEntry {
+        color_id: "1".into(),
+        ..Default::default()
+    };
+    let colored: Event = serde_json::from_str(r#"{"id": "a", "colorId": "11"}"#).unwrap();
+    let uncolored: Event = serde_json::from_str(r#"{"id": "b"}"#).unwrap();
+    assert_eq!(
+        colors
+            .color_of_event(&colored, &calendar)
+            .unwrap()
+            .background,
+        "#dc2127"
+    );
+    assert_eq!(
+        colors
+            .color_of_event(&uncolored, &calendar)
+            .unwrap()
+            .background,
+        "#ac725e"
+    );
+
+    let custom = CalendarListEntry {
+        color_id: "1".into(),
+        background_color: "#000000".into(),
+        foreground_color: "#ffffff".into(),
+        ..Default::default()
+    };
+    assert_eq!(
+        colors.color_of_calendar(&custom).unwrap().foreground,
+        "#ffffff"
+    );
+    mock.assert_async().await;
+}
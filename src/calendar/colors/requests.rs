@@ -0,0 +1,52 @@
+use reqwest::Method;
+
+use crate::{auth::client::GoogleClient, error::GoogleApiError, utils::request::Request};
+
+use super::types::Colors;
+
+/// Indicates that the request builder is not yet initialized with a specific mode.
+pub struct Uninitialized;
+/// Indicates that the request builder is initialized for retrieving the color palettes.
+pub struct ColorsGetMode;
+
+/// Client for the color palettes of calendars and events.
+///
+/// The generic type parameter `T` determines the mode of operation for this client,
+/// which affects which methods are available and what parameters can be set.
+pub struct ColorsClient<'a, T = Uninitialized> {
+    pub(super) request: Request<'a>,
+    pub(super) _mode: std::marker::PhantomData<T>,
+}
+
+impl<'a> ColorsClient<'a, Uninitialized> {
+    /// Creates a new colors client using the provided Google client for authentication.
+    pub fn new(client: &'a GoogleClient) -> Self {
+        Self {
+            request: Request::new(client),
+            _mode: std::marker::PhantomData,
+        }
+    }
+
+    /// Get the color palettes. They rarely change, so the result can be cached.
+    pub fn get_colors(self) -> ColorsClient<'a, ColorsGetMode> {
+        let mut builder = ColorsClient {
+            request: self.request,
+            _mode: std::marker::PhantomData,
+        };
+        builder.request.url = format!("{}/colors", builder.request.client.endpoints.calendar);
+        builder.request.method = Method::GET;
+        builder
+    }
+}
+
+impl<'a> ColorsClient<'a, ColorsGetMode> {
+    /// Executes the request to get the color palettes.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(Colors))` - The calendar and event palettes if successful
+    /// * `Err(GoogleApiError)` - If the request failed or Google returned a non-2xx status
+    pub async fn request(&mut self) -> Result<Option<Colors>, GoogleApiError> {
+        self.request.execute().await.map(Some)
+    }
+}
@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::calendar::{calendar_list::types::CalendarListEntry, events::types::Event};
+
+/// The color palettes of Google Calendar, the `color_id` of calendars and events refers to
+/// an entry in these palettes.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
+pub struct Colors {
+    /**
+     * Type of the resource ("calendar#colors").
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
+    )]
+    pub kind: String,
+
+    /**
+     * Last modification time of the color palette (as a RFC3339 timestamp).
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::deserialize::deserialize_date_time_format::deserialize",
+        serialize_with = "crate::utils::serialize::deserialize_date_time_format::serialize"
+    )]
+    pub updated: Option<chrono::DateTime<chrono::Utc>>,
+
+    /**
+     * Palette of calendar colors, keyed by color ID. Used by the `color_id` of calendar list
+     * entries.
+     */
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub calendar: HashMap<String, ColorDefinition>,
+
+    /**
+     * Palette of event colors, keyed by color ID. Used by the `color_id` of events.
+     */
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub event: HashMap<String, ColorDefinition>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, JsonSchema, Default)]
+pub struct ColorDefinition {
+    /**
+     * The background color, in the hexadecimal format "#0088aa".
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
+    )]
+    pub background: String,
+
+    /**
+     * The foreground color that can be used to write on top of the background color, in the
+     * hexadecimal format "#1d1d1d".
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
+    )]
+    pub foreground: String,
+}
+
+impl Colors {
+    /// The color of a calendar with this `color_id`.
+    pub fn calendar_color(&self, color_id: &str) -> Option<&ColorDefinition> {
+        self.calendar.get(color_id)
+    }
+
+    /// The color of an event with this `color_id`.
+    pub fn event_color(&self, color_id: &str) -> Option<&ColorDefinition> {
+        self.event.get(color_id)
+    }
+
+    /// The color of a calendar as shown by Google Calendar: the custom colors of the entry if
+    /// set, otherwise the palette color of its `color_id`.
+    pub fn color_of_calendar(&self, entry: &CalendarListEntry) -> Option<ColorDefinition> {
+        if !entry.background_color.is_empty() {
+            return Some(ColorDefinition {
+                background: entry.background_color.clone(),
+                foreground: entry.foreground_color.clone(),
+            });
+        }
+        self.calendar_color(&entry.color_id).cloned()
+    }
+
+    /// The color of an event as shown by Google Calendar: the event color if it has one,
+    /// otherwise the color of the calendar it belongs to.
+    ///
+    /// # Example
+    /// ```rust
+    /// let colors = ColorsClient::new(&client).get_colors().request().await?.unwrap();
+    /// let calendar = CalendarListClient::new(&client)
+    ///     .get_calendar_list_entry("primary")
+    ///     .request()
+    ///     .await?
+    ///     .unwrap();
+    /// for event in events {
+    ///     let color = colors.color_of_event(&event, &calendar);
+    /// }
+    /// ```
+    pub fn color_of_event(
+        &self,
+        event: &Event,
+        calendar: &CalendarListEntry,
+    ) -> Option<ColorDefinition> {
+        match self.event_color(&event.color_id) {
+            Some(color) => Some(color.clone()),
+            None => self.color_of_calendar(calendar),
+        }
+    }
+}
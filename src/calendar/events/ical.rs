@@ -0,0 +1,729 @@
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+
+use crate::error::GoogleApiError;
+
+use super::types::{
+    CreateEventRequest, Event, EventAttendee, EventDateTime, EventDefaultReminder, EventList,
+    EventPerson, EventReminders, EventStatus, ResponseStatus, Transparency, Visibility,
+};
+
+const PRODID: &str = "-//google-workspace-apis//Calendar//EN";
+
+impl Event {
+    /// Exports the event as an iCalendar (.ics) document with a single VEVENT.
+    ///
+    /// Date-times with a `time_zone` are written as local times with an IANA `TZID`, without
+    /// a VTIMEZONE definition. Google Calendar, Outlook and Apple Calendar resolve these by
+    /// name.
+    pub fn to_ics(&self) -> String {
+        calendar_to_ics(std::slice::from_ref(self), None, None)
+    }
+}
+
+impl EventList {
+    /// Exports the events as an iCalendar (.ics) document, with the summary and time zone of
+    /// the calendar as `X-WR-CALNAME` and `X-WR-TIMEZONE`.
+    ///
+    /// # Example
+    /// ```rust
+    /// let events = CalendarEventsClient::new(&client)
+    ///     .get_events("primary")
+    ///     .request()
+    ///     .await?
+    ///     .unwrap();
+    /// std::fs::write("calendar.ics", events.to_ics())?;
+    /// ```
+    pub fn to_ics(&self) -> String {
+        calendar_to_ics(
+            &self.items,
+            Some(self.summary.as_str()).filter(|s| !s.is_empty()),
+            Some(self.time_zone.as_str()).filter(|s| !s.is_empty()),
+        )
+    }
+}
+
+/// Parses an iCalendar (.ics) document into requests that can be sent with `insert_event` or
+/// `import_event`, one for every VEVENT.
+///
+/// Date-times with a `TZID` must use an IANA time zone name. Floating date-times use the
+/// `X-WR-TIMEZONE` of the calendar, or UTC if it has none.
+///
+/// An override of a single instance (`RECURRENCE-ID`) keeps the UID of its recurring event
+/// and has `original_start_time` set to the instance it replaces. Overrides are returned after
+/// all other events, so importing the requests in order creates the recurring event before
+/// Google attaches the overrides to it. Inserting an override with `insert_event` instead
+/// creates a separate event.
+///
+/// # Example
+/// ```rust
+/// let ics = std::fs::read_to_string("export.ics")?;
+/// for event in parse_ics(&ics)? {
+///     let ical_uid = event.ical_uid.clone().unwrap_or_default();
+///     CalendarEventsClient::new(&client)
+///         .import_event("primary", &ical_uid, event.start.clone(), event.end.clone())
+///         .set_event(event)
+///         .request()
+///         .await?;
+/// }
+/// ```
+pub fn parse_ics(ics: &str) -> Result<Vec<CreateEventRequest>, GoogleApiError> {
+    let mut events = Vec::new();
+    let mut components: Vec<String> = Vec::new();
+    let mut default_tz: Option<String> = None;
+    let mut event: Option<VEvent> = None;
+    let mut alarm: Option<VAlarm> = None;
+
+    for line in unfold(ics) {
+        let line = ContentLine::parse(&line)?;
+        match line.name.as_str() {
+            "BEGIN" => {
+                let component = line.value.to_ascii_uppercase();
+                match component.as_str() {
+                    "VEVENT" => event = Some(VEvent::default()),
+                    "VALARM" if event.is_some() => alarm = Some(VAlarm::default()),
+                    _ => {}
+                }
+                components.push(component);
+                continue;
+            }
+            "END" => {
+                let component = line.value.to_ascii_uppercase();
+                if components.pop().as_deref() != Some(component.as_str()) {
+                    return Err(invalid(format!("unexpected END:{component}")));
+                }
+                match component.as_str() {
+                    "VEVENT" => {
+                        if let Some(vevent) = event.take() {
+                            events.push(vevent.into_request(default_tz.as_deref())?);
+                        }
+                    }
+                    "VALARM" => {
+                        if let (Some(vevent), Some(valarm)) = (event.as_mut(), alarm.take()) {
+                            vevent.reminders.extend(valarm.into_reminder());
+                        }
+                    }
+                    _ => {}
+                }
+                continue;
+            }
+            _ => {}
+        }
+
+        match components.last().map(String::as_str) {
+            Some("VCALENDAR") if line.name == "X-WR-TIMEZONE" => {
+                default_tz = Some(line.value.clone())
+            }
+            Some("VEVENT") => {
+                if let Some(vevent) = event.as_mut() {
+                    vevent.add(line);
+                }
+            }
+            Some("VALARM") => {
+                if let Some(valarm) = alarm.as_mut() {
+                    valarm.add(line);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(component) = components.pop() {
+        return Err(invalid(format!("missing END:{component}")));
+    }
+    events.sort_by_key(|event| event.original_start_time.is_some());
+    Ok(events)
+}
+
+fn calendar_to_ics(events: &[Event], name: Option<&str>, time_zone: Option<&str>) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        format!("PRODID:{PRODID}"),
+        "CALSCALE:GREGORIAN".to_string(),
+    ];
+    if let Some(name) = name {
+        lines.push(format!("X-WR-CALNAME:{}", escape(name)));
+    }
+    if let Some(time_zone) = time_zone {
+        lines.push(format!("X-WR-TIMEZONE:{time_zone}"));
+    }
+    for event in events {
+        event_lines(event, &mut lines);
+    }
+    lines.push("END:VCALENDAR".to_string());
+
+    let mut ics = String::new();
+    for line in lines {
+        fold(&line, &mut ics);
+    }
+    ics
+}
+
+fn event_lines(event: &Event, lines: &mut Vec<String>) {
+    lines.push("BEGIN:VEVENT".to_string());
+    if !event.ical_uid.is_empty() {
+        lines.push(format!("UID:{}", event.ical_uid));
+    } else if !event.recurring_event_id.is_empty() {
+        lines.push(format!("UID:{}@google.com", event.recurring_event_id));
+    } else if !event.id.is_empty() {
+        lines.push(format!("UID:{}@google.com", event.id));
+    }
+    let stamp = event.updated.or(event.created).unwrap_or_else(Utc::now);
+    lines.push(format!("DTSTAMP:{}", format_utc(&stamp)));
+    if let Some(start) = event.start.as_ref().and_then(|s| date_line("DTSTART", s)) {
+        lines.push(start);
+    }
+    if let Some(end) = event.end.as_ref().and_then(|e| date_line("DTEND", e)) {
+        lines.push(end);
+    }
+    if let Some(original_start) = event
+        .original_start_time
+        .as_ref()
+        .and_then(|o| date_line("RECURRENCE-ID", o))
+    {
+        lines.push(original_start);
+    }
+    if let Some(created) = &event.created {
+        lines.push(format!("CREATED:{}", format_utc(created)));
+    }
+    if let Some(updated) = &event.updated {
+        lines.push(format!("LAST-MODIFIED:{}", format_utc(updated)));
+    }
+    for (name, value) in [
+        ("SUMMARY", &event.summary),
+        ("DESCRIPTION", &event.description),
+        ("LOCATION", &event.location),
+    ] {
+        if !value.is_empty() {
+            lines.push(format!("{name}:{}", escape(value)));
+        }
+    }
+    if let Some(status) = &event.status {
+        lines.push(format!("STATUS:{}", status.as_str().to_ascii_uppercase()));
+    }
+    if let Some(transparency) = &event.transparency {
+        lines.push(format!(
+            "TRANSP:{}",
+            transparency.as_str().to_ascii_uppercase()
+        ));
+    }
+    if let Some(visibility) = event
+        .visibility
+        .as_ref()
+        .filter(|v| **v != Visibility::Default)
+    {
+        lines.push(format!(
+            "CLASS:{}",
+            visibility.as_str().to_ascii_uppercase()
+        ));
+    }
+    if event.sequence != 0 {
+        lines.push(format!("SEQUENCE:{}", event.sequence));
+    }
+    if let Some(organizer) = event.organizer.as_ref().filter(|o| !o.email.is_empty()) {
+        let mut line = "ORGANIZER".to_string();
+        if !organizer.display_name.is_empty() {
+            line.push_str(&format!(";CN={}", param_value(&organizer.display_name)));
+        }
+        lines.push(format!("{line}:mailto:{}", organizer.email));
+    }
+    for attendee in &event.attendees {
+        lines.push(attendee_line(attendee));
+    }
+    lines.extend(event.recurrence.iter().cloned());
+    if let Some(reminders) = &event.reminders {
+        let description = if event.summary.is_empty() {
+            "Reminder"
+        } else {
+            event.summary.as_str()
+        };
+        for reminder in &reminders.overrides {
+            lines.push("BEGIN:VALARM".to_string());
+            if reminder.method == "email" {
+                lines.push("ACTION:EMAIL".to_string());
+                lines.push(format!("SUMMARY:{}", escape(description)));
+            } else {
+                lines.push("ACTION:DISPLAY".to_string());
+            }
+            lines.push(format!("DESCRIPTION:{}", escape(description)));
+            lines.push(format!("TRIGGER:-PT{}M", reminder.minutes));
+            lines.push("END:VALARM".to_string());
+        }
+    }
+    lines.push("END:VEVENT".to_string());
+}
+
+fn attendee_line(attendee: &EventAttendee) -> String {
+    let mut line = "ATTENDEE".to_string();
+    if !attendee.display_name.is_empty() {
+        line.push_str(&format!(";CN={}", param_value(&attendee.display_name)));
+    }
+    if attendee.resource == Some(true) {
+        line.push_str(";CUTYPE=RESOURCE");
+    }
+    if attendee.optional == Some(true) {
+        line.push_str(";ROLE=OPT-PARTICIPANT");
+    } else {
+        line.push_str(";ROLE=REQ-PARTICIPANT");
+    }
+    let partstat = match attendee.response_status {
+        Some(ResponseStatus::Accepted) => Some("ACCEPTED"),
+        Some(ResponseStatus::Declined) => Some("DECLINED"),
+        Some(ResponseStatus::Tentative) => Some("TENTATIVE"),
+        Some(ResponseStatus::NeedsAction) => Some("NEEDS-ACTION"),
+        _ => None,
+    };
+    if let Some(partstat) = partstat {
+        line.push_str(&format!(";PARTSTAT={partstat}"));
+    }
+    if attendee.additional_guests > 0 {
+        line.push_str(&format!(";X-NUM-GUESTS={}", attendee.additional_guests));
+    }
+    format!("{line}:mailto:{}", attendee.email)
+}
+
+fn date_line(name: &str, date: &EventDateTime) -> Option<String> {
+    if let Some(day) = date.naive_date() {
+        return Some(format!("{name};VALUE=DATE:{}", day.format("%Y%m%d")));
+    }
+    if let Some(zoned) = date.zoned_date_time() {
+        return Some(format!(
+            "{name};TZID={}:{}",
+            zoned.timezone(),
+            zoned.format("%Y%m%dT%H%M%S")
+        ));
+    }
+    let date_time = date.date_time?;
+    Some(format!(
+        "{name}:{}",
+        format_utc(&date_time.with_timezone(&Utc))
+    ))
+}
+
+fn format_utc(date: &DateTime<Utc>) -> String {
+    date.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// The properties of a VEVENT that are collected while parsing.
+#[derive(Default)]
+struct VEvent {
+    uid: Option<String>,
+    start: Option<ContentLine>,
+    end: Option<ContentLine>,
+    recurrence_id: Option<ContentLine>,
+    duration: Option<String>,
+    summary: Option<String>,
+    description: Option<String>,
+    location: Option<String>,
+    status: Option<EventStatus>,
+    transparency: Option<Transparency>,
+    visibility: Option<Visibility>,
+    sequence: Option<i32>,
+    organizer: Option<EventPerson>,
+    attendees: Vec<EventAttendee>,
+    recurrence: Vec<String>,
+    reminders: Vec<EventDefaultReminder>,
+}
+
+impl VEvent {
+    fn add(&mut self, line: ContentLine) {
+        match line.name.as_str() {
+            "UID" => self.uid = Some(line.value),
+            "DTSTART" => self.start = Some(line),
+            "DTEND" => self.end = Some(line),
+            "RECURRENCE-ID" => self.recurrence_id = Some(line),
+            "DURATION" => self.duration = Some(line.value),
+            "SUMMARY" => self.summary = Some(unescape(&line.value)),
+            "DESCRIPTION" => self.description = Some(unescape(&line.value)),
+            "LOCATION" => self.location = Some(unescape(&line.value)),
+            "STATUS" => {
+                self.status = Some(match line.value.to_ascii_uppercase().as_str() {
+                    "CONFIRMED" => EventStatus::Confirmed,
+                    "TENTATIVE" => EventStatus::Tentative,
+                    "CANCELLED" => EventStatus::Cancelled,
+                    _ => EventStatus::Unknown(line.value.to_ascii_lowercase()),
+                })
+            }
+            "TRANSP" => {
+                self.transparency = Some(match line.value.to_ascii_uppercase().as_str() {
+                    "OPAQUE" => Transparency::Opaque,
+                    "TRANSPARENT" => Transparency::Transparent,
+                    _ => Transparency::Unknown(line.value.to_ascii_lowercase()),
+                })
+            }
+            "CLASS" => {
+                self.visibility = Some(match line.value.to_ascii_uppercase().as_str() {
+                    "PUBLIC" => Visibility::Public,
+                    "PRIVATE" => Visibility::Private,
+                    "CONFIDENTIAL" => Visibility::Confidential,
+                    _ => Visibility::Unknown(line.value.to_ascii_lowercase()),
+                })
+            }
+            "SEQUENCE" => self.sequence = line.value.parse().ok(),
+            "ORGANIZER" => {
+                self.organizer = Some(EventPerson {
+                    id: String::new(),
+                    email: mailto(&line.value),
+                    display_name: line.param("CN").unwrap_or_default().to_string(),
+                    self_: None,
+                })
+            }
+            "ATTENDEE" => self.attendees.push(parse_attendee(&line)),
+            "RRULE" | "EXRULE" | "RDATE" | "EXDATE" => self.recurrence.push(line.raw),
+            _ => {}
+        }
+    }
+
+    fn into_request(self, default_tz: Option<&str>) -> Result<CreateEventRequest, GoogleApiError> {
+        let start_line = self
+            .start
+            .ok_or_else(|| invalid("VEVENT without DTSTART".to_string()))?;
+        let mut start = parse_date_time(&start_line, default_tz)?;
+        let mut end = match (&self.end, self.duration) {
+            (Some(end), _) => parse_date_time(end, default_tz)?,
+            (None, duration) => {
+                let duration = duration
+                    .map(|d| {
+                        parse_duration(&d).ok_or_else(|| invalid(format!("invalid DURATION {d}")))
+                    })
+                    .transpose()?;
+                default_end(&start, duration)?
+            }
+        };
+        // Google requires a time zone to expand recurring events
+        if !self.recurrence.is_empty() {
+            for date in [&mut start, &mut end] {
+                if date.date_time.is_some() && date.time_zone.is_none() {
+                    date.time_zone = Some("UTC".to_string());
+                }
+            }
+        }
+
+        let original_start_time = self
+            .recurrence_id
+            .map(|line| parse_date_time(&line, default_tz))
+            .transpose()?;
+
+        let mut request = CreateEventRequest::new(start, end);
+        request.ical_uid = self.uid;
+        request.original_start_time = original_start_time;
+        request.summary = self.summary;
+        request.description = self.description;
+        request.location = self.location;
+        request.status = self.status;
+        request.transparency = self.transparency;
+        request.visibility = self.visibility;
+        request.sequence = self.sequence;
+        request.organizer = self.organizer;
+        request.attendees = self.attendees;
+        request.recurrence = self.recurrence;
+        if !self.reminders.is_empty() {
+            request.reminders = Some(EventReminders {
+                use_default: Some(false),
+                overrides: self.reminders,
+            });
+        }
+        Ok(request)
+    }
+}
+
+#[derive(Default)]
+struct VAlarm {
+    action: Option<String>,
+    trigger: Option<ContentLine>,
+}
+
+impl VAlarm {
+    fn add(&mut self, line: ContentLine) {
+        match line.name.as_str() {
+            "ACTION" => self.action = Some(line.value.to_ascii_uppercase()),
+            "TRIGGER" => self.trigger = Some(line),
+            _ => {}
+        }
+    }
+
+    /// Only alarms relative to the start of the event can be expressed as a reminder.
+    fn into_reminder(self) -> Option<EventDefaultReminder> {
+        let trigger = self.trigger?;
+        if trigger.param("VALUE") == Some("DATE-TIME") || trigger.param("RELATED") == Some("END") {
+            return None;
+        }
+        let before = -parse_duration(&trigger.value)?;
+        let method = match self.action.as_deref() {
+            Some("EMAIL") => "email",
+            _ => "popup",
+        };
+        Some(EventDefaultReminder {
+            method: method.to_string(),
+            minutes: before.num_minutes().max(0),
+        })
+    }
+}
+
+fn parse_attendee(line: &ContentLine) -> EventAttendee {
+    let response_status = match line.param("PARTSTAT") {
+        Some("ACCEPTED") => Some(ResponseStatus::Accepted),
+        Some("DECLINED") => Some(ResponseStatus::Declined),
+        Some("TENTATIVE") => Some(ResponseStatus::Tentative),
+        Some("NEEDS-ACTION") => Some(ResponseStatus::NeedsAction),
+        _ => None,
+    };
+    EventAttendee {
+        id: String::new(),
+        email: mailto(&line.value),
+        display_name: line.param("CN").unwrap_or_default().to_string(),
+        organizer: None,
+        self_: None,
+        resource: matches!(line.param("CUTYPE"), Some("RESOURCE" | "ROOM")).then_some(true),
+        optional: (line.param("ROLE") == Some("OPT-PARTICIPANT")).then_some(true),
+        response_status,
+        comment: String::new(),
+        additional_guests: line
+            .param("X-NUM-GUESTS")
+            .and_then(|n| n.parse().ok())
+            .unwrap_or_default(),
+    }
+}
+
+fn parse_date_time(
+    line: &ContentLine,
+    default_tz: Option<&str>,
+) -> Result<EventDateTime, GoogleApiError> {
+    let value = line.value.as_str();
+    let error = || invalid(format!("invalid {} value {value}", line.name));
+
+    if line.param("VALUE") == Some("DATE") || value.len() == 8 {
+        let date = NaiveDate::parse_from_str(value, "%Y%m%d").map_err(|_| error())?;
+        return Ok(EventDateTime::all_day(date));
+    }
+    if let Some(utc) = value.strip_suffix('Z') {
+        let date = NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S").map_err(|_| error())?;
+        return Ok(EventDateTime::utc(date.and_utc()));
+    }
+
+    let local = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").map_err(|_| error())?;
+    let Some(tz_name) = line.param("TZID").or(default_tz) else {
+        return Ok(EventDateTime::utc(local.and_utc()));
+    };
+    let tz_name = tz_name.trim_start_matches('/');
+    let tz: Tz = tz_name
+        .parse()
+        .map_err(|_| invalid(format!("unknown time zone {tz_name}")))?;
+    // Other clients write times skipped by DST, move them forward like the clock does
+    let date_time = tz
+        .from_local_datetime(&local)
+        .earliest()
+        .or_else(|| {
+            tz.from_local_datetime(&(local + Duration::hours(1)))
+                .earliest()
+        })
+        .ok_or_else(error)?;
+    Ok(EventDateTime::zoned(date_time))
+}
+
+/// The end of an event without DTEND: the start plus DURATION, or the end of the day for
+/// all-day events.
+fn default_end(
+    start: &EventDateTime,
+    duration: Option<Duration>,
+) -> Result<EventDateTime, GoogleApiError> {
+    let overflow = || invalid("DURATION is out of range".to_string());
+    let mut end = start.clone();
+    if let Some(day) = start.naive_date() {
+        let days = duration.map(|d| d.num_days()).unwrap_or(1).max(1);
+        let last = Duration::try_days(days)
+            .and_then(|days| day.checked_add_signed(days))
+            .ok_or_else(overflow)?;
+        end = EventDateTime::all_day(last);
+    } else if let (Some(date_time), Some(duration)) = (start.date_time, duration) {
+        end.date_time = Some(
+            date_time
+                .checked_add_signed(duration)
+                .ok_or_else(overflow)?,
+        );
+    }
+    Ok(end)
+}
+
+/// Parses an RFC 5545 duration like `PT15M`, `-P1D` or `P1W`. `None` if it is invalid or
+/// out of range.
+fn parse_duration(value: &str) -> Option<Duration> {
+    let (sign, rest) = match value.strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let rest = rest.strip_prefix('P')?;
+    let mut total = Duration::zero();
+    let mut number = String::new();
+    let mut in_time = false;
+    for c in rest.chars() {
+        match c {
+            '0'..='9' => number.push(c),
+            'T' => in_time = true,
+            unit => {
+                let n: i64 = number.parse().ok()?;
+                number.clear();
+                let part = match (unit, in_time) {
+                    ('W', false) => Duration::try_weeks(n),
+                    ('D', false) => Duration::try_days(n),
+                    ('H', true) => Duration::try_hours(n),
+                    ('M', true) => Duration::try_minutes(n),
+                    ('S', true) => Duration::try_seconds(n),
+                    _ => None,
+                };
+                total = total.checked_add(&part?)?;
+            }
+        }
+    }
+    if !number.is_empty() {
+        return None;
+    }
+    total.checked_mul(sign)
+}
+
+/// A property line of an iCalendar document, e.g. `DTSTART;TZID=Europe/Amsterdam:20250101T090000`.
+struct ContentLine {
+    raw: String,
+    name: String,
+    params: Vec<(String, String)>,
+    value: String,
+}
+
+impl ContentLine {
+    fn parse(line: &str) -> Result<Self, GoogleApiError> {
+        let mut in_quotes = false;
+        let mut separator = None;
+        for (i, c) in line.char_indices() {
+            match c {
+                '"' => in_quotes = !in_quotes,
+                ':' if !in_quotes => {
+                    separator = Some(i);
+                    break;
+                }
+                _ => {}
+            }
+        }
+        let separator = separator.ok_or_else(|| invalid(format!("invalid line {line}")))?;
+        let (head, value) = (&line[..separator], &line[separator + 1..]);
+
+        let mut parts = split_unquoted(head, ';').into_iter();
+        let name = parts.next().unwrap_or_default().to_ascii_uppercase();
+        let params = parts
+            .filter_map(|param| {
+                let (key, value) = param.split_once('=')?;
+                Some((
+                    key.to_ascii_uppercase(),
+                    value.trim_matches('"').to_string(),
+                ))
+            })
+            .collect();
+        Ok(Self {
+            raw: line.to_string(),
+            name,
+            params,
+            value: value.to_string(),
+        })
+    }
+
+    fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+fn split_unquoted(s: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut in_quotes = false;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        if c == '"' {
+            in_quotes = !in_quotes;
+        } else if c == separator && !in_quotes {
+            parts.push(&s[start..i]);
+            start = i + 1;
+        }
+    }
+    parts.push(&s[start..]);
+    parts
+}
+
+/// Joins folded lines, a line starting with a space or tab continues the previous line.
+fn unfold(ics: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in ics.lines() {
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(continuation), Some(last)) => last.push_str(continuation),
+            _ if line.trim().is_empty() => {}
+            _ => lines.push(line.to_string()),
+        }
+    }
+    lines
+}
+
+/// Appends the line to `ics`, folded at 75 octets as required by RFC 5545.
+fn fold(line: &str, ics: &mut String) {
+    let mut limit = 75;
+    let mut rest = line;
+    while rest.len() > limit {
+        let mut split = limit;
+        while !rest.is_char_boundary(split) {
+            split -= 1;
+        }
+        ics.push_str(&rest[..split]);
+        ics.push_str("\r\n ");
+        rest = &rest[split..];
+        // The leading space of a continuation line counts towards the limit
+        limit = 74;
+    }
+    ics.push_str(rest);
+    ics.push_str("\r\n");
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+fn unescape(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n' | 'N') => result.push('\n'),
+            Some(other) => result.push(other),
+            None => result.push('\\'),
+        }
+    }
+    result
+}
+
+/// Quotes a parameter value if it contains characters with a meaning in content lines.
+fn param_value(value: &str) -> String {
+    let value = value.replace('"', "'");
+    if value.contains([':', ';', ',']) {
+        format!("\"{value}\"")
+    } else {
+        value
+    }
+}
+
+fn mailto(value: &str) -> String {
+    match value.get(..7) {
+        Some(prefix) if prefix.eq_ignore_ascii_case("mailto:") => value[7..].to_string(),
+        _ => value.to_string(),
+    }
+}
+
+fn invalid(message: String) -> GoogleApiError {
+    GoogleApiError::InvalidRequest(format!("Invalid iCalendar: {message}"))
+}
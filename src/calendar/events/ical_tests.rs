@@ -0,0 +1,275 @@
+#![allow(clippy::unwrap_used)]
+
+use chrono::{NaiveDate, TimeZone, Utc};
+
+use super::{
+    ical::parse_ics,
+    types::{Event, EventList, EventStatus, ResponseStatus, Transparency, Visibility},
+};
+use crate::error::GoogleApiError;
+
+fn event() -> Event {
+    serde_json::from_str(
+        r#"{
+            "id": "abc123",
+            "iCalUID": "abc123@google.com",
+            "status": "confirmed",
+            "summary": "Planning; Q3, part 1",
+            "description": "Agenda:\n- budget",
+            "location": "Room 4",
+            "transparency": "opaque",
+            "visibility": "private",
+            "sequence": 2,
+            "created": "2025-01-01T08:00:00Z",
+            "updated": "2025-01-02T08:00:00Z",
+            "start": {"dateTime": "2025-03-03T09:00:00+01:00", "timeZone": "Europe/Amsterdam"},
+            "end": {"dateTime": "2025-03-03T10:00:00+01:00", "timeZone": "Europe/Amsterdam"},
+            "organizer": {"email": "boss@example.com", "displayName": "The Boss"},
+            "attendees": [
+                {"email": "boss@example.com", "displayName": "The Boss", "responseStatus": "accepted"},
+                {"email": "dev@example.com", "optional": true, "responseStatus": "tentative", "additionalGuests": 1},
+                {"email": "room@resource.calendar.google.com", "displayName": "Room 4", "resource": true, "responseStatus": "needsAction"}
+            ],
+            "recurrence": ["RRULE:FREQ=WEEKLY;BYDAY=MO", "EXDATE;TZID=Europe/Amsterdam:20250310T090000"],
+            "reminders": {"useDefault": false, "overrides": [{"method": "popup", "minutes": 10}, {"method": "email", "minutes": 1440}]}
+        }"#,
+    )
+    .unwrap()
+}
+
+#[test]
+fn exports_event_as_vevent() {
+    let ics = event().to_ics();
+    let unfolded = ics.replace("\r\n ", "");
+
+    assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+    assert!(ics.ends_with("END:VEVENT\r\nEND:VCALENDAR\r\n"));
+    for line in [
+        "UID:abc123@google.com",
+        "DTSTAMP:20250102T080000Z",
+        "DTSTART;TZID=Europe/Amsterdam:20250303T090000",
+        "DTEND;TZID=Europe/Amsterdam:20250303T100000",
+        "SUMMARY:Planning\\; Q3\\, part 1",
+        "DESCRIPTION:Agenda:\\n- budget",
+        "STATUS:CONFIRMED",
+        "TRANSP:OPAQUE",
+        "CLASS:PRIVATE",
+        "SEQUENCE:2",
+        "ORGANIZER;CN=The Boss:mailto:boss@example.com",
+        "ATTENDEE;ROLE=OPT-PARTICIPANT;PARTSTAT=TENTATIVE;X-NUM-GUESTS=1:mailto:dev@example.com",
+        "RRULE:FREQ=WEEKLY;BYDAY=MO",
+        "ACTION:EMAIL",
+        "TRIGGER:-PT1440M",
+    ] {
+        assert!(
+            unfolded.contains(&format!("{line}\r\n")),
+            "missing {line} in {unfolded}"
+        );
+    }
+    assert!(ics.lines().all(|line| line.len() <= 75));
+}
+
+#[test]
+fn export_round_trips_through_parse() {
+    let event = event();
+    let requests = parse_ics(&event.to_ics()).unwrap();
+
+    assert_eq!(requests.len(), 1);
+    let request = &requests[0];
+    assert_eq!(request.start, event.start.clone().unwrap());
+    assert_eq!(request.end, event.end.clone().unwrap());
+    assert_eq!(request.ical_uid.as_deref(), Some(event.ical_uid.as_str()));
+    assert_eq!(request.summary.as_deref(), Some(event.summary.as_str()));
+    assert_eq!(
+        request.description.as_deref(),
+        Some(event.description.as_str())
+    );
+    assert_eq!(request.location.as_deref(), Some(event.location.as_str()));
+    assert_eq!(request.status, Some(EventStatus::Confirmed));
+    assert_eq!(request.transparency, Some(Transparency::Opaque));
+    assert_eq!(request.visibility, Some(Visibility::Private));
+    assert_eq!(request.sequence, Some(2));
+    assert_eq!(request.organizer, event.organizer);
+    assert_eq!(request.attendees, event.attendees);
+    assert_eq!(request.recurrence, event.recurrence);
+    assert_eq!(request.reminders, event.reminders);
+}
+
+#[test]
+fn event_list_exports_calendar_name_and_long_lines_folded() {
+    let mut long = event();
+    long.description = "é".repeat(100);
+    let list: EventList =
+        serde_json::from_str(r#"{"summary": "Team", "timeZone": "Europe/Amsterdam", "items": []}"#)
+            .unwrap();
+    let list = EventList {
+        items: vec![long.clone(), event()],
+        ..list
+    };
+
+    let ics = list.to_ics();
+    assert!(ics.contains("X-WR-CALNAME:Team\r\n"));
+    assert!(ics.contains("X-WR-TIMEZONE:Europe/Amsterdam\r\n"));
+    assert_eq!(ics.matches("BEGIN:VEVENT").count(), 2);
+    assert!(ics.lines().all(|line| line.len() <= 75));
+
+    let requests = parse_ics(&ics).unwrap();
+    assert_eq!(
+        requests[0].description.as_deref(),
+        Some(long.description.as_str())
+    );
+}
+
+#[test]
+fn parses_ics_from_other_clients() {
+    let ics = "BEGIN:VCALENDAR\n\
+        VERSION:2.0\n\
+        PRODID:-//Example//EN\n\
+        X-WR-TIMEZONE:America/New_York\n\
+        BEGIN:VTIMEZONE\n\
+        TZID:America/New_York\n\
+        BEGIN:STANDARD\n\
+        DTSTART:19701101T020000\n\
+        END:STANDARD\n\
+        END:VTIMEZONE\n\
+        BEGIN:VEVENT\n\
+        UID:1@example.com\n\
+        DTSTART:20250601T140000\n\
+        DURATION:PT1H30M\n\
+        SUMMARY:A summary that is long enough to be folded over \n \
+        two lines\n\
+        ATTENDEE;CN=\"Doe, Jane\";CUTYPE=ROOM;PARTSTAT=DECLINED:MAILTO:jane@example.com\n\
+        BEGIN:VALARM\n\
+        ACTION:AUDIO\n\
+        TRIGGER:-P1D\n\
+        END:VALARM\n\
+        BEGIN:VALARM\n\
+        ACTION:DISPLAY\n\
+        TRIGGER;VALUE=DATE-TIME:20250601T120000Z\n\
+        END:VALARM\n\
+        END:VEVENT\n\
+        BEGIN:VEVENT\n\
+        DTSTART;VALUE=DATE:20250704\n\
+        SUMMARY:Holiday\n\
+        RRULE:FREQ=YEARLY\n\
+        END:VEVENT\n\
+        END:VCALENDAR\n";
+
+    let requests = parse_ics(ics).unwrap();
+    assert_eq!(requests.len(), 2);
+
+    let meeting = &requests[0];
+    let start = meeting.start.date_time.unwrap();
+    assert_eq!(start, Utc.with_ymd_and_hms(2025, 6, 1, 18, 0, 0).unwrap());
+    assert_eq!(start.to_rfc3339(), "2025-06-01T14:00:00-04:00");
+    assert_eq!(meeting.start.time_zone.as_deref(), Some("America/New_York"));
+    assert_eq!(
+        meeting.end.date_time.unwrap(),
+        Utc.with_ymd_and_hms(2025, 6, 1, 19, 30, 0).unwrap()
+    );
+    assert_eq!(
+        meeting.summary.as_deref(),
+        Some("A summary that is long enough to be folded over two lines")
+    );
+    let attendee = &meeting.attendees[0];
+    assert_eq!(attendee.email, "jane@example.com");
+    assert_eq!(attendee.display_name, "Doe, Jane");
+    assert_eq!(attendee.resource, Some(true));
+    assert_eq!(attendee.response_status, Some(ResponseStatus::Declined));
+    let reminders = meeting.reminders.as_ref().unwrap();
+    assert_eq!(reminders.use_default, Some(false));
+    assert_eq!(reminders.overrides.len(), 1);
+    assert_eq!(reminders.overrides[0].method, "popup");
+    assert_eq!(reminders.overrides[0].minutes, 1440);
+
+    let holiday = &requests[1];
+    assert_eq!(holiday.start.date, NaiveDate::from_ymd_opt(2025, 7, 4));
+    assert_eq!(holiday.end.date, NaiveDate::from_ymd_opt(2025, 7, 5));
+    assert_eq!(holiday.recurrence, vec!["RRULE:FREQ=YEARLY".to_string()]);
+}
+
+#[test]
+fn utc_times_of_recurring_events_get_a_time_zone() {
+    let ics = "BEGIN:VCALENDAR\r\nBEGIN:VEVENT\r\nDTSTART:20250101T090000Z\r\n\
+        DTEND:20250101T100000Z\r\nRRULE:FREQ=DAILY;COUNT=3\r\nEND:VEVENT\r\nEND:VCALENDAR\r\n";
+
+    let request = parse_ics(ics).unwrap().remove(0);
+    assert_eq!(request.start.time_zone.as_deref(), Some("UTC"));
+    assert_eq!(request.end.time_zone.as_deref(), Some("UTC"));
+}
+
+#[test]
+fn rejects_invalid_documents() {
+    for (ics, message) in [
+        (
+            "BEGIN:VCALENDAR\nBEGIN:VEVENT\nSUMMARY:x\nEND:VEVENT\nEND:VCALENDAR\n",
+            "VEVENT without DTSTART",
+        ),
+        (
+            "BEGIN:VCALENDAR\nBEGIN:VEVENT\nDTSTART;TZID=Mars/Olympus:20250101T090000\nEND:VEVENT\nEND:VCALENDAR\n",
+            "unknown time zone Mars/Olympus",
+        ),
+        (
+            "BEGIN:VCALENDAR\nBEGIN:VEVENT\nDTSTART:20250101T090000Z\nEND:VCALENDAR\n",
+            "unexpected END:VCALENDAR",
+        ),
+        ("BEGIN:VCALENDAR\n", "missing END:VCALENDAR"),
+        (
+            "BEGIN:VCALENDAR\nBEGIN:VEVENT\nDTSTART:20250101T090000Z\nDURATION:P99999999999999W\nEND:VEVENT\nEND:VCALENDAR\n",
+            "invalid DURATION P99999999999999W",
+        ),
+        (
+            "BEGIN:VCALENDAR\nBEGIN:VEVENT\nDTSTART;VALUE=DATE:20250101\nDURATION:P9999999999D\nEND:VEVENT\nEND:VCALENDAR\n",
+            "DURATION is out of range",
+        ),
+        (
+            "BEGIN:VCALENDAR\nBEGIN:VEVENT\nDTSTART:20250101T090000Z\nDURATION:P9999999999D\nEND:VEVENT\nEND:VCALENDAR\n",
+            "DURATION is out of range",
+        ),
+    ] {
+        match parse_ics(ics) {
+            Err(GoogleApiError::InvalidRequest(error)) => {
+                assert!(error.contains(message), "{error} does not contain {message}")
+            }
+            other => panic!("expected an error for {ics}, got {other:?}"),
+        }
+    }
+}
+
+#[test]
+fn overridden_instance_round_trips_with_recurrence_id() {
+    let master = event();
+    let moved: Event = serde_json::from_str(
+        r#"{
+            "id": "abc123_20250310T080000Z",
+            "iCalUID": "abc123@google.com",
+            "recurringEventId": "abc123",
+            "originalStartTime": {"dateTime": "2025-03-10T09:00:00+01:00", "timeZone": "Europe/Amsterdam"},
+            "summary": "Planning, moved",
+            "start": {"dateTime": "2025-03-10T11:00:00+01:00", "timeZone": "Europe/Amsterdam"},
+            "end": {"dateTime": "2025-03-10T12:00:00+01:00", "timeZone": "Europe/Amsterdam"}
+        }"#,
+    )
+    .unwrap();
+    let list: EventList = serde_json::from_str(r#"{"items": []}"#).unwrap();
+    let list = EventList {
+        items: vec![moved.clone(), master.clone()],
+        ..list
+    };
+
+    let ics = list.to_ics();
+    assert!(ics.contains("RECURRENCE-ID;TZID=Europe/Amsterdam:20250310T090000\r\n"));
+
+    let requests = parse_ics(&ics).unwrap();
+    assert_eq!(requests.len(), 2);
+    let (parsed_master, parsed_moved) = (&requests[0], &requests[1]);
+    assert_eq!(parsed_master.original_start_time, None);
+    assert_eq!(parsed_master.recurrence, master.recurrence);
+    assert_eq!(
+        parsed_moved.ical_uid.as_deref(),
+        Some(master.ical_uid.as_str())
+    );
+    assert_eq!(parsed_moved.original_start_time, moved.original_start_time);
+    assert_eq!(parsed_moved.start, moved.start.unwrap());
+    assert!(parsed_moved.recurrence.is_empty());
+}
@@ -1,4 +1,13 @@
+pub mod ical;
+#[cfg(test)]
+mod ical_tests;
+pub mod recurrence;
+#[cfg(test)]
+mod recurrence_tests;
 #[cfg(test)]
 mod request_tests;
 pub mod requests;
+pub mod sync;
+#[cfg(test)]
+mod sync_tests;
 pub mod types;
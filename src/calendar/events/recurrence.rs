@@ -0,0 +1,1132 @@
+use std::{collections::BTreeSet, fmt, str::FromStr};
+
+use chrono::{
+    DateTime, Datelike, Duration, Months, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike,
+    Utc, Weekday,
+};
+
+use crate::error::GoogleApiError;
+
+/// Stop expanding a rule after this many periods, so rules that never match (like the 30th of
+/// February) can't loop until the end of the range. Hitting the limit is reported as an error.
+const MAX_PERIODS: i64 = 100_000;
+
+/// The `FREQ` of a recurrence rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frequency {
+    Secondly,
+    Minutely,
+    Hourly,
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+impl Frequency {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Frequency::Secondly => "SECONDLY",
+            Frequency::Minutely => "MINUTELY",
+            Frequency::Hourly => "HOURLY",
+            Frequency::Daily => "DAILY",
+            Frequency::Weekly => "WEEKLY",
+            Frequency::Monthly => "MONTHLY",
+            Frequency::Yearly => "YEARLY",
+        }
+    }
+
+    fn unit(&self) -> &str {
+        match self {
+            Frequency::Secondly => "second",
+            Frequency::Minutely => "minute",
+            Frequency::Hourly => "hour",
+            Frequency::Daily => "day",
+            Frequency::Weekly => "week",
+            Frequency::Monthly => "month",
+            Frequency::Yearly => "year",
+        }
+    }
+}
+
+impl FromStr for Frequency {
+    type Err = GoogleApiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "SECONDLY" => Ok(Frequency::Secondly),
+            "MINUTELY" => Ok(Frequency::Minutely),
+            "HOURLY" => Ok(Frequency::Hourly),
+            "DAILY" => Ok(Frequency::Daily),
+            "WEEKLY" => Ok(Frequency::Weekly),
+            "MONTHLY" => Ok(Frequency::Monthly),
+            "YEARLY" => Ok(Frequency::Yearly),
+            _ => Err(invalid(format!("unknown FREQ {s}"))),
+        }
+    }
+}
+
+/// An entry of `BYDAY`: a weekday, optionally limited to the nth occurrence within the month or
+/// year, e.g. `1MO` for the first Monday or `-1FR` for the last Friday.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ByDay {
+    /// 1 for the first, -1 for the last occurrence. `None` for every occurrence.
+    pub nth: Option<i8>,
+    pub weekday: Weekday,
+}
+
+impl ByDay {
+    /// Every occurrence of the weekday.
+    pub fn every(weekday: Weekday) -> Self {
+        Self { nth: None, weekday }
+    }
+
+    /// The nth occurrence of the weekday within the month or year, negative to count from the
+    /// end.
+    pub fn nth(nth: i8, weekday: Weekday) -> Self {
+        Self {
+            nth: Some(nth),
+            weekday,
+        }
+    }
+
+    fn describe(&self) -> String {
+        match self.nth {
+            Some(nth) => format!("the {} {}", ordinal(nth as i32), weekday_name(self.weekday)),
+            None => weekday_name(self.weekday).to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ByDay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(nth) = self.nth {
+            write!(f, "{nth}")?;
+        }
+        f.write_str(weekday_code(self.weekday))
+    }
+}
+
+impl FromStr for ByDay {
+    type Err = GoogleApiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() < 2 || !s.is_char_boundary(s.len() - 2) {
+            return Err(invalid(format!("invalid BYDAY value {s}")));
+        }
+        let (nth, day) = s.split_at(s.len() - 2);
+        let weekday = parse_weekday(day)?;
+        let nth = match nth.trim_start_matches('+') {
+            "" => None,
+            n => Some(
+                n.parse()
+                    .map_err(|_| invalid(format!("invalid BYDAY value {s}")))?,
+            ),
+        };
+        Ok(Self { nth, weekday })
+    }
+}
+
+/// A date or date-time as used by `UNTIL`, `RDATE` and `EXDATE`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecurrenceDate {
+    /// A date, used for all-day events, e.g. `20250131`.
+    Date(NaiveDate),
+    /// A local date-time, in the time zone of the event or of the `TZID` parameter, e.g.
+    /// `20250131T090000`.
+    DateTime(NaiveDateTime),
+    /// A date-time in UTC, e.g. `20250131T080000Z`.
+    Utc(DateTime<Utc>),
+}
+
+impl fmt::Display for RecurrenceDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RecurrenceDate::Date(date) => write!(f, "{}", date.format("%Y%m%d")),
+            RecurrenceDate::DateTime(date) => write!(f, "{}", date.format("%Y%m%dT%H%M%S")),
+            RecurrenceDate::Utc(date) => write!(f, "{}", date.format("%Y%m%dT%H%M%SZ")),
+        }
+    }
+}
+
+impl FromStr for RecurrenceDate {
+    type Err = GoogleApiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || invalid(format!("invalid date {s}"));
+        if let Some(utc) = s.strip_suffix('Z') {
+            let date = NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S").map_err(|_| error())?;
+            Ok(RecurrenceDate::Utc(date.and_utc()))
+        } else if s.len() == 8 {
+            Ok(RecurrenceDate::Date(
+                NaiveDate::parse_from_str(s, "%Y%m%d").map_err(|_| error())?,
+            ))
+        } else {
+            Ok(RecurrenceDate::DateTime(
+                NaiveDateTime::parse_from_str(s, "%Y%m%dT%H%M%S").map_err(|_| error())?,
+            ))
+        }
+    }
+}
+
+/// The dates of an `RDATE` or `EXDATE` line.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DateList {
+    /// The `TZID` parameter of local date-times. The expander interprets local date-times in
+    /// the time zone of the start of the event.
+    pub tzid: Option<String>,
+    pub dates: Vec<RecurrenceDate>,
+}
+
+impl DateList {
+    pub fn new(dates: Vec<RecurrenceDate>) -> Self {
+        Self { tzid: None, dates }
+    }
+
+    fn to_line(&self, name: &str) -> String {
+        let mut line = name.to_string();
+        if self
+            .dates
+            .iter()
+            .all(|d| matches!(d, RecurrenceDate::Date(_)))
+        {
+            line.push_str(";VALUE=DATE");
+        }
+        if let Some(tzid) = &self.tzid {
+            line.push_str(&format!(";TZID={tzid}"));
+        }
+        let dates: Vec<String> = self.dates.iter().map(|d| d.to_string()).collect();
+        format!("{line}:{}", dates.join(","))
+    }
+
+    fn parse(params: &str, value: &str) -> Result<Self, GoogleApiError> {
+        let mut list = DateList::default();
+        for param in params.split(';').filter(|p| !p.is_empty()) {
+            match param.split_once('=') {
+                Some(("TZID", tzid)) => list.tzid = Some(tzid.to_string()),
+                Some(("VALUE", "DATE" | "DATE-TIME")) => {}
+                _ => return Err(invalid(format!("unsupported parameter {param}"))),
+            }
+        }
+        list.dates = value.split(',').map(str::parse).collect::<Result<_, _>>()?;
+        Ok(list)
+    }
+}
+
+/// A single `RRULE` or `EXRULE`, see [RFC 5545](https://www.rfc-editor.org/rfc/rfc5545#section-3.3.10).
+///
+/// # Example
+/// ```rust
+/// // Every other week on Monday and Wednesday, 10 times
+/// let rule = RRule::new(Frequency::Weekly)
+///     .interval(2)
+///     .by_weekdays(&[Weekday::Mon, Weekday::Wed])
+///     .count(10);
+/// assert_eq!(rule.to_string(), "FREQ=WEEKLY;COUNT=10;INTERVAL=2;BYDAY=MO,WE");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RRule {
+    pub freq: Frequency,
+    pub interval: u32,
+    pub count: Option<u32>,
+    pub until: Option<RecurrenceDate>,
+    pub by_second: Vec<u8>,
+    pub by_minute: Vec<u8>,
+    pub by_hour: Vec<u8>,
+    pub by_day: Vec<ByDay>,
+    pub by_month_day: Vec<i8>,
+    pub by_year_day: Vec<i16>,
+    pub by_week_no: Vec<i8>,
+    pub by_month: Vec<u8>,
+    pub by_set_pos: Vec<i16>,
+    pub week_start: Option<Weekday>,
+}
+
+impl RRule {
+    pub fn new(freq: Frequency) -> Self {
+        Self {
+            freq,
+            interval: 1,
+            count: None,
+            until: None,
+            by_second: Vec::new(),
+            by_minute: Vec::new(),
+            by_hour: Vec::new(),
+            by_day: Vec::new(),
+            by_month_day: Vec::new(),
+            by_year_day: Vec::new(),
+            by_week_no: Vec::new(),
+            by_month: Vec::new(),
+            by_set_pos: Vec::new(),
+            week_start: None,
+        }
+    }
+
+    /// Repeat every `interval` periods, e.g. 2 with `Frequency::Weekly` for every other week.
+    pub fn interval(mut self, interval: u32) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Stop after this many occurrences. Can't be combined with `until`.
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Stop after this date, inclusive. Can't be combined with `count`.
+    pub fn until(mut self, until: RecurrenceDate) -> Self {
+        self.until = Some(until);
+        self
+    }
+
+    pub fn by_day(mut self, days: Vec<ByDay>) -> Self {
+        self.by_day = days;
+        self
+    }
+
+    /// Every occurrence of these weekdays, shorthand for `by_day` with `ByDay::every`.
+    pub fn by_weekdays(self, days: &[Weekday]) -> Self {
+        self.by_day(days.iter().copied().map(ByDay::every).collect())
+    }
+
+    /// Days of the month, negative to count from the end: -1 is the last day.
+    pub fn by_month_day(mut self, days: Vec<i8>) -> Self {
+        self.by_month_day = days;
+        self
+    }
+
+    /// Days of the year, negative to count from the end.
+    pub fn by_year_day(mut self, days: Vec<i16>) -> Self {
+        self.by_year_day = days;
+        self
+    }
+
+    /// ISO week numbers, only valid with `Frequency::Yearly`.
+    pub fn by_week_no(mut self, weeks: Vec<i8>) -> Self {
+        self.by_week_no = weeks;
+        self
+    }
+
+    /// Months, 1 is January.
+    pub fn by_month(mut self, months: Vec<u8>) -> Self {
+        self.by_month = months;
+        self
+    }
+
+    pub fn by_hour(mut self, hours: Vec<u8>) -> Self {
+        self.by_hour = hours;
+        self
+    }
+
+    pub fn by_minute(mut self, minutes: Vec<u8>) -> Self {
+        self.by_minute = minutes;
+        self
+    }
+
+    pub fn by_second(mut self, seconds: Vec<u8>) -> Self {
+        self.by_second = seconds;
+        self
+    }
+
+    /// Only keep the nth occurrences within each period, e.g. -1 with weekdays for the last
+    /// working day of the month.
+    pub fn by_set_pos(mut self, positions: Vec<i16>) -> Self {
+        self.by_set_pos = positions;
+        self
+    }
+
+    pub fn week_start(mut self, weekday: Weekday) -> Self {
+        self.week_start = Some(weekday);
+        self
+    }
+
+    /// Checks the rule against the constraints of RFC 5545.
+    pub fn validate(&self) -> Result<(), GoogleApiError> {
+        use Frequency::*;
+
+        if self.interval == 0 {
+            return Err(invalid("INTERVAL must be at least 1".to_string()));
+        }
+        if self.count.is_some() && self.until.is_some() {
+            return Err(invalid("COUNT and UNTIL can't be combined".to_string()));
+        }
+        check_range("BYSECOND", &self.by_second, 0, 60, false)?;
+        check_range("BYMINUTE", &self.by_minute, 0, 59, false)?;
+        check_range("BYHOUR", &self.by_hour, 0, 23, false)?;
+        check_range("BYMONTH", &self.by_month, 1, 12, false)?;
+        check_range("BYMONTHDAY", &self.by_month_day, -31, 31, true)?;
+        check_range("BYYEARDAY", &self.by_year_day, -366, 366, true)?;
+        check_range("BYWEEKNO", &self.by_week_no, -53, 53, true)?;
+        check_range("BYSETPOS", &self.by_set_pos, -366, 366, true)?;
+        for day in &self.by_day {
+            match day.nth {
+                Some(nth) if nth == 0 || !(-53..=53).contains(&nth) => {
+                    return Err(invalid(format!("BYDAY value {day} is out of range")))
+                }
+                Some(_) if !matches!(self.freq, Monthly | Yearly) => {
+                    return Err(invalid(
+                        "BYDAY with a position is only valid for MONTHLY and YEARLY".to_string(),
+                    ))
+                }
+                Some(nth) if self.freq == Monthly && !(-5..=5).contains(&nth) => {
+                    return Err(invalid(format!("BYDAY value {day} is out of range")))
+                }
+                _ => {}
+            }
+        }
+        if !self.by_week_no.is_empty() && self.freq != Yearly {
+            return Err(invalid("BYWEEKNO is only valid for YEARLY".to_string()));
+        }
+        if !self.by_year_day.is_empty() && matches!(self.freq, Daily | Weekly | Monthly) {
+            return Err(invalid(
+                "BYYEARDAY is not valid for DAILY, WEEKLY and MONTHLY".to_string(),
+            ));
+        }
+        if !self.by_month_day.is_empty() && self.freq == Weekly {
+            return Err(invalid("BYMONTHDAY is not valid for WEEKLY".to_string()));
+        }
+        if !self.by_set_pos.is_empty()
+            && self.by_day.is_empty()
+            && self.by_month_day.is_empty()
+            && self.by_year_day.is_empty()
+            && self.by_week_no.is_empty()
+            && self.by_month.is_empty()
+            && self.by_hour.is_empty()
+            && self.by_minute.is_empty()
+            && self.by_second.is_empty()
+        {
+            return Err(invalid(
+                "BYSETPOS must be combined with another BYxxx rule".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// A short English description of the rule, e.g. "Every 2 weeks on Monday, Wednesday, 10
+    /// times".
+    pub fn describe(&self) -> String {
+        let mut text = match (self.interval, self.freq) {
+            (1, Frequency::Daily) => "Daily".to_string(),
+            (1, Frequency::Weekly) => "Weekly".to_string(),
+            (1, Frequency::Monthly) => "Monthly".to_string(),
+            (1, Frequency::Yearly) => "Annually".to_string(),
+            (1, freq) => format!("Every {}", freq.unit()),
+            (n, freq) => format!("Every {n} {}s", freq.unit()),
+        };
+        if !self.by_day.is_empty() {
+            let days: Vec<String> = self.by_day.iter().map(ByDay::describe).collect();
+            text.push_str(&format!(" on {}", days.join(", ")));
+        }
+        if !self.by_month_day.is_empty() {
+            let days: Vec<String> = self
+                .by_month_day
+                .iter()
+                .map(|day| match day {
+                    d if *d < 0 => format!("the {} day", ordinal(*d as i32)),
+                    d => d.to_string(),
+                })
+                .collect();
+            text.push_str(&format!(" on day {}", days.join(", ")));
+        }
+        if !self.by_month.is_empty() {
+            let months: Vec<&str> = self.by_month.iter().map(|m| month_name(*m)).collect();
+            text.push_str(&format!(" in {}", months.join(", ")));
+        }
+        match (self.count, self.until) {
+            (Some(1), _) => text.push_str(", once"),
+            (Some(count), _) => text.push_str(&format!(", {count} times")),
+            (None, Some(RecurrenceDate::Date(date))) => {
+                text.push_str(&format!(", until {}", date.format("%Y-%m-%d")))
+            }
+            (None, Some(RecurrenceDate::DateTime(date))) => {
+                text.push_str(&format!(", until {}", date.format("%Y-%m-%d %H:%M")))
+            }
+            (None, Some(RecurrenceDate::Utc(date))) => {
+                text.push_str(&format!(", until {}", date.format("%Y-%m-%d %H:%M UTC")))
+            }
+            (None, None) => {}
+        }
+        text
+    }
+
+    /// Expands the rule into local date-times from `start` until `end` (exclusive).
+    /// `to_utc` converts a local date-time to UTC, for comparing with a UTC `UNTIL`.
+    ///
+    /// Fails if `end` is more than `MAX_PERIODS` periods after `start`.
+    fn expand(
+        &self,
+        start: NaiveDateTime,
+        end: NaiveDateTime,
+        to_utc: &dyn Fn(NaiveDateTime) -> Option<DateTime<Utc>>,
+    ) -> Result<Vec<NaiveDateTime>, GoogleApiError> {
+        let mut occurrences = Vec::new();
+        let mut count = 0;
+        for period in 0..MAX_PERIODS {
+            let Some((period_start, candidates)) = self.period(start, period) else {
+                return Ok(occurrences);
+            };
+            if period_start >= end {
+                return Ok(occurrences);
+            }
+            for candidate in candidates {
+                if candidate < start {
+                    continue;
+                }
+                let past_until = match self.until {
+                    Some(RecurrenceDate::Date(until)) => candidate.date() > until,
+                    Some(RecurrenceDate::DateTime(until)) => candidate > until,
+                    Some(RecurrenceDate::Utc(until)) => {
+                        to_utc(candidate).is_some_and(|c| c > until)
+                    }
+                    None => false,
+                };
+                if past_until || self.count.is_some_and(|max| count >= max) || candidate >= end {
+                    return Ok(occurrences);
+                }
+                count += 1;
+                occurrences.push(candidate);
+            }
+        }
+        Err(invalid(format!(
+            "expanding {self} stopped after {MAX_PERIODS} periods, use a shorter range"
+        )))
+    }
+
+    /// The start of the nth period after `start` and the sorted occurrences within it.
+    fn period(&self, start: NaiveDateTime, n: i64) -> Option<(NaiveDateTime, Vec<NaiveDateTime>)> {
+        let step = n.checked_mul(self.interval as i64)?;
+        let date = start.date();
+        let (period_start, dates) = match self.freq {
+            Frequency::Yearly => {
+                let year = date.year().checked_add(i32::try_from(step).ok()?)?;
+                let first = NaiveDate::from_ymd_opt(year, 1, 1)?;
+                let last = NaiveDate::from_ymd_opt(year, 12, 31)?;
+                (
+                    first,
+                    first.iter_days().take_while(|d| *d <= last).collect(),
+                )
+            }
+            Frequency::Monthly => {
+                let first = date
+                    .with_day(1)?
+                    .checked_add_months(Months::new(u32::try_from(step).ok()?))?;
+                let next = first.checked_add_months(Months::new(1))?;
+                (first, first.iter_days().take_while(|d| *d < next).collect())
+            }
+            Frequency::Weekly => {
+                let week_start = self.week_start.unwrap_or(Weekday::Mon);
+                let offset = date.weekday().days_since(week_start) as i64;
+                let first = date
+                    .checked_sub_signed(Duration::days(offset))?
+                    .checked_add_signed(Duration::try_weeks(step)?)?;
+                (first, first.iter_days().take(7).collect())
+            }
+            Frequency::Daily => {
+                let day = date.checked_add_signed(Duration::try_days(step)?)?;
+                (day, vec![day])
+            }
+            Frequency::Hourly | Frequency::Minutely | Frequency::Secondly => {
+                return self.sub_daily_period(start, step);
+            }
+        };
+
+        let mut candidates = Vec::new();
+        for date in dates.into_iter().filter(|d| self.matches_date(d, date)) {
+            for hour in or_default(&self.by_hour, start.hour() as u8) {
+                for minute in or_default(&self.by_minute, start.minute() as u8) {
+                    for second in or_default(&self.by_second, start.second() as u8) {
+                        // A leap second (60) is treated as the last second of the minute
+                        if let Some(time) = NaiveTime::from_hms_opt(
+                            hour as u32,
+                            minute as u32,
+                            second.min(59) as u32,
+                        ) {
+                            candidates.push(date.and_time(time));
+                        }
+                    }
+                }
+            }
+        }
+        Some((
+            period_start.and_time(NaiveTime::MIN),
+            self.set_pos(candidates),
+        ))
+    }
+
+    fn sub_daily_period(
+        &self,
+        start: NaiveDateTime,
+        step: i64,
+    ) -> Option<(NaiveDateTime, Vec<NaiveDateTime>)> {
+        let (period_start, minutes, seconds) = match self.freq {
+            Frequency::Hourly => (
+                start
+                    .with_minute(0)?
+                    .with_second(0)?
+                    .checked_add_signed(Duration::try_hours(step)?)?,
+                or_default(&self.by_minute, start.minute() as u8),
+                or_default(&self.by_second, start.second() as u8),
+            ),
+            Frequency::Minutely => (
+                start
+                    .with_second(0)?
+                    .checked_add_signed(Duration::try_minutes(step)?)?,
+                vec![0],
+                or_default(&self.by_second, start.second() as u8),
+            ),
+            _ => (
+                start.checked_add_signed(Duration::try_seconds(step)?)?,
+                vec![0],
+                vec![0],
+            ),
+        };
+
+        let date = period_start.date();
+        if !self.matches_date(&date, date)
+            || (!self.by_hour.is_empty() && !self.by_hour.contains(&(period_start.hour() as u8)))
+            || (self.freq == Frequency::Secondly
+                && !self.by_minute.is_empty()
+                && !self.by_minute.contains(&(period_start.minute() as u8)))
+        {
+            return Some((period_start, Vec::new()));
+        }
+        let mut candidates = Vec::new();
+        for minute in minutes {
+            for second in &seconds {
+                let candidate = period_start
+                    + Duration::minutes(minute as i64)
+                    + Duration::seconds((*second).min(59) as i64);
+                candidates.push(candidate);
+            }
+        }
+        Some((period_start, self.set_pos(candidates)))
+    }
+
+    /// Whether `date` matches the date parts of the rule. Without any of `BYDAY`,
+    /// `BYMONTHDAY`, `BYYEARDAY` and `BYWEEKNO` the day of `start` is used.
+    fn matches_date(&self, date: &NaiveDate, start: NaiveDate) -> bool {
+        let has_day_rule = !self.by_day.is_empty()
+            || !self.by_month_day.is_empty()
+            || !self.by_year_day.is_empty()
+            || !self.by_week_no.is_empty();
+        let default_day = match self.freq {
+            _ if has_day_rule => true,
+            Frequency::Yearly if self.by_month.is_empty() => {
+                date.month() == start.month() && date.day() == start.day()
+            }
+            Frequency::Yearly | Frequency::Monthly => date.day() == start.day(),
+            Frequency::Weekly => date.weekday() == start.weekday(),
+            _ => true,
+        };
+
+        default_day
+            && (self.by_month.is_empty() || self.by_month.contains(&(date.month() as u8)))
+            && (self.by_week_no.is_empty()
+                || self.by_week_no.iter().any(|w| week_matches(date, *w)))
+            && (self.by_year_day.is_empty()
+                || self.by_year_day.iter().any(|d| {
+                    let len = days_in_year(date.year()) as i16;
+                    let day = date.ordinal() as i16;
+                    *d == day || *d == day - len - 1
+                }))
+            && (self.by_month_day.is_empty()
+                || self.by_month_day.iter().any(|d| {
+                    let len = days_in_month(date) as i8;
+                    let day = date.day() as i8;
+                    *d == day || *d == day - len - 1
+                }))
+            && (self.by_day.is_empty() || self.by_day.iter().any(|d| self.matches_by_day(date, d)))
+    }
+
+    fn matches_by_day(&self, date: &NaiveDate, by_day: &ByDay) -> bool {
+        if date.weekday() != by_day.weekday {
+            return false;
+        }
+        let Some(nth) = by_day.nth else {
+            return true;
+        };
+        // The position is within the month, unless the rule is yearly without BYMONTH
+        let (day, len) = if self.freq == Frequency::Yearly && self.by_month.is_empty() {
+            (date.ordinal(), days_in_year(date.year()))
+        } else {
+            (date.day(), days_in_month(date))
+        };
+        let from_start = ((day - 1) / 7 + 1) as i8;
+        let from_end = -(((len - day) / 7 + 1) as i8);
+        nth == from_start || nth == from_end
+    }
+
+    fn set_pos(&self, mut candidates: Vec<NaiveDateTime>) -> Vec<NaiveDateTime> {
+        candidates.sort();
+        candidates.dedup();
+        if self.by_set_pos.is_empty() {
+            return candidates;
+        }
+        let len = candidates.len() as i64;
+        let selected: BTreeSet<NaiveDateTime> = self
+            .by_set_pos
+            .iter()
+            .filter_map(|pos| {
+                let index = if *pos > 0 {
+                    *pos as i64 - 1
+                } else {
+                    len + *pos as i64
+                };
+                usize::try_from(index)
+                    .ok()
+                    .and_then(|i| candidates.get(i).copied())
+            })
+            .collect();
+        selected.into_iter().collect()
+    }
+}
+
+impl fmt::Display for RRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "FREQ={}", self.freq.as_str())?;
+        if let Some(until) = &self.until {
+            write!(f, ";UNTIL={until}")?;
+        }
+        if let Some(count) = self.count {
+            write!(f, ";COUNT={count}")?;
+        }
+        if self.interval != 1 {
+            write!(f, ";INTERVAL={}", self.interval)?;
+        }
+        write_list(f, "BYSECOND", &self.by_second)?;
+        write_list(f, "BYMINUTE", &self.by_minute)?;
+        write_list(f, "BYHOUR", &self.by_hour)?;
+        write_list(f, "BYDAY", &self.by_day)?;
+        write_list(f, "BYMONTHDAY", &self.by_month_day)?;
+        write_list(f, "BYYEARDAY", &self.by_year_day)?;
+        write_list(f, "BYWEEKNO", &self.by_week_no)?;
+        write_list(f, "BYMONTH", &self.by_month)?;
+        write_list(f, "BYSETPOS", &self.by_set_pos)?;
+        if let Some(week_start) = self.week_start {
+            write!(f, ";WKST={}", weekday_code(week_start))?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for RRule {
+    type Err = GoogleApiError;
+
+    /// Parses the value of a rule, with or without the `RRULE:` or `EXRULE:` prefix, and
+    /// validates it.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s
+            .strip_prefix("RRULE:")
+            .or_else(|| s.strip_prefix("EXRULE:"))
+            .unwrap_or(s);
+        let mut freq = None;
+        let mut rule = RRule::new(Frequency::Daily);
+        let mut seen = BTreeSet::new();
+        for part in value.split(';').filter(|p| !p.is_empty()) {
+            let (name, value) = part
+                .split_once('=')
+                .ok_or_else(|| invalid(format!("invalid rule part {part}")))?;
+            if !seen.insert(name) {
+                return Err(invalid(format!("{name} is given more than once")));
+            }
+            match name {
+                "FREQ" => freq = Some(value.parse()?),
+                "INTERVAL" => rule.interval = parse_number(name, value)?,
+                "COUNT" => rule.count = Some(parse_number(name, value)?),
+                "UNTIL" => rule.until = Some(value.parse()?),
+                "BYSECOND" => rule.by_second = parse_list(name, value)?,
+                "BYMINUTE" => rule.by_minute = parse_list(name, value)?,
+                "BYHOUR" => rule.by_hour = parse_list(name, value)?,
+                "BYDAY" => {
+                    rule.by_day = value.split(',').map(str::parse).collect::<Result<_, _>>()?
+                }
+                "BYMONTHDAY" => rule.by_month_day = parse_list(name, value)?,
+                "BYYEARDAY" => rule.by_year_day = parse_list(name, value)?,
+                "BYWEEKNO" => rule.by_week_no = parse_list(name, value)?,
+                "BYMONTH" => rule.by_month = parse_list(name, value)?,
+                "BYSETPOS" => rule.by_set_pos = parse_list(name, value)?,
+                "WKST" => rule.week_start = Some(parse_weekday(value)?),
+                _ => return Err(invalid(format!("unknown rule part {name}"))),
+            }
+        }
+        rule.freq = freq.ok_or_else(|| invalid("FREQ is required".to_string()))?;
+        rule.validate()?;
+        Ok(rule)
+    }
+}
+
+/// The recurrence of an event: the `RRULE`, `EXRULE`, `RDATE` and `EXDATE` lines of
+/// `Event::recurrence`.
+///
+/// # Example
+/// ```rust
+/// let event = CalendarEventsClient::new(&client)
+///     .get_event("primary", &event_id)
+///     .request()
+///     .await?
+///     .unwrap();
+/// let recurrence = event.parse_recurrence()?;
+/// println!("{}", recurrence.describe());
+/// // Preview the next month without requesting the instances from Google
+/// let start = event.start.unwrap().date_time.unwrap();
+/// let next = recurrence.occurrences_between(&start, Utc::now(), Utc::now() + Duration::days(30))?;
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Recurrence {
+    pub rules: Vec<RRule>,
+    pub exception_rules: Vec<RRule>,
+    pub dates: Vec<DateList>,
+    pub exception_dates: Vec<DateList>,
+}
+
+impl Recurrence {
+    /// A recurrence with a single rule.
+    pub fn new(rule: RRule) -> Self {
+        Self {
+            rules: vec![rule],
+            ..Default::default()
+        }
+    }
+
+    /// Adds an `RRULE`.
+    pub fn rule(mut self, rule: RRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Adds an `EXRULE`, occurrences of this rule are removed.
+    pub fn exception_rule(mut self, rule: RRule) -> Self {
+        self.exception_rules.push(rule);
+        self
+    }
+
+    /// Adds an extra occurrence (`RDATE`).
+    pub fn include(mut self, date: RecurrenceDate) -> Self {
+        self.dates.push(DateList::new(vec![date]));
+        self
+    }
+
+    /// Removes an occurrence (`EXDATE`). A date removes every occurrence on that day.
+    pub fn exclude(mut self, date: RecurrenceDate) -> Self {
+        self.exception_dates.push(DateList::new(vec![date]));
+        self
+    }
+
+    /// Parses the recurrence lines of an event and validates them.
+    pub fn parse<I, S>(lines: I) -> Result<Self, GoogleApiError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut recurrence = Recurrence::default();
+        for line in lines {
+            let line = line.as_ref().trim();
+            let (name, value) = line
+                .split_once(':')
+                .ok_or_else(|| invalid(format!("invalid line {line}")))?;
+            let (name, params) = name.split_once(';').unwrap_or((name, ""));
+            match name {
+                "RRULE" if params.is_empty() => recurrence.rules.push(value.parse()?),
+                "EXRULE" if params.is_empty() => recurrence.exception_rules.push(value.parse()?),
+                "RDATE" => recurrence.dates.push(DateList::parse(params, value)?),
+                "EXDATE" => recurrence
+                    .exception_dates
+                    .push(DateList::parse(params, value)?),
+                _ => return Err(invalid(format!("unsupported line {line}"))),
+            }
+        }
+        Ok(recurrence)
+    }
+
+    /// Checks every rule, see `RRule::validate`.
+    pub fn validate(&self) -> Result<(), GoogleApiError> {
+        if self.rules.is_empty() && self.dates.is_empty() {
+            return Err(invalid(
+                "at least one RRULE or RDATE is required".to_string(),
+            ));
+        }
+        self.rules
+            .iter()
+            .chain(&self.exception_rules)
+            .try_for_each(RRule::validate)
+    }
+
+    /// The lines as stored in `Event::recurrence`.
+    pub fn to_lines(&self) -> Vec<String> {
+        self.rules
+            .iter()
+            .map(|rule| format!("RRULE:{rule}"))
+            .chain(
+                self.exception_rules
+                    .iter()
+                    .map(|rule| format!("EXRULE:{rule}")),
+            )
+            .chain(self.dates.iter().map(|dates| dates.to_line("RDATE")))
+            .chain(
+                self.exception_dates
+                    .iter()
+                    .map(|dates| dates.to_line("EXDATE")),
+            )
+            .collect()
+    }
+
+    /// A short English description of the rules, e.g. "Weekly on Monday, 10 times".
+    pub fn describe(&self) -> String {
+        let rules: Vec<String> = self.rules.iter().map(RRule::describe).collect();
+        rules.join("; ")
+    }
+
+    /// The occurrences of an event starting at `start` that start within `from..to`.
+    ///
+    /// The rules are expanded in the local time of `start`, so a weekly meeting at 09:00 stays
+    /// at 09:00 across daylight saving time changes.
+    ///
+    /// Fails instead of returning a partial list if a rule would have to be expanded over more
+    /// than 100 000 periods to reach `to`, e.g. a `SECONDLY` rule over more than a day.
+    pub fn occurrences_between<Tz: TimeZone>(
+        &self,
+        start: &DateTime<Tz>,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<DateTime<Tz>>, GoogleApiError> {
+        let tz = start.timezone();
+        let local = |naive: NaiveDateTime| {
+            tz.from_local_datetime(&naive).earliest().or_else(|| {
+                // Skipped by a daylight saving time change, use the time after the gap
+                tz.from_local_datetime(&(naive + Duration::hours(1)))
+                    .earliest()
+            })
+        };
+        let to_utc = |naive: NaiveDateTime| local(naive).map(|d| d.with_timezone(&Utc));
+        let first = start.naive_local();
+        let end = to.with_timezone(&tz).naive_local() + Duration::days(1);
+
+        let mut occurrences = BTreeSet::new();
+        for rule in &self.rules {
+            occurrences.extend(rule.expand(first, end, &to_utc)?);
+        }
+        for date in self.dates.iter().flat_map(|list| &list.dates) {
+            occurrences.insert(match date {
+                RecurrenceDate::Date(date) => date.and_time(first.time()),
+                RecurrenceDate::DateTime(date) => *date,
+                RecurrenceDate::Utc(date) => date.with_timezone(&tz).naive_local(),
+            });
+        }
+        for rule in &self.exception_rules {
+            for excluded in rule.expand(first, end, &to_utc)? {
+                occurrences.remove(&excluded);
+            }
+        }
+        for date in self.exception_dates.iter().flat_map(|list| &list.dates) {
+            match date {
+                RecurrenceDate::Date(date) => occurrences.retain(|o| o.date() != *date),
+                RecurrenceDate::DateTime(date) => {
+                    occurrences.remove(date);
+                }
+                RecurrenceDate::Utc(date) => {
+                    occurrences.remove(&date.with_timezone(&tz).naive_local());
+                }
+            }
+        }
+
+        Ok(occurrences
+            .into_iter()
+            .filter_map(local)
+            .filter(|o| {
+                let utc = o.with_timezone(&Utc);
+                utc >= from && utc < to
+            })
+            .collect())
+    }
+
+    /// The occurrences of an all-day event starting on `start` within `from..to`.
+    pub fn dates_between(
+        &self,
+        start: NaiveDate,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<NaiveDate>, GoogleApiError> {
+        let midnight = |date: NaiveDate| date.and_time(NaiveTime::MIN).and_utc();
+        Ok(self
+            .occurrences_between(&midnight(start), midnight(from), midnight(to))?
+            .into_iter()
+            .map(|o| o.date_naive())
+            .collect())
+    }
+}
+
+impl FromStr for Recurrence {
+    type Err = GoogleApiError;
+
+    /// Parses recurrence lines separated by newlines.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s.lines().filter(|l| !l.trim().is_empty()))
+    }
+}
+
+impl From<Recurrence> for Vec<String> {
+    fn from(recurrence: Recurrence) -> Self {
+        recurrence.to_lines()
+    }
+}
+
+fn invalid(message: String) -> GoogleApiError {
+    GoogleApiError::InvalidRequest(format!("Invalid recurrence: {message}"))
+}
+
+fn or_default(values: &[u8], default: u8) -> Vec<u8> {
+    if values.is_empty() {
+        vec![default]
+    } else {
+        values.to_vec()
+    }
+}
+
+fn check_range<T>(
+    name: &str,
+    values: &[T],
+    min: T,
+    max: T,
+    nonzero: bool,
+) -> Result<(), GoogleApiError>
+where
+    T: PartialOrd + Default + Copy + fmt::Display,
+{
+    match values
+        .iter()
+        .find(|v| **v < min || **v > max || (nonzero && **v == T::default()))
+    {
+        Some(value) => Err(invalid(format!("{name} value {value} is out of range"))),
+        None => Ok(()),
+    }
+}
+
+fn parse_number<T: FromStr>(name: &str, value: &str) -> Result<T, GoogleApiError> {
+    value
+        .trim_start_matches('+')
+        .parse()
+        .map_err(|_| invalid(format!("invalid {name} value {value}")))
+}
+
+fn parse_list<T: FromStr>(name: &str, value: &str) -> Result<Vec<T>, GoogleApiError> {
+    value.split(',').map(|v| parse_number(name, v)).collect()
+}
+
+fn write_list<T: fmt::Display>(
+    f: &mut fmt::Formatter<'_>,
+    name: &str,
+    values: &[T],
+) -> fmt::Result {
+    if values.is_empty() {
+        return Ok(());
+    }
+    let values: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+    write!(f, ";{name}={}", values.join(","))
+}
+
+fn parse_weekday(code: &str) -> Result<Weekday, GoogleApiError> {
+    match code {
+        "MO" => Ok(Weekday::Mon),
+        "TU" => Ok(Weekday::Tue),
+        "WE" => Ok(Weekday::Wed),
+        "TH" => Ok(Weekday::Thu),
+        "FR" => Ok(Weekday::Fri),
+        "SA" => Ok(Weekday::Sat),
+        "SU" => Ok(Weekday::Sun),
+        _ => Err(invalid(format!("unknown weekday {code}"))),
+    }
+}
+
+fn weekday_code(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "MO",
+        Weekday::Tue => "TU",
+        Weekday::Wed => "WE",
+        Weekday::Thu => "TH",
+        Weekday::Fri => "FR",
+        Weekday::Sat => "SA",
+        Weekday::Sun => "SU",
+    }
+}
+
+fn weekday_name(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "Monday",
+        Weekday::Tue => "Tuesday",
+        Weekday::Wed => "Wednesday",
+        Weekday::Thu => "Thursday",
+        Weekday::Fri => "Friday",
+        Weekday::Sat => "Saturday",
+        Weekday::Sun => "Sunday",
+    }
+}
+
+fn month_name(month: u8) -> &'static str {
+    match month {
+        1 => "January",
+        2 => "February",
+        3 => "March",
+        4 => "April",
+        5 => "May",
+        6 => "June",
+        7 => "July",
+        8 => "August",
+        9 => "September",
+        10 => "October",
+        11 => "November",
+        _ => "December",
+    }
+}
+
+fn ordinal(n: i32) -> String {
+    match n {
+        1 => "first".to_string(),
+        2 => "second".to_string(),
+        3 => "third".to_string(),
+        4 => "fourth".to_string(),
+        5 => "fifth".to_string(),
+        -1 => "last".to_string(),
+        n if n < 0 => format!("{} to last", ordinal(-n)),
+        n => {
+            let suffix = match (n % 10, n % 100) {
+                (_, 11..=13) => "th",
+                (1, _) => "st",
+                (2, _) => "nd",
+                (3, _) => "rd",
+                _ => "th",
+            };
+            format!("{n}{suffix}")
+        }
+    }
+}
+
+fn days_in_month(date: &NaiveDate) -> u32 {
+    let first = date.with_day(1).unwrap_or(*date);
+    first
+        .checked_add_months(Months::new(1))
+        .map(|next| (next - first).num_days() as u32)
+        .unwrap_or(31)
+}
+
+fn days_in_year(year: i32) -> u32 {
+    if NaiveDate::from_ymd_opt(year, 2, 29).is_some() {
+        366
+    } else {
+        365
+    }
+}
+
+fn week_matches(date: &NaiveDate, week: i8) -> bool {
+    let iso = date.iso_week();
+    if iso.year() != date.year() {
+        return false;
+    }
+    let weeks = if NaiveDate::from_isoywd_opt(date.year(), 53, Weekday::Mon).is_some() {
+        53
+    } else {
+        52
+    };
+    let number = iso.week() as i8;
+    week == number || week == number - weeks - 1
+}
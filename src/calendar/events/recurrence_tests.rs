@@ -0,0 +1,256 @@
+#![allow(clippy::unwrap_used)]
+
+use chrono::{DateTime, NaiveDate, TimeZone, Utc, Weekday};
+
+use super::recurrence::{ByDay, Frequency, RRule, Recurrence, RecurrenceDate};
+use crate::error::GoogleApiError;
+
+fn utc(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(y, m, d, h, min, 0).unwrap()
+}
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+#[test]
+fn builder_formats_rule() {
+    let rule = RRule::new(Frequency::Weekly)
+        .interval(2)
+        .by_weekdays(&[Weekday::Mon, Weekday::Wed])
+        .count(10);
+    assert_eq!(
+        rule.to_string(),
+        "FREQ=WEEKLY;COUNT=10;INTERVAL=2;BYDAY=MO,WE"
+    );
+
+    let recurrence = Recurrence::new(
+        RRule::new(Frequency::Monthly)
+            .by_day(vec![ByDay::nth(-1, Weekday::Fri)])
+            .until(RecurrenceDate::Utc(utc(2025, 12, 31, 23, 59))),
+    )
+    .exclude(RecurrenceDate::Date(date(2025, 3, 28)));
+    assert_eq!(
+        recurrence.to_lines(),
+        vec![
+            "RRULE:FREQ=MONTHLY;UNTIL=20251231T235900Z;BYDAY=-1FR",
+            "EXDATE;VALUE=DATE:20250328",
+        ]
+    );
+}
+
+#[test]
+fn parse_round_trips_lines() {
+    let lines = vec![
+        "RRULE:FREQ=YEARLY;COUNT=5;BYDAY=1SU;BYMONTH=3;WKST=SU".to_string(),
+        "EXRULE:FREQ=YEARLY;BYMONTHDAY=-1;BYMONTH=3".to_string(),
+        "RDATE;VALUE=DATE:20250101,20250102".to_string(),
+        "EXDATE;TZID=Europe/Amsterdam:20250309T090000".to_string(),
+    ];
+    let recurrence = Recurrence::parse(&lines).unwrap();
+
+    assert_eq!(
+        recurrence.rules[0].by_day,
+        vec![ByDay::nth(1, Weekday::Sun)]
+    );
+    assert_eq!(recurrence.rules[0].week_start, Some(Weekday::Sun));
+    assert_eq!(recurrence.dates[0].dates.len(), 2);
+    assert_eq!(
+        recurrence.exception_dates[0].tzid.as_deref(),
+        Some("Europe/Amsterdam")
+    );
+    assert_eq!(recurrence.to_lines(), lines);
+    assert_eq!(
+        recurrence.describe(),
+        "Annually on the first Sunday in March, 5 times"
+    );
+}
+
+#[test]
+fn invalid_rules_are_rejected() {
+    for rule in [
+        "RRULE:INTERVAL=2",
+        "RRULE:FREQ=FORTNIGHTLY",
+        "RRULE:FREQ=DAILY;COUNT=2;UNTIL=20250101",
+        "RRULE:FREQ=DAILY;INTERVAL=0",
+        "RRULE:FREQ=WEEKLY;BYDAY=1MO",
+        "RRULE:FREQ=WEEKLY;BYMONTHDAY=1",
+        "RRULE:FREQ=MONTHLY;BYMONTHDAY=32",
+        "RRULE:FREQ=MONTHLY;BYMONTH=13",
+        "RRULE:FREQ=MONTHLY;BYWEEKNO=1",
+        "RRULE:FREQ=MONTHLY;BYSETPOS=1",
+        "RRULE:FREQ=DAILY;FREQ=WEEKLY",
+        "RRULE:FREQ=DAILY;BYDAY=XX",
+        "RRULE:FREQ=DAILY;COLOR=RED",
+        "DTSTART:20250101T090000Z",
+    ] {
+        assert!(
+            Recurrence::parse([rule]).is_err(),
+            "{rule} should be rejected"
+        );
+    }
+    assert!(RRule::new(Frequency::Daily).interval(0).validate().is_err());
+}
+
+#[test]
+fn expands_weekly_rule_with_count() {
+    let recurrence: Recurrence = "RRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,WE;COUNT=5"
+        .parse()
+        .unwrap();
+    // Wednesday 1 January 2025
+    let start = utc(2025, 1, 1, 9, 0);
+    let occurrences = recurrence
+        .occurrences_between(&start, utc(2025, 1, 1, 0, 0), utc(2026, 1, 1, 0, 0))
+        .unwrap();
+
+    assert_eq!(
+        occurrences,
+        vec![
+            utc(2025, 1, 1, 9, 0),
+            utc(2025, 1, 13, 9, 0),
+            utc(2025, 1, 15, 9, 0),
+            utc(2025, 1, 27, 9, 0),
+            utc(2025, 1, 29, 9, 0),
+        ]
+    );
+}
+
+#[test]
+fn expands_monthly_positions_and_set_pos() {
+    // Last working day of the month
+    let recurrence = Recurrence::new(
+        RRule::new(Frequency::Monthly)
+            .by_weekdays(&[
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Fri,
+            ])
+            .by_set_pos(vec![-1]),
+    );
+    let dates = recurrence
+        .dates_between(date(2025, 1, 31), date(2025, 1, 1), date(2025, 6, 1))
+        .unwrap();
+    assert_eq!(
+        dates,
+        vec![
+            date(2025, 1, 31),
+            date(2025, 2, 28),
+            date(2025, 3, 31),
+            date(2025, 4, 30),
+            date(2025, 5, 30),
+        ]
+    );
+
+    let day_31: Recurrence = "RRULE:FREQ=MONTHLY;BYMONTHDAY=31;COUNT=3".parse().unwrap();
+    assert_eq!(
+        day_31
+            .dates_between(date(2025, 1, 31), date(2025, 1, 1), date(2026, 1, 1))
+            .unwrap(),
+        vec![date(2025, 1, 31), date(2025, 3, 31), date(2025, 5, 31)]
+    );
+}
+
+#[test]
+fn until_rdate_exdate_and_range_are_applied() {
+    let recurrence = Recurrence::parse([
+        "RRULE:FREQ=DAILY;UNTIL=20250110",
+        "RDATE;VALUE=DATE:20250120",
+        "EXDATE;VALUE=DATE:20250103",
+    ])
+    .unwrap();
+    let dates = recurrence
+        .dates_between(date(2025, 1, 1), date(2025, 1, 2), date(2025, 2, 1))
+        .unwrap();
+
+    assert_eq!(dates.first(), Some(&date(2025, 1, 2)));
+    assert!(!dates.contains(&date(2025, 1, 3)));
+    assert_eq!(dates.len(), 9);
+    assert_eq!(dates.last(), Some(&date(2025, 1, 20)));
+}
+
+#[test]
+fn expands_in_local_time_of_start() {
+    let cet = chrono::FixedOffset::east_opt(3600).unwrap();
+    let recurrence: Recurrence = "RRULE:FREQ=DAILY;COUNT=2".parse().unwrap();
+    let start = cet.with_ymd_and_hms(2025, 3, 29, 9, 0, 0).unwrap();
+    let occurrences = recurrence
+        .occurrences_between(&start, utc(2025, 3, 1, 0, 0), utc(2025, 4, 1, 0, 0))
+        .unwrap();
+    assert_eq!(occurrences.len(), 2);
+    assert_eq!(occurrences[1].to_rfc3339(), "2025-03-30T09:00:00+01:00");
+
+    let hourly: Recurrence = "RRULE:FREQ=HOURLY;INTERVAL=6;BYMINUTE=0,30;COUNT=3"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        hourly
+            .occurrences_between(
+                &utc(2025, 1, 1, 0, 0),
+                utc(2025, 1, 1, 0, 0),
+                utc(2025, 1, 2, 0, 0)
+            )
+            .unwrap(),
+        vec![
+            utc(2025, 1, 1, 0, 0),
+            utc(2025, 1, 1, 0, 30),
+            utc(2025, 1, 1, 6, 0)
+        ]
+    );
+}
+
+#[test]
+fn expansion_that_hits_the_period_limit_fails() {
+    let secondly: Recurrence = "RRULE:FREQ=SECONDLY".parse().unwrap();
+
+    let res = secondly.occurrences_between(
+        &utc(2025, 1, 1, 0, 0),
+        utc(2025, 1, 1, 0, 0),
+        utc(2025, 1, 3, 0, 0),
+    );
+    match res {
+        Err(GoogleApiError::InvalidRequest(error)) => {
+            assert!(error.contains("100000 periods"), "{error}")
+        }
+        other => panic!("expected an error, got {other:?}"),
+    }
+    assert_eq!(
+        secondly
+            .occurrences_between(
+                &utc(2025, 1, 1, 0, 0),
+                utc(2025, 1, 1, 0, 0),
+                utc(2025, 1, 1, 1, 0)
+            )
+            .unwrap()
+            .len(),
+        3600
+    );
+}
+
+#[test]
+fn describes_ordinals_with_english_suffixes() {
+    let yearly: Recurrence = "RRULE:FREQ=YEARLY;BYDAY=11MO,12TU,13WE,21TH,22FR,23SA,-2SU"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        yearly.describe(),
+        "Annually on the 11th Monday, the 12th Tuesday, the 13th Wednesday, the 21st Thursday, \
+         the 22nd Friday, the 23rd Saturday, the second to last Sunday"
+    );
+
+    let monthly: Recurrence = "RRULE:FREQ=MONTHLY;BYMONTHDAY=-21,-12".parse().unwrap();
+    assert_eq!(
+        monthly.describe(),
+        "Monthly on day the 21st to last day, the 12th to last day"
+    );
+}
+
+#[test]
+fn event_recurrence_is_parsed() {
+    let event: super::types::Event =
+        serde_json::from_str(r#"{"id": "a", "recurrence": ["RRULE:FREQ=WEEKLY;BYDAY=TU"]}"#)
+            .unwrap();
+    let recurrence = event.parse_recurrence().unwrap();
+    assert_eq!(recurrence.describe(), "Weekly on Tuesday");
+}
@@ -4,17 +4,25 @@ use crate::{
     auth::client::{get_validity_token_secs, AccessToken, ClientCredentials, GoogleClient},
     calendar::{
         events::{
+            ical::parse_ics,
             requests::EventRequest,
-            types::{EventAttendee, EventList},
+            types::{Event, EventAttendee},
         },
-        prelude::{EventOrderBy, EventType},
+        prelude::{
+            EventOrderBy, EventStatus, EventType, ResponseStatus, SendUpdates, Transparency,
+            Visibility,
+        },
+    },
+    error::GoogleApiError,
+    utils::{
+        endpoints::ApiEndpoints,
+        request::{PaginationRequestTrait, TimeRequestTrait},
     },
-    utils::request::TimeRequestTrait,
 };
 
 use super::{requests::CalendarEventsClient, types::EventDateTime};
-use anyhow::Error;
-use chrono::{Duration, Utc};
+use chrono::{Duration, NaiveDate, TimeZone, Utc};
+use futures::TryStreamExt;
 use reqwest::Method;
 
 fn dummy_creds() -> ClientCredentials {
@@ -48,7 +56,7 @@ fn dummy_google_client_valid() -> GoogleClient {
 
 fn sample_dt(date: &str) -> EventDateTime {
     EventDateTime {
-        date: Some(date.to_string()),
+        date: Some(date.parse().unwrap()),
         date_time: None,
         time_zone: None,
     }
@@ -87,9 +95,9 @@ fn google_client_validity_flag() {
 
 #[test]
 fn get_events_sets_url_method_and_params() {
-    let mut gc = dummy_google_client_valid();
+    let gc = dummy_google_client_valid();
 
-    let builder = CalendarEventsClient::new(&mut gc)
+    let builder = CalendarEventsClient::new(&gc)
         .get_events("primary")
         .single_events(true)
         .event_type(EventType::Birthday)
@@ -119,12 +127,12 @@ fn get_events_sets_url_method_and_params() {
 
 #[test]
 fn time_filters_are_serialized_as_rfc3339() {
-    let mut gc = dummy_google_client_valid();
+    let gc = dummy_google_client_valid();
 
     let now = Utc::now();
     let later = now + Duration::days(1);
 
-    let builder = CalendarEventsClient::new(&mut gc)
+    let builder = CalendarEventsClient::new(&gc)
         .get_events("primary")
         .time_min(now)
         .time_max(later);
@@ -136,7 +144,7 @@ fn time_filters_are_serialized_as_rfc3339() {
 
 #[test]
 fn insert_event_builds_body_and_setters_apply() {
-    let mut gc = dummy_google_client_valid();
+    let gc = dummy_google_client_valid();
 
     let start = sample_dt("2025-07-28");
     let end = sample_dt("2025-07-28");
@@ -150,13 +158,13 @@ fn insert_event_builds_body_and_setters_apply() {
             self_: None,
             resource: None,
             optional: None,
-            response_status: String::new(),
+            response_status: None,
             comment: String::new(),
             additional_guests: 0,
         }
     }];
 
-    let builder = CalendarEventsClient::new(&mut gc)
+    let builder = CalendarEventsClient::new(&gc)
         .insert_event("cal_123", start.clone(), end.clone())
         .set_summary("My Summary")
         .set_description("Desc")
@@ -184,7 +192,7 @@ fn insert_event_builds_body_and_setters_apply() {
             assert_eq!(payload.color_id.as_deref(), Some("5"));
             assert_eq!(payload.attendees, attendees);
             assert_eq!(payload.recurrence, vec!["RRULE:FREQ=DAILY".to_string()]);
-            assert_eq!(payload.event_type.as_deref(), Some("default"));
+            assert_eq!(payload.event_type, Some(EventType::Default));
         }
         _ => panic!("expected CreateEventRequest"),
     }
@@ -192,12 +200,12 @@ fn insert_event_builds_body_and_setters_apply() {
 
 #[test]
 fn patch_event_setters_apply() {
-    let mut gc = dummy_google_client_valid();
+    let gc = dummy_google_client_valid();
 
     let new_start = sample_dt("2026-02-01");
     let new_end = sample_dt("2026-02-02");
 
-    let builder = CalendarEventsClient::new(&mut gc)
+    let builder = CalendarEventsClient::new(&gc)
         .patch_event("primary", "evt_42")
         .set_summary("New title")
         .set_description("New desc")
@@ -210,12 +218,12 @@ fn patch_event_setters_apply() {
         .set_id("new-id")
         .set_recurrence(vec!["RRULE:FREQ=WEEKLY".into()])
         .set_sequence(7)
-        .set_status("tentative")
-        .set_transparancy("opaque")
-        .set_visibility("private")
+        .set_status(EventStatus::Tentative)
+        .set_transparency(Transparency::Opaque)
+        .set_visibility(Visibility::Private)
         .set_start(new_start.clone())
         .set_end(new_end.clone())
-        .set_send_updates("all")
+        .set_send_updates(SendUpdates::All)
         .set_conference_data_version(1)
         .support_attachments(true)
         .set_max_attendees(3);
@@ -248,16 +256,16 @@ fn patch_event_setters_apply() {
             assert_eq!(payload.description.as_deref(), Some("New desc"));
             assert_eq!(payload.location.as_deref(), Some("Moon base"));
             assert_eq!(payload.color_id.as_deref(), Some("9"));
-            assert_eq!(payload.event_type.as_deref(), Some("outOfOffice"));
+            assert_eq!(payload.event_type, Some(EventType::OutOfOffice));
             assert_eq!(payload.guests_can_invite_others, Some(true));
             assert_eq!(payload.guests_can_modify, Some(false));
             assert_eq!(payload.guests_can_see_other_guests, Some(true));
             assert_eq!(payload.id.as_deref(), Some("new-id"));
             assert_eq!(payload.recurrence, vec!["RRULE:FREQ=WEEKLY".to_string()]);
             assert_eq!(payload.sequence, Some(7));
-            assert_eq!(payload.status.as_deref(), Some("tentative"));
-            assert_eq!(payload.transparency.as_deref(), Some("opaque"));
-            assert_eq!(payload.visibility.as_deref(), Some("private"));
+            assert_eq!(payload.status, Some(EventStatus::Tentative));
+            assert_eq!(payload.transparency, Some(Transparency::Opaque));
+            assert_eq!(payload.visibility, Some(Visibility::Private));
         }
         _ => panic!("expected PatchEventRequest"),
     }
@@ -265,12 +273,12 @@ fn patch_event_setters_apply() {
 
 #[test]
 fn serialized_event_body_matches_expected_format() {
-    let mut gc = dummy_google_client_valid();
+    let gc = dummy_google_client_valid();
 
     let new_start = sample_dt("2026-02-01");
     let new_end = sample_dt("2026-02-02");
 
-    let builder = CalendarEventsClient::new(&mut gc)
+    let builder = CalendarEventsClient::new(&gc)
         .patch_event("primary", "evt_42")
         .set_summary("New title")
         .set_description("New desc")
@@ -294,13 +302,883 @@ fn serialized_event_body_matches_expected_format() {
 }
 
 #[tokio::test]
-async fn make_request_unsupported_method_errors() {
+async fn make_request_sends_any_method_with_json_body() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("PUT", "/calendars/primary/events/evt_42")
+        .match_header("authorization", "Bearer atok")
+        .match_header("content-type", "application/json")
+        .match_body(mockito::Matcher::PartialJson(
+            serde_json::json!({"summary": "Replaced"}),
+        ))
+        .with_status(200)
+        .with_body(r#"{"id": "evt_42", "summary": "Replaced"}"#)
+        .create_async()
+        .await;
+
+    let gc = dummy_google_client_valid();
+    let mut client = CalendarEventsClient::new(&gc)
+        .patch_event("primary", "evt_42")
+        .set_summary("Replaced");
+    // The shared executor doesn't depend on the mode the builder was created for
+    client.request.method = Method::PUT;
+    client.request.url = format!("{}/calendars/primary/events/evt_42", server.url());
+
+    let event: Option<Event> = client.make_request().await.unwrap();
+
+    assert_eq!(event.unwrap().summary, "Replaced");
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn make_request_maps_error_status() {
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server
+        .mock("DELETE", "/calendars/primary/events/missing")
+        .with_status(404)
+        .with_body(r#"{"error": {"code": 404, "message": "Not Found"}}"#)
+        .create_async()
+        .await;
+
+    let gc = dummy_google_client_valid();
+    let mut client = CalendarEventsClient::new(&gc).delete_event("primary", "missing");
+    client.request.url = format!("{}/calendars/primary/events/missing", server.url());
+
+    let res = client.request().await;
+    assert!(matches!(res, Err(GoogleApiError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn custom_endpoints_route_requests_and_token_refresh() {
+    let mut server = mockito::Server::new_async().await;
+    let token = server
+        .mock("POST", "/token")
+        .match_body(mockito::Matcher::UrlEncoded(
+            "grant_type".into(),
+            "refresh_token".into(),
+        ))
+        .with_status(200)
+        .with_body(r#"{"access_token": "fresh", "expires_in": 3600, "token_type": "Bearer"}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let events = server
+        .mock("GET", "/calendar/v3/calendars/primary/events")
+        .match_header("authorization", "Bearer fresh")
+        .with_status(200)
+        .with_body(r#"{"kind": "calendar#events", "items": [{"id": "evt_1"}]}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let mut gc = GoogleClient::new(dummy_creds(), dummy_access(-10), true);
+    gc.set_endpoints(ApiEndpoints::with_base_url(&server.url()));
+
+    let list = CalendarEventsClient::new(&gc)
+        .get_events("primary")
+        .request()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(list.items[0].id, "evt_1");
+    token.assert_async().await;
+    events.assert_async().await;
+}
+
+async fn mock_two_event_pages(
+    server: &mut mockito::ServerGuard,
+    second_page_hits: usize,
+) -> (mockito::Mock, mockito::Mock) {
+    let first = server
+        .mock("GET", "/calendar/v3/calendars/primary/events")
+        .match_query(mockito::Matcher::Missing)
+        .with_status(200)
+        .with_body(r#"{"items": [{"id": "evt_1"}, {"id": "evt_2"}], "nextPageToken": "page2"}"#)
+        .create_async()
+        .await;
+    let second = server
+        .mock("GET", "/calendar/v3/calendars/primary/events")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "pageToken".into(),
+            "page2".into(),
+        ))
+        .with_status(200)
+        .with_body(r#"{"items": [{"id": "evt_3"}]}"#)
+        .expect(second_page_hits)
+        .create_async()
+        .await;
+    (first, second)
+}
+
+#[tokio::test]
+async fn collect_all_follows_next_page_token() {
+    let mut server = mockito::Server::new_async().await;
+    let (first, second) = mock_two_event_pages(&mut server, 1).await;
+
+    let mut gc = dummy_google_client_valid();
+    gc.set_endpoints(ApiEndpoints::with_base_url(&server.url()));
+
+    let events = CalendarEventsClient::new(&gc)
+        .get_events("primary")
+        .collect_all()
+        .await
+        .unwrap();
+
+    let ids: Vec<_> = events.iter().map(|e| e.id.as_str()).collect();
+    assert_eq!(ids, vec!["evt_1", "evt_2", "evt_3"]);
+    first.assert_async().await;
+    second.assert_async().await;
+}
+
+#[tokio::test]
+async fn stream_stops_at_max_items_without_fetching_next_page() {
+    let mut server = mockito::Server::new_async().await;
+    let (first, second) = mock_two_event_pages(&mut server, 0).await;
+
+    let mut gc = dummy_google_client_valid();
+    gc.set_endpoints(ApiEndpoints::with_base_url(&server.url()));
+
+    let events: Vec<Event> = CalendarEventsClient::new(&gc)
+        .get_events("primary")
+        .max_items(2)
+        .stream()
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(events.len(), 2);
+    first.assert_async().await;
+    second.assert_async().await;
+}
+
+#[tokio::test]
+async fn get_event_returns_single_event() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/calendar/v3/calendars/primary/events/evt_42")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "maxAttendees".into(),
+            "2".into(),
+        ))
+        .with_status(200)
+        .with_body(r#"{"id": "evt_42", "summary": "Standup"}"#)
+        .create_async()
+        .await;
+
+    let mut gc = dummy_google_client_valid();
+    gc.set_endpoints(ApiEndpoints::with_base_url(&server.url()));
+
+    let event = CalendarEventsClient::new(&gc)
+        .get_event("primary", "evt_42")
+        .max_attendees(2)
+        .request()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(event.id, "evt_42");
+    assert_eq!(event.summary, "Standup");
+    mock.assert_async().await;
+}
+
+#[test]
+fn get_instances_sets_url_and_params() {
+    let gc = dummy_google_client_valid();
+    let original_start = Utc::now();
+
+    let builder = CalendarEventsClient::new(&gc)
+        .get_instances("primary", "recurring_1")
+        .time_min(original_start)
+        .time_max(original_start + Duration::days(7))
+        .max_attendees(1)
+        .original_start(original_start)
+        .show_deleted(true)
+        .time_zone("Europe/Amsterdam")
+        .max_results(50)
+        .page_token("next");
+
+    assert_eq!(
+        builder.request.url,
+        "https://www.googleapis.com/calendar/v3/calendars/primary/events/recurring_1/instances"
+    );
+    assert_eq!(builder.request.method, Method::GET);
+
+    let p = &builder.request.params;
+    assert_eq!(p.get("timeMin").unwrap(), &original_start.to_rfc3339());
+    assert_eq!(
+        p.get("originalStart").unwrap(),
+        &original_start.to_rfc3339()
+    );
+    assert_eq!(p.get("maxAttendees").map(String::as_str), Some("1"));
+    assert_eq!(p.get("showDeleted").map(String::as_str), Some("true"));
+    assert_eq!(
+        p.get("timeZone").map(String::as_str),
+        Some("Europe/Amsterdam")
+    );
+    assert_eq!(p.get("maxResults").map(String::as_str), Some("50"));
+    assert_eq!(p.get("pageToken").map(String::as_str), Some("next"));
+}
+
+#[tokio::test]
+async fn get_instances_collects_all_pages() {
+    let mut server = mockito::Server::new_async().await;
+    let path = "/calendar/v3/calendars/primary/events/recurring_1/instances";
+    let first = server
+        .mock("GET", path)
+        .match_query(mockito::Matcher::Missing)
+        .with_status(200)
+        .with_body(
+            r#"{"items": [{"id": "recurring_1_20250101", "recurringEventId": "recurring_1"}], "nextPageToken": "page2"}"#,
+        )
+        .create_async()
+        .await;
+    let second = server
+        .mock("GET", path)
+        .match_query(mockito::Matcher::UrlEncoded(
+            "pageToken".into(),
+            "page2".into(),
+        ))
+        .with_status(200)
+        .with_body(
+            r#"{"items": [{"id": "recurring_1_20250108", "recurringEventId": "recurring_1"}]}"#,
+        )
+        .create_async()
+        .await;
+
+    let mut gc = dummy_google_client_valid();
+    gc.set_endpoints(ApiEndpoints::with_base_url(&server.url()));
+
+    let instances = CalendarEventsClient::new(&gc)
+        .get_instances("primary", "recurring_1")
+        .collect_all()
+        .await
+        .unwrap();
+
+    let ids: Vec<_> = instances.iter().map(|e| e.id.as_str()).collect();
+    assert_eq!(ids, vec!["recurring_1_20250101", "recurring_1_20250108"]);
+    assert!(instances
+        .iter()
+        .all(|e| e.recurring_event_id == "recurring_1"));
+    first.assert_async().await;
+    second.assert_async().await;
+}
+
+#[tokio::test]
+async fn update_event_puts_full_event() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("PUT", "/calendar/v3/calendars/primary/events/evt_42")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "sendUpdates".into(),
+            "none".into(),
+        ))
+        .match_body(mockito::Matcher::Json(serde_json::json!({
+            "id": "evt_42",
+            "summary": "Replaced",
+            "location": "Room 1",
+            "start": {"date": "2026-02-01"},
+            "end": {"date": "2026-02-02"}
+        })))
+        .with_status(200)
+        .with_body(r#"{"id": "evt_42", "summary": "Replaced"}"#)
+        .create_async()
+        .await;
+
+    let mut gc = dummy_google_client_valid();
+    gc.set_endpoints(ApiEndpoints::with_base_url(&server.url()));
+
+    let event: Event = serde_json::from_str(
+        r#"{"id": "evt_42", "summary": "Old", "start": {"date": "2026-02-01"}, "end": {"date": "2026-02-02"}}"#,
+    )
+    .unwrap();
+    let updated = CalendarEventsClient::new(&gc)
+        .update_event("primary", "evt_42", event)
+        .set_summary("Replaced")
+        .set_location("Room 1")
+        .set_send_updates(SendUpdates::None)
+        .request()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(updated.summary, "Replaced");
+    mock.assert_async().await;
+}
+
+#[test]
+fn move_and_quick_add_set_url_and_params() {
+    let gc = dummy_google_client_valid();
+
+    let moved = CalendarEventsClient::new(&gc)
+        .move_event("primary", "evt_42", "team@group.calendar.google.com")
+        .set_send_updates(SendUpdates::All);
+    assert_eq!(
+        moved.request.url,
+        "https://www.googleapis.com/calendar/v3/calendars/primary/events/evt_42/move"
+    );
+    assert_eq!(moved.request.method, Method::POST);
+    assert_eq!(
+        moved.request.params.get("destination").map(String::as_str),
+        Some("team@group.calendar.google.com")
+    );
+    assert_eq!(
+        moved.request.params.get("sendUpdates").map(String::as_str),
+        Some("all")
+    );
+    assert!(moved.event.is_none());
+
+    let quick =
+        CalendarEventsClient::new(&gc).quick_add("primary", "Lunch with Jane tomorrow at noon");
+    assert_eq!(
+        quick.request.url,
+        "https://www.googleapis.com/calendar/v3/calendars/primary/events/quickAdd"
+    );
+    assert_eq!(quick.request.method, Method::POST);
+    assert_eq!(
+        quick.request.params.get("text").map(String::as_str),
+        Some("Lunch with Jane tomorrow at noon")
+    );
+}
+
+#[tokio::test]
+async fn import_event_posts_ical_uid() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/calendar/v3/calendars/primary/events/import")
+        .match_body(mockito::Matcher::Json(serde_json::json!({
+            "iCalUID": "uid-123@example.com",
+            "summary": "Migrated",
+            "sequence": 2,
+            "start": {"date": "2026-02-01"},
+            "end": {"date": "2026-02-02"}
+        })))
+        .with_status(200)
+        .with_body(r#"{"id": "imported", "iCalUID": "uid-123@example.com"}"#)
+        .create_async()
+        .await;
+
     let mut gc = dummy_google_client_valid();
-    // Start from any mode; we'll get EventListMode by calling get_events
-    let mut client = CalendarEventsClient::new(&mut gc).get_events("primary");
-    // Force an unsupported method to exercise the error path without any network I/O
-    client.request.method = Method::DELETE;
+    gc.set_endpoints(ApiEndpoints::with_base_url(&server.url()));
+
+    let event = CalendarEventsClient::new(&gc)
+        .import_event(
+            "primary",
+            "uid-123@example.com",
+            sample_dt("2026-02-01"),
+            sample_dt("2026-02-02"),
+        )
+        .set_summary("Migrated")
+        .set_sequence(2)
+        .request()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(event.ical_uid, "uid-123@example.com");
+    mock.assert_async().await;
+}
 
-    let res: Result<Option<EventList>, Error> = client.make_request().await;
-    assert!(res.is_err());
+#[tokio::test]
+async fn insert_event_sends_event_parsed_from_ics() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/calendar/v3/calendars/primary/events")
+        .match_body(mockito::Matcher::Json(serde_json::json!({
+            "iCalUID": "party@example.com",
+            "summary": "Party",
+            "start": {"date": "2026-02-01"},
+            "end": {"date": "2026-02-02"}
+        })))
+        .with_status(200)
+        .with_body(r#"{"id": "created"}"#)
+        .create_async()
+        .await;
+
+    let mut gc = dummy_google_client_valid();
+    gc.set_endpoints(ApiEndpoints::with_base_url(&server.url()));
+
+    let ics = "BEGIN:VCALENDAR\nBEGIN:VEVENT\nUID:party@example.com\n\
+        DTSTART;VALUE=DATE:20260201\nSUMMARY:Party\nEND:VEVENT\nEND:VCALENDAR\n";
+    let event = parse_ics(ics).unwrap().remove(0);
+    CalendarEventsClient::new(&gc)
+        .insert_event("primary", sample_dt("2000-01-01"), sample_dt("2000-01-02"))
+        .set_event(event)
+        .request()
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+}
+
+#[test]
+fn event_date_time_keeps_local_time_across_dst() {
+    let amsterdam = chrono_tz::Europe::Amsterdam;
+    let nine = |day| {
+        NaiveDate::from_ymd_opt(2025, 3, day)
+            .unwrap()
+            .and_hms_opt(9, 0, 0)
+            .unwrap()
+    };
+
+    let winter = EventDateTime::from_local(nine(28), amsterdam).unwrap();
+    let summer = EventDateTime::from_local(nine(31), amsterdam).unwrap();
+    assert_eq!(winter.time_zone.as_deref(), Some("Europe/Amsterdam"));
+    assert_eq!(
+        winter.date_time.unwrap().to_rfc3339(),
+        "2025-03-28T09:00:00+01:00"
+    );
+    assert_eq!(
+        summer.date_time.unwrap().to_rfc3339(),
+        "2025-03-31T09:00:00+02:00"
+    );
+    assert_eq!(summer.zoned_date_time().unwrap().naive_local(), nine(31));
+
+    let skipped = NaiveDate::from_ymd_opt(2025, 3, 30)
+        .unwrap()
+        .and_hms_opt(2, 30, 0)
+        .unwrap();
+    assert!(matches!(
+        EventDateTime::from_local(skipped, amsterdam),
+        Err(GoogleApiError::InvalidRequest(_))
+    ));
+}
+
+#[test]
+fn event_date_time_converts_to_local_time() {
+    let event: Event = serde_json::from_str(
+        r#"{
+            "start": {"dateTime": "2025-06-01T23:30:00-04:00", "timeZone": "America/New_York"},
+            "end": {"date": "2025-06-03"}
+        }"#,
+    )
+    .unwrap();
+    let start = event.start.unwrap();
+    let end = event.end.unwrap();
+    let tokyo = chrono_tz::Asia::Tokyo;
+
+    assert!(!start.is_all_day());
+    assert_eq!(start.tz(), Some(chrono_tz::America::New_York));
+    assert_eq!(
+        start.zoned_date_time().unwrap().to_rfc3339(),
+        "2025-06-01T23:30:00-04:00"
+    );
+    assert_eq!(
+        start.local_date(&tokyo),
+        NaiveDate::from_ymd_opt(2025, 6, 2)
+    );
+
+    assert!(end.is_all_day());
+    assert_eq!(end.naive_date(), NaiveDate::from_ymd_opt(2025, 6, 3));
+    assert_eq!(
+        end.to_local(&tokyo).unwrap().to_rfc3339(),
+        "2025-06-03T00:00:00+09:00"
+    );
+    assert_eq!(end.local_date(&Utc), NaiveDate::from_ymd_opt(2025, 6, 3));
+}
+
+#[test]
+fn event_date_time_keeps_offset_through_serde() {
+    let json = r#"{"dateTime":"2025-06-01T09:00:00+02:00","timeZone":"Europe/Amsterdam"}"#;
+    let date: EventDateTime = serde_json::from_str(json).unwrap();
+
+    assert_eq!(date.date_time.unwrap().offset().local_minus_utc(), 2 * 3600);
+    assert_eq!(serde_json::to_string(&date).unwrap(), json);
+    let utc = EventDateTime::utc(Utc.with_ymd_and_hms(2025, 6, 1, 7, 0, 0).unwrap());
+    assert_eq!(
+        serde_json::to_string(&utc).unwrap(),
+        r#"{"dateTime":"2025-06-01T07:00:00Z"}"#
+    );
+}
+
+#[test]
+fn invalid_event_dates_are_rejected_when_deserializing() {
+    for json in [
+        r#"{"date": "2025-02-30"}"#,
+        r#"{"date": "2025-13-01"}"#,
+        r#"{"date": "01-01-2025"}"#,
+        r#"{"dateTime": "2025-06-01 09:00"}"#,
+    ] {
+        assert!(
+            serde_json::from_str::<EventDateTime>(json).is_err(),
+            "{json} was accepted"
+        );
+    }
+    let date: EventDateTime = serde_json::from_str(r#"{"date": "2024-02-29"}"#).unwrap();
+    assert_eq!(date.naive_date(), NaiveDate::from_ymd_opt(2024, 2, 29));
+}
+
+#[tokio::test]
+async fn invalid_event_date_time_is_rejected_before_sending() {
+    let both = EventDateTime {
+        date_time: Some(Utc::now().fixed_offset()),
+        ..sample_dt("2025-01-01")
+    };
+    let unknown_zone = EventDateTime {
+        time_zone: Some("Mars/Olympus".to_string()),
+        ..EventDateTime::utc(Utc::now())
+    };
+    for (start, message) in [
+        (both, "both date and dateTime"),
+        (EventDateTime::default(), "one of date or dateTime"),
+        (unknown_zone, "unknown time zone Mars/Olympus"),
+    ] {
+        assert!(matches!(
+            start.validate(),
+            Err(GoogleApiError::InvalidRequest(ref m)) if m.contains(message)
+        ));
+
+        let gc = dummy_google_client_valid();
+        let result = CalendarEventsClient::new(&gc)
+            .insert_event("primary", start, sample_dt("2025-01-02"))
+            .request()
+            .await;
+        assert!(matches!(result, Err(GoogleApiError::InvalidRequest(_))));
+    }
+    assert!(
+        EventDateTime::all_day(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap())
+            .validate()
+            .is_ok()
+    );
+}
+
+#[tokio::test]
+async fn insert_event_with_new_meet_requests_conference() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/calendar/v3/calendars/primary/events")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "conferenceDataVersion".into(),
+            "1".into(),
+        ))
+        .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+            "conferenceData": {
+                "createRequest": {
+                    "requestId": "standup-1",
+                    "conferenceSolutionKey": {"type": "hangoutsMeet"}
+                }
+            }
+        })))
+        .with_status(200)
+        .with_body(
+            r#"{
+                "id": "created",
+                "conferenceData": {
+                    "createRequest": {"requestId": "standup-1", "status": {"statusCode": "success"}},
+                    "entryPoints": [
+                        {"entryPointType": "video", "uri": "https://meet.google.com/abc-defg-hij"},
+                        {"entryPointType": "phone", "uri": "tel:+31-20-000-0000", "pin": "123456"},
+                        {"entryPointType": "more", "uri": "https://tel.meet/abc-defg-hij"}
+                    ],
+                    "conferenceId": "abc-defg-hij"
+                }
+            }"#,
+        )
+        .create_async()
+        .await;
+
+    let mut gc = dummy_google_client_valid();
+    gc.set_endpoints(ApiEndpoints::with_base_url(&server.url()));
+
+    let event = CalendarEventsClient::new(&gc)
+        .insert_event("primary", sample_dt("2026-02-01"), sample_dt("2026-02-02"))
+        .with_new_meet("standup-1")
+        .request()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert!(!event.conference_pending());
+    assert_eq!(
+        event.video_entry_point().unwrap().uri,
+        "https://meet.google.com/abc-defg-hij"
+    );
+    let phones = event.phone_entry_points();
+    assert_eq!(phones.len(), 1);
+    assert_eq!(phones[0].pin, "123456");
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn patch_event_copies_conference_of_other_event() {
+    let source: Event = serde_json::from_str(
+        r#"{
+            "id": "source",
+            "conferenceData": {
+                "createRequest": {"requestId": "r", "status": {"statusCode": "pending"}},
+                "conferenceSolution": {"key": {"type": "hangoutsMeet"}, "name": "Google Meet"},
+                "conferenceId": "abc-defg-hij"
+            }
+        }"#,
+    )
+    .unwrap();
+    assert!(source.conference_pending());
+    assert!(source.video_entry_point().is_none());
+
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("PATCH", "/calendar/v3/calendars/primary/events/target")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "conferenceDataVersion".into(),
+            "1".into(),
+        ))
+        .match_body(mockito::Matcher::Json(serde_json::json!({
+            "conferenceData": {
+                "conferenceSolution": {"key": {"type": "hangoutsMeet"}, "name": "Google Meet"},
+                "conferenceId": "abc-defg-hij"
+            }
+        })))
+        .with_status(200)
+        .with_body(r#"{"id": "target"}"#)
+        .create_async()
+        .await;
+
+    let mut gc = dummy_google_client_valid();
+    gc.set_endpoints(ApiEndpoints::with_base_url(&server.url()));
+
+    CalendarEventsClient::new(&gc)
+        .patch_event("primary", "target")
+        .set_conference_data(source.conference_data.unwrap().into())
+        .request()
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn list_filters_repeat_event_types_and_extended_properties() {
+    let mut server = mockito::Server::new_async().await;
+    let updated_min = Utc::now() - Duration::days(7);
+    let mock = server
+        .mock("GET", "/calendar/v3/calendars/primary/events")
+        .match_query(mockito::Matcher::AllOf(vec![
+            // UrlEncoded keeps one value per key, match repeated keys in the raw query
+            mockito::Matcher::Regex("eventTypes=default&eventTypes=focusTime".into()),
+            mockito::Matcher::Regex(
+                "privateExtendedProperty=app%3Dcrm&privateExtendedProperty=deal%3D42".into(),
+            ),
+            mockito::Matcher::UrlEncoded("sharedExtendedProperty".into(), "team=sales".into()),
+            mockito::Matcher::UrlEncoded("iCalUID".into(), "uid@example.com".into()),
+            mockito::Matcher::UrlEncoded("showDeleted".into(), "true".into()),
+            mockito::Matcher::UrlEncoded("updatedMin".into(), updated_min.to_rfc3339()),
+            mockito::Matcher::UrlEncoded("timeZone".into(), "Europe/Amsterdam".into()),
+            mockito::Matcher::UrlEncoded("alwaysIncludeEmail".into(), "true".into()),
+        ]))
+        .with_status(200)
+        .with_body(r#"{"items": [{"id": "a"}]}"#)
+        .create_async()
+        .await;
+
+    let mut gc = dummy_google_client_valid();
+    gc.set_endpoints(ApiEndpoints::with_base_url(&server.url()));
+
+    let events = CalendarEventsClient::new(&gc)
+        .get_events("primary")
+        .event_type(EventType::Default)
+        .event_type(EventType::FocusTime)
+        .private_extended_property("app", "crm")
+        .private_extended_property("deal", "42")
+        .shared_extended_property("team", "sales")
+        .i_cal_uid("uid@example.com")
+        .show_deleted(true)
+        .updated_min(updated_min)
+        .time_zone("Europe/Amsterdam")
+        .always_include_email(true)
+        .collect_all()
+        .await
+        .unwrap();
+
+    assert_eq!(events.len(), 1);
+    mock.assert_async().await;
+}
+
+const INVITATION: &str = r#"{
+    "id": "party",
+    "etag": "\"3181161784712000\"",
+    "attendees": [
+        {"email": "host@example.com", "organizer": true, "responseStatus": "accepted"},
+        {"email": "me@example.com", "self": true, "responseStatus": "needsAction"},
+        {"email": "friend@example.com", "optional": true, "responseStatus": "tentative"},
+        {"email": "late@example.com", "responseStatus": "declined"},
+        {"email": "room@resource.calendar.google.com", "resource": true, "responseStatus": "accepted"}
+    ]
+}"#;
+
+#[tokio::test]
+async fn respond_to_event_patches_only_own_response() {
+    let mut server = mockito::Server::new_async().await;
+    let get = server
+        .mock("GET", "/calendar/v3/calendars/primary/events/party")
+        .with_status(200)
+        .with_body(INVITATION)
+        .create_async()
+        .await;
+    let mut expected: Event = serde_json::from_str(INVITATION).unwrap();
+    expected.attendees[1].response_status = Some(ResponseStatus::Declined);
+    expected.attendees[1].comment = "On holiday".into();
+    let patch = server
+        .mock("PATCH", "/calendar/v3/calendars/primary/events/party")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "sendUpdates".into(),
+            "all".into(),
+        ))
+        .match_header("if-match", "\"3181161784712000\"")
+        .match_body(mockito::Matcher::Json(serde_json::json!({
+            "attendees": expected.attendees
+        })))
+        .with_status(200)
+        .with_body(serde_json::to_string(&expected).unwrap())
+        .create_async()
+        .await;
+
+    let mut gc = dummy_google_client_valid();
+    gc.set_endpoints(ApiEndpoints::with_base_url(&server.url()));
+
+    let event = CalendarEventsClient::new(&gc)
+        .respond_to_event("primary", "party", ResponseStatus::Declined)
+        .comment("On holiday")
+        .set_send_updates(SendUpdates::All)
+        .request()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(
+        event.self_attendee().unwrap().response_status,
+        Some(ResponseStatus::Declined)
+    );
+    get.assert_async().await;
+    patch.assert_async().await;
+}
+
+#[tokio::test]
+async fn respond_to_event_requires_invitation() {
+    let mut server = mockito::Server::new_async().await;
+    server
+        .mock("GET", "/calendar/v3/calendars/primary/events/other")
+        .with_status(200)
+        .with_body(r#"{"id": "other", "attendees": [{"email": "a@example.com"}]}"#)
+        .create_async()
+        .await;
+    let patch = server
+        .mock("PATCH", "/calendar/v3/calendars/primary/events/other")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let mut gc = dummy_google_client_valid();
+    gc.set_endpoints(ApiEndpoints::with_base_url(&server.url()));
+
+    let result = CalendarEventsClient::new(&gc)
+        .respond_to_event("primary", "other", ResponseStatus::Accepted)
+        .request()
+        .await;
+
+    match result {
+        Err(GoogleApiError::InvalidRequest(message)) => {
+            assert!(message.contains("not an attendee"), "{message}")
+        }
+        other => panic!("expected InvalidRequest, got {other:?}"),
+    }
+    patch.assert_async().await;
+}
+
+#[tokio::test]
+async fn respond_to_event_fails_when_event_changed_since_fetch() {
+    let mut server = mockito::Server::new_async().await;
+    let _get = server
+        .mock("GET", "/calendar/v3/calendars/primary/events/party")
+        .with_status(200)
+        .with_body(INVITATION)
+        .create_async()
+        .await;
+    let patch = server
+        .mock("PATCH", "/calendar/v3/calendars/primary/events/party")
+        .match_header("if-match", "\"3181161784712000\"")
+        .with_status(412)
+        .with_body(r#"{"error": {"code": 412, "message": "Precondition Failed", "errors": [{"domain": "global", "reason": "conditionNotMet"}]}}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let mut gc = dummy_google_client_valid();
+    gc.set_endpoints(ApiEndpoints::with_base_url(&server.url()));
+
+    let result = CalendarEventsClient::new(&gc)
+        .respond_to_event("primary", "party", ResponseStatus::Accepted)
+        .request()
+        .await;
+
+    match result {
+        Err(GoogleApiError::PreconditionFailed(details)) => {
+            assert_eq!(details.message, "Precondition Failed")
+        }
+        other => panic!("expected PreconditionFailed, got {other:?}"),
+    }
+    patch.assert_async().await;
+}
+
+#[test]
+fn event_summarizes_guest_responses() {
+    let event: Event = serde_json::from_str(INVITATION).unwrap();
+
+    let counts = event.response_counts();
+    assert_eq!(
+        (
+            counts.accepted,
+            counts.declined,
+            counts.tentative,
+            counts.needs_action
+        ),
+        (1, 1, 1, 1)
+    );
+    let required: Vec<_> = event
+        .required_attendees()
+        .iter()
+        .map(|a| a.email.as_str())
+        .collect();
+    assert_eq!(
+        required,
+        ["host@example.com", "me@example.com", "late@example.com"]
+    );
+    assert_eq!(event.optional_attendees()[0].email, "friend@example.com");
+    assert_eq!(event.self_attendee().unwrap().email, "me@example.com");
+}
+
+#[test]
+fn event_enums_keep_unknown_values() {
+    let json = serde_json::json!({
+        "id": "e",
+        "status": "cancelled",
+        "transparency": "transparent",
+        "visibility": "teamOnly",
+        "eventType": "workingLocation",
+        "attendees": [
+            {"email": "a@example.com", "responseStatus": "accepted"},
+            {"email": "b@example.com", "responseStatus": "maybeLater"}
+        ]
+    });
+    let event: Event = serde_json::from_value(json.clone()).unwrap();
+
+    assert_eq!(event.status, Some(EventStatus::Cancelled));
+    assert_eq!(event.transparency, Some(Transparency::Transparent));
+    assert_eq!(
+        event.visibility,
+        Some(Visibility::Unknown("teamOnly".into()))
+    );
+    assert_eq!(event.event_type, Some(EventType::WorkingLocation));
+    assert_eq!(
+        event.attendees[1].response_status,
+        Some(ResponseStatus::Unknown("maybeLater".into()))
+    );
+    assert_eq!(
+        event.attendees[1]
+            .response_status
+            .as_ref()
+            .unwrap()
+            .as_str(),
+        "maybeLater"
+    );
+    assert_eq!(serde_json::to_value(&event).unwrap(), json);
 }
@@ -1,17 +1,33 @@
 use crate::{
     auth::client::GoogleClient,
-    calendar::events::types::{CreateEventRequest, EventDateTime},
-    utils::request::{PaginationRequestTrait, Request, TimeRequestTrait},
+    calendar::{
+        channels::types::Channel,
+        events::{
+            recurrence::Recurrence,
+            types::{CreateEventRequest, EventDateTime},
+        },
+    },
+    error::GoogleApiError,
+    utils::{
+        pagination::{paginate, Page},
+        request::{PaginationRequestTrait, Request, TimeRequestTrait},
+    },
 };
 
-use anyhow::{anyhow, Error};
 use chrono::DateTime;
-use reqwest::Method;
+use futures::{Stream, TryStreamExt};
+use reqwest::{
+    header::{HeaderValue, IF_MATCH},
+    Method,
+};
 use serde::{de::DeserializeOwned, Serialize};
 
+pub use super::types::{EventType, ResponseStatus};
+
 use super::types::{
-    BirthdayProperties, Event, EventAttendee, EventList, EventReminders, EventSource,
-    ExtendedProperties, OutOfOfficeProperties, PatchEventRequest, WorkingLocationProperties,
+    BirthdayProperties, ConferenceData, Event, EventAttendee, EventList, EventReminders,
+    EventSource, EventStatus, ExtendedProperties, OutOfOfficeProperties, PatchEventRequest,
+    Transparency, Visibility, WorkingLocationProperties,
 };
 
 /// Indicates that the request builder is not yet initialized with a specific mode.
@@ -22,6 +38,10 @@ pub struct EventGetMode;
 /// Indicates that the request builder is initialized for retrieving a list of events.
 /// This struct determines which filters can be applied to the request.
 pub struct EventListMode;
+/// Indicates that the request builder is initialized for retrieving the instances of a
+/// recurring event.
+/// This struct determines which filters can be applied to the request.
+pub struct EventInstancesMode;
 /// Indicates that the request builder is initialized for inserting events.
 /// This struct determines which filters can be applied to the request.
 pub struct EventDeleteMode;
@@ -30,12 +50,50 @@ pub struct EventDeleteMode;
 pub struct EventInsertMode;
 
 pub struct EventPatchMode;
+/// Indicates that the request builder is initialized for replacing an event.
+/// This struct determines which filters can be applied to the request.
+pub struct EventUpdateMode;
+/// Indicates that the request builder is initialized for moving an event to another calendar.
+pub struct EventMoveMode;
+/// Indicates that the request builder is initialized for creating an event from a text.
+pub struct EventQuickAddMode;
+/// Indicates that the request builder is initialized for importing a private copy of an event.
+/// This struct determines which filters can be applied to the request.
+pub struct EventImportMode;
+/// Indicates that the request builder is initialized for watching events for changes.
+/// This struct determines which filters can be applied to the request.
+pub struct EventWatchMode;
+/// Indicates that the request builder is initialized for responding to an invitation.
+pub struct EventRespondMode;
 
 #[derive(Serialize)]
 #[serde(untagged)]
 pub enum EventRequest {
     Create(CreateEventRequest),
     Patch(PatchEventRequest),
+    Update(Box<Event>),
+    Watch(Channel),
+}
+
+impl EventRequest {
+    /// Checks the start and end of the event before it is sent.
+    fn validate(&self) -> Result<(), GoogleApiError> {
+        let (start, end, original_start) = match self {
+            EventRequest::Create(event) => (
+                Some(&event.start),
+                Some(&event.end),
+                event.original_start_time.as_ref(),
+            ),
+            EventRequest::Patch(event) => (event.start.as_ref(), event.end.as_ref(), None),
+            EventRequest::Update(event) => (event.start.as_ref(), event.end.as_ref(), None),
+            EventRequest::Watch(_) => (None, None, None),
+        };
+        start
+            .into_iter()
+            .chain(end)
+            .chain(original_start)
+            .try_for_each(EventDateTime::validate)
+    }
 }
 
 /// The generic type parameter `T` determines the mode of operation for this client,
@@ -50,7 +108,7 @@ pub struct CalendarEventsClient<'a, T = Uninitialized> {
 /// This provides the entry points to initialize the client for specific operations.
 impl<'a> CalendarEventsClient<'a, Uninitialized> {
     /// Creates a new calendar events client using the provided Google client for authentication.
-    pub fn new(client: &'a mut GoogleClient) -> Self {
+    pub fn new(client: &'a GoogleClient) -> Self {
         Self {
             request: Request::new(client),
             event: None,
@@ -66,10 +124,9 @@ impl<'a> CalendarEventsClient<'a, Uninitialized> {
     /// ``` rust
     /// #[axum::debug_handler]
     /// pub async fn get_birtday_events(State(state): State<AppState>) -> Json<EventResponse> {
-    ///     //GoogleClient is stored in the AppState wrapped in a Arc<Mutex>
-    ///     let google_client_guard = state.google_client.lock().await;
-    ///     let client = google_client_guard.as_ref().unwrap();
-    ///     let events = EventRequestBuilder::new(client)
+    ///     //GoogleClient is stored in the AppState, cloning it is cheap
+    ///     let client = state.google_client.read().await.clone().unwrap();
+    ///     let events = EventRequestBuilder::new(&client)
     ///         .get_events("primary")
     ///         .single_events(true)
     ///         .event_type(EventType::Birthday)
@@ -93,13 +150,85 @@ impl<'a> CalendarEventsClient<'a, Uninitialized> {
             event: None,
             _mode: std::marker::PhantomData,
         };
-        builder.request.url = "https://www.googleapis.com/calendar/v3/calendars/".to_string()
-            + calendar_id
-            + "/events";
+        builder.request.url = format!(
+            "{}/calendars/{calendar_id}/events",
+            builder.request.client.endpoints.calendar
+        );
         builder.request.method = reqwest::Method::GET;
         builder
     }
 
+    /// Get a single event from the specified calendar.
+    ///
+    /// # Arguments
+    ///
+    /// * `calendar_id` - The ID of the calendar where the event is located
+    /// * `event_id` - The ID of the event, this can also be the ID of a single instance of a
+    ///   recurring event
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let event = CalendarEventsClient::new(&client)
+    ///     .get_event("primary", "event_id")
+    ///     .max_attendees(10)
+    ///     .request()
+    ///     .await?;
+    /// ```
+    pub fn get_event(
+        self,
+        calendar_id: &str,
+        event_id: &str,
+    ) -> CalendarEventsClient<'a, EventGetMode> {
+        let mut builder = CalendarEventsClient {
+            request: self.request,
+            event: None,
+            _mode: std::marker::PhantomData,
+        };
+        builder.request.url = format!(
+            "{}/calendars/{calendar_id}/events/{event_id}",
+            builder.request.client.endpoints.calendar
+        );
+        builder.request.method = Method::GET;
+        builder
+    }
+
+    /// Get the instances of a recurring event.
+    ///
+    /// # Arguments
+    ///
+    /// * `calendar_id` - The ID of the calendar where the event is located
+    /// * `event_id` - The ID of the recurring event
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // All occurrences of a weekly meeting in the next month
+    /// let instances = CalendarEventsClient::new(&client)
+    ///     .get_instances("primary", "recurring_event_id")
+    ///     .time_min(chrono::Utc::now())
+    ///     .time_max(chrono::Utc::now() + chrono::Duration::days(30))
+    ///     .collect_all()
+    ///     .await?;
+    /// ```
+    pub fn get_instances(
+        self,
+        calendar_id: &str,
+        event_id: &str,
+    ) -> CalendarEventsClient<'a, EventInstancesMode> {
+        let mut builder = CalendarEventsClient {
+            request: self.request,
+            event: None,
+            _mode: std::marker::PhantomData,
+        };
+        builder.request.url = format!(
+            "{}/calendars/{calendar_id}/events/{event_id}/instances",
+            builder.request.client.endpoints.calendar
+        );
+        builder.request.method = Method::GET;
+        builder
+    }
+
     /// Creates a new event in the specified calendar.
     ///
     /// # Arguments
@@ -119,19 +248,11 @@ impl<'a> CalendarEventsClient<'a, Uninitialized> {
     ///  
     /// ```rust
     /// pub async fn insert_new_event(State(state): State<AppState>) {
-    ///     let mut google_client_guard = state.google_client.lock().await;
-    ///     let client = google_client_guard.as_mut().unwrap();
-    ///     let start: EventDateTime = EventDateTime {
-    ///         date: Some("2025-07-28".to_string()),
-    ///         date_time: None,
-    ///         time_zone: None,
-    ///     };
-    ///     let end: EventDateTime = EventDateTime {
-    ///         date: Some("2025-07-28".to_string()),
-    ///         date_time: None,
-    ///         time_zone: None,
-    ///     };
-    ///     CalendarEventsClient::new(client)
+    ///     let client = state.google_client.read().await.clone().unwrap();
+    ///     let day = NaiveDate::from_ymd_opt(2025, 7, 28).unwrap();
+    ///     let start = EventDateTime::all_day(day);
+    ///     let end = EventDateTime::all_day(day.succ_opt().unwrap());
+    ///     CalendarEventsClient::new(&client)
     ///         .insert_event("calendar_id", start, end)
     ///         .set_summary("test_insert")
     ///         .set_description("new event")
@@ -149,8 +270,10 @@ impl<'a> CalendarEventsClient<'a, Uninitialized> {
             event: Some(EventRequest::Create(CreateEventRequest::new(start, end))),
             _mode: std::marker::PhantomData,
         };
-        builder.request.url =
-            format!("https://www.googleapis.com/calendar/v3/calendars/{calendar_id}/events",);
+        builder.request.url = format!(
+            "{}/calendars/{calendar_id}/events",
+            builder.request.client.endpoints.calendar
+        );
         builder.request.method = Method::POST;
         builder
     }
@@ -173,9 +296,8 @@ impl<'a> CalendarEventsClient<'a, Uninitialized> {
     ///
     /// ``` rust
     ///     async fn update_event(State(state): State<AppState>) {
-    ///     let mut google_client_guard = state.google_client.lock().await;
-    ///     let client = google_client_guard.as_mut().unwrap();
-    ///     CalendarEventsClient::new(client)
+    ///     let client = state.google_client.read().await.clone().unwrap();
+    ///     CalendarEventsClient::new(&client)
     ///      // Main callendar can be targetd by the string "primary" as id
     ///      .patch_event("calendar_id", "event_id")
     ///      .set_summary("New summary/title")
@@ -196,12 +318,192 @@ impl<'a> CalendarEventsClient<'a, Uninitialized> {
             _mode: std::marker::PhantomData,
         };
         builder.request.url = format!(
-            "https://www.googleapis.com/calendar/v3/calendars/{calendar_id}/events/{event_id}"
+            "{}/calendars/{calendar_id}/events/{event_id}",
+            builder.request.client.endpoints.calendar
         );
         builder.request.method = Method::PATCH;
         builder
     }
 
+    /// Replaces an event in the specified calendar.
+    ///
+    /// Unlike `patch_event` this is a full replacement, fields missing from `event` are
+    /// cleared. Typically used after fetching the event with `get_event` and changing it.
+    ///
+    /// # Arguments
+    ///
+    /// * `calendar_id` - The ID of the calendar where the event is located
+    /// * `event_id` - The ID of the event to replace
+    /// * `event` - The new version of the event, `start` and `end` are required
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let mut event = CalendarEventsClient::new(&client)
+    ///     .get_event("primary", "event_id")
+    ///     .request()
+    ///     .await?
+    ///     .unwrap();
+    /// event.summary = "New summary".to_string();
+    /// CalendarEventsClient::new(&client)
+    ///     .update_event("primary", "event_id", event)
+    ///     .set_send_updates(SendUpdates::All)
+    ///     .request()
+    ///     .await?;
+    /// ```
+    pub fn update_event(
+        self,
+        calendar_id: &str,
+        event_id: &str,
+        event: Event,
+    ) -> CalendarEventsClient<'a, EventUpdateMode> {
+        let mut builder = CalendarEventsClient {
+            request: self.request,
+            event: Some(EventRequest::Update(Box::new(event))),
+            _mode: std::marker::PhantomData,
+        };
+        builder.request.url = format!(
+            "{}/calendars/{calendar_id}/events/{event_id}",
+            builder.request.client.endpoints.calendar
+        );
+        builder.request.method = Method::PUT;
+        builder
+    }
+
+    /// Moves an event to another calendar, changing its organizer.
+    /// Only default events can be moved.
+    ///
+    /// # Arguments
+    ///
+    /// * `calendar_id` - The ID of the calendar where the event is currently located
+    /// * `event_id` - The ID of the event to move
+    /// * `destination` - The ID of the calendar to move the event to
+    pub fn move_event(
+        self,
+        calendar_id: &str,
+        event_id: &str,
+        destination: &str,
+    ) -> CalendarEventsClient<'a, EventMoveMode> {
+        let mut builder = CalendarEventsClient {
+            request: self.request,
+            event: None,
+            _mode: std::marker::PhantomData,
+        };
+        builder.request.url = format!(
+            "{}/calendars/{calendar_id}/events/{event_id}/move",
+            builder.request.client.endpoints.calendar
+        );
+        builder.request.method = Method::POST;
+        builder
+            .request
+            .params
+            .insert("destination".to_string(), destination.to_string());
+        builder
+    }
+
+    /// Creates an event from a natural language text, like "Lunch with Jane tomorrow at noon".
+    ///
+    /// # Arguments
+    ///
+    /// * `calendar_id` - The ID of the calendar where the event will be created
+    /// * `text` - The text describing the event
+    pub fn quick_add(
+        self,
+        calendar_id: &str,
+        text: &str,
+    ) -> CalendarEventsClient<'a, EventQuickAddMode> {
+        let mut builder = CalendarEventsClient {
+            request: self.request,
+            event: None,
+            _mode: std::marker::PhantomData,
+        };
+        builder.request.url = format!(
+            "{}/calendars/{calendar_id}/events/quickAdd",
+            builder.request.client.endpoints.calendar
+        );
+        builder.request.method = Method::POST;
+        builder
+            .request
+            .params
+            .insert("text".to_string(), text.to_string());
+        builder
+    }
+
+    /// Imports a private copy of an existing event into the specified calendar.
+    ///
+    /// Importing is meant for migrations: no invitations are sent and an event with the same
+    /// `iCalUID` in the calendar is updated instead of duplicated.
+    ///
+    /// # Arguments
+    ///
+    /// * `calendar_id` - The ID of the calendar to import the event into
+    /// * `ical_uid` - The iCalendar UID of the event
+    /// * `start` - The start time information for the event
+    /// * `end` - The end time information for the event
+    pub fn import_event(
+        self,
+        calendar_id: &str,
+        ical_uid: &str,
+        start: EventDateTime,
+        end: EventDateTime,
+    ) -> CalendarEventsClient<'a, EventImportMode> {
+        let mut event = CreateEventRequest::new(start, end);
+        event.ical_uid = Some(ical_uid.to_string());
+        let mut builder = CalendarEventsClient {
+            request: self.request,
+            event: Some(EventRequest::Create(event)),
+            _mode: std::marker::PhantomData,
+        };
+        builder.request.url = format!(
+            "{}/calendars/{calendar_id}/events/import",
+            builder.request.client.endpoints.calendar
+        );
+        builder.request.method = Method::POST;
+        builder
+    }
+
+    /// Watch the events of a calendar for changes.
+    ///
+    /// Google sends a notification to the address of the channel whenever an event changes,
+    /// parse it with `CalendarNotification::from_headers`.
+    ///
+    /// # Arguments
+    ///
+    /// * `calendar_id` - The ID of the calendar to watch
+    /// * `channel` - The channel to deliver notifications to, see `Channel::web_hook`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let channel = CalendarEventsClient::new(&client)
+    ///     .watch_events(
+    ///         "primary",
+    ///         Channel::web_hook(&uuid, "https://example.com/webhooks/calendar"),
+    ///     )
+    ///     .set_token(&channel_token)
+    ///     .request()
+    ///     .await?
+    ///     .unwrap();
+    /// // Store channel.id and channel.resource_id to stop the channel later
+    /// ```
+    pub fn watch_events(
+        self,
+        calendar_id: &str,
+        channel: Channel,
+    ) -> CalendarEventsClient<'a, EventWatchMode> {
+        let mut builder = CalendarEventsClient {
+            request: self.request,
+            event: Some(EventRequest::Watch(channel)),
+            _mode: std::marker::PhantomData,
+        };
+        builder.request.url = format!(
+            "{}/calendars/{calendar_id}/events/watch",
+            builder.request.client.endpoints.calendar
+        );
+        builder.request.method = Method::POST;
+        builder
+    }
+
     pub fn delete_event(
         self,
         calendar_id: &str,
@@ -213,11 +515,69 @@ impl<'a> CalendarEventsClient<'a, Uninitialized> {
             _mode: std::marker::PhantomData,
         };
         builder.request.url = format!(
-            "https://www.googleapis.com/calendar/v3/calendars/{calendar_id}/events/{event_id}"
+            "{}/calendars/{calendar_id}/events/{event_id}",
+            builder.request.client.endpoints.calendar
         );
         builder.request.method = Method::DELETE;
         builder
     }
+
+    /// Responds to an invitation on behalf of the authenticated user.
+    ///
+    /// Only the response of the user changes: the event is fetched, the attendee marked as
+    /// `self` gets the new response and the attendees are patched. Fails with
+    /// `GoogleApiError::InvalidRequest` when the user is not an attendee.
+    ///
+    /// # Arguments
+    ///
+    /// * `calendar_id` - The ID of the calendar of the user that contains the event
+    /// * `event_id` - The ID of the event
+    /// * `response` - The response of the user
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// CalendarEventsClient::new(&client)
+    ///     .respond_to_event("primary", "event_id", ResponseStatus::Declined)
+    ///     .comment("On holiday, sorry!")
+    ///     .set_send_updates(SendUpdates::All)
+    ///     .request()
+    ///     .await?;
+    /// ```
+    pub fn respond_to_event(
+        self,
+        calendar_id: &str,
+        event_id: &str,
+        response: ResponseStatus,
+    ) -> CalendarEventsClient<'a, EventRespondMode> {
+        // Until the event is fetched the attendees only hold the response of the user
+        let response = EventAttendee {
+            id: String::new(),
+            email: String::new(),
+            display_name: String::new(),
+            organizer: None,
+            self_: Some(true),
+            resource: None,
+            optional: None,
+            response_status: Some(response),
+            comment: String::new(),
+            additional_guests: 0,
+        };
+        let mut builder = CalendarEventsClient {
+            request: self.request,
+            event: Some(EventRequest::Patch(PatchEventRequest {
+                attendees: vec![response],
+                ..Default::default()
+            })),
+            _mode: std::marker::PhantomData,
+        };
+        builder.request.url = format!(
+            "{}/calendars/{calendar_id}/events/{event_id}",
+            builder.request.client.endpoints.calendar
+        );
+        builder.request.method = Method::PATCH;
+        builder
+    }
 }
 
 /// Event ordering options for Google Calendar events.
@@ -235,28 +595,21 @@ impl EventOrderBy {
     }
 }
 
-/** Event types for Google Calendar events.
-* These are used to filter events when making requests.
-* See [Google Calendar API
-* documentation](https://developers.google.com/calendar/api/v3/reference/events
-*/
-pub enum EventType {
-    Birthday,
-    Default,
-    FocusTime,
-    FromGmail,
-    OutOfOffice,
-    WorkingLocation,
+/// Who receives notifications about a change of an event.
+pub enum SendUpdates {
+    /// All guests
+    All,
+    /// Only guests that don't use Google Calendar
+    ExternalOnly,
+    /// No guests, also not for a cancelled event
+    None,
 }
-impl EventType {
+impl SendUpdates {
     pub fn as_str(&self) -> &str {
         match self {
-            EventType::Birthday => "birthday",
-            EventType::Default => "default",
-            EventType::FocusTime => "focusTime",
-            EventType::FromGmail => "fromGmail",
-            EventType::OutOfOffice => "outOfOffice",
-            EventType::WorkingLocation => "workingLocation",
+            SendUpdates::All => "all",
+            SendUpdates::ExternalOnly => "externalOnly",
+            SendUpdates::None => "none",
         }
     }
 }
@@ -299,11 +652,11 @@ impl<'a> TimeRequestTrait for CalendarEventsClient<'a, EventListMode> {
 }
 
 impl<'a> CalendarEventsClient<'a, EventListMode> {
-    /// Set the type of events to filter by.
+    /// Set the type of events to filter by, call it again to include more types.
     pub fn event_type(mut self, type_: EventType) -> Self {
         self.request
             .params
-            .insert("eventTypes".to_string(), type_.as_str().to_string());
+            .append("eventTypes".to_string(), type_.as_str().to_string());
         self
     }
 
@@ -352,103 +705,279 @@ impl<'a> CalendarEventsClient<'a, EventListMode> {
         self
     }
 
+    /// Only return the event with this iCalendar UID, including all its instances.
+    pub fn i_cal_uid(mut self, uid: &str) -> Self {
+        self.request
+            .params
+            .insert("iCalUID".to_string(), uid.to_string());
+        self
+    }
+
+    /// Only return events with this private extended property, call it again to require more
+    /// properties.
+    pub fn private_extended_property(mut self, name: &str, value: &str) -> Self {
+        self.request.params.append(
+            "privateExtendedProperty".to_string(),
+            format!("{name}={value}"),
+        );
+        self
+    }
+
+    /// Only return events with this shared extended property, call it again to require more
+    /// properties.
+    pub fn shared_extended_property(mut self, name: &str, value: &str) -> Self {
+        self.request.params.append(
+            "sharedExtendedProperty".to_string(),
+            format!("{name}={value}"),
+        );
+        self
+    }
+
+    /// Filter if set to true includes deleted events with status "cancelled".
+    pub fn show_deleted(mut self, show: bool) -> Self {
+        self.request
+            .params
+            .insert("showDeleted".to_string(), show.to_string());
+        self
+    }
+
+    /// Only return events that were modified after this time, deleted events included.
+    pub fn updated_min(mut self, updated_min: DateTime<chrono::Utc>) -> Self {
+        self.request
+            .params
+            .insert("updatedMin".to_string(), updated_min.to_rfc3339());
+        self
+    }
+
+    /// Time zone used in the response. Defaults to the time zone of the calendar.
+    pub fn time_zone(mut self, time_zone: &str) -> Self {
+        self.request
+            .params
+            .insert("timeZone".to_string(), time_zone.to_string());
+        self
+    }
+
+    /// Whether to always include the email of the organizer and attendees, also when it is not
+    /// available. Deprecated by Google and ignored.
+    pub fn always_include_email(mut self, always: bool) -> Self {
+        self.request
+            .params
+            .insert("alwaysIncludeEmail".to_string(), always.to_string());
+        self
+    }
+
+    /// Only return the events that changed since the list request that returned this token as
+    /// `next_sync_token`. Deleted events are included with status "cancelled".
+    ///
+    /// A sync token can't be combined with `query`, `order_by`, `time_min`, `time_max` and
+    /// other filters that restrict the result. When the token expired Google responds with
+    /// `GoogleApiError::Gone`, and a full sync without a token is required. See `EventSync`
+    /// for a helper that handles this.
+    pub fn sync_token(mut self, token: &str) -> Self {
+        self.request
+            .params
+            .insert("syncToken".to_string(), token.to_string());
+        self
+    }
+
     /// Returns a request result for getting a list of events from the specified calendar.
-    pub async fn request(&mut self) -> Result<Option<EventList>, Error> {
+    pub async fn request(&mut self) -> Result<Option<EventList>, GoogleApiError> {
         self.make_request().await
     }
+
+    /// Stop `stream` and `collect_all` after this many events in total.
+    pub fn max_items(mut self, max: usize) -> Self {
+        self.request.max_items = Some(max);
+        self
+    }
+
+    /// Returns a stream of all events matching the filters, following `nextPageToken`
+    /// until the last page or until `max_items` events have been yielded.
+    ///
+    /// # Example
+    /// ```rust
+    /// let mut events = CalendarEventsClient::new(&client)
+    ///     .get_events("primary")
+    ///     .single_events(true)
+    ///     .stream();
+    /// while let Some(event) = events.try_next().await? {
+    ///     println!("{}", event.summary);
+    /// }
+    /// ```
+    pub fn stream(self) -> impl Stream<Item = Result<Event, GoogleApiError>> + 'a {
+        paginate::<EventList>(self.request)
+    }
+
+    /// Fetches every page and returns all events matching the filters.
+    pub async fn collect_all(self) -> Result<Vec<Event>, GoogleApiError> {
+        self.stream().try_collect().await
+    }
+}
+
+impl<'a> CalendarEventsClient<'a, EventGetMode> {
+    /// The maximum number of attendees to include in the response.
+    /// If there are more attendees, only the participant is returned.
+    pub fn max_attendees(mut self, max: i64) -> Self {
+        self.request
+            .params
+            .insert("maxAttendees".to_string(), max.to_string());
+        self
+    }
+
+    /// Time zone used in the response, e.g. "Europe/Amsterdam". Defaults to the time zone of
+    /// the calendar.
+    pub fn time_zone(mut self, time_zone: &str) -> Self {
+        self.request
+            .params
+            .insert("timeZone".to_string(), time_zone.to_string());
+        self
+    }
+
+    /// Returns a request result for getting the event.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(Event))` - The event if successful
+    /// * `Err(GoogleApiError)` - For example `GoogleApiError::NotFound` if it doesn't exist
+    pub async fn request(&mut self) -> Result<Option<Event>, GoogleApiError> {
+        self.make_request().await
+    }
+}
+
+impl<'a> PaginationRequestTrait for CalendarEventsClient<'a, EventInstancesMode> {
+    /// Maximum number of instances to return per page.
+    fn max_results(mut self, max: i64) -> Self {
+        self.request
+            .params
+            .insert("maxResults".to_string(), max.to_string());
+        self
+    }
+
+    /// Page token for pagination. Works with `max_results`.
+    fn page_token(mut self, token: &str) -> Self {
+        self.request
+            .params
+            .insert("pageToken".to_string(), token.to_string());
+        self
+    }
+}
+
+impl<'a> TimeRequestTrait for CalendarEventsClient<'a, EventInstancesMode> {
+    /// Only return instances ending after this time.
+    fn time_min(mut self, time_min: DateTime<chrono::Utc>) -> Self {
+        self.request
+            .params
+            .insert("timeMin".to_string(), time_min.to_rfc3339());
+        self
+    }
+
+    /// Only return instances starting before this time.
+    fn time_max(mut self, time_max: DateTime<chrono::Utc>) -> Self {
+        self.request
+            .params
+            .insert("timeMax".to_string(), time_max.to_rfc3339());
+        self
+    }
+}
+
+impl<'a> CalendarEventsClient<'a, EventInstancesMode> {
+    /// The maximum number of attendees to include in the response.
+    /// If there are more attendees, only the participant is returned.
+    pub fn max_attendees(mut self, max: i64) -> Self {
+        self.request
+            .params
+            .insert("maxAttendees".to_string(), max.to_string());
+        self
+    }
+
+    /// Only return the instance that originally started at this time, also when it has been
+    /// moved since.
+    pub fn original_start(mut self, original_start: DateTime<chrono::Utc>) -> Self {
+        self.request
+            .params
+            .insert("originalStart".to_string(), original_start.to_rfc3339());
+        self
+    }
+
+    /// Filter if set to true also returns cancelled instances.
+    pub fn show_deleted(mut self, show: bool) -> Self {
+        self.request
+            .params
+            .insert("showDeleted".to_string(), show.to_string());
+        self
+    }
+
+    /// Time zone used in the response, e.g. "Europe/Amsterdam". Defaults to the time zone of
+    /// the calendar.
+    pub fn time_zone(mut self, time_zone: &str) -> Self {
+        self.request
+            .params
+            .insert("timeZone".to_string(), time_zone.to_string());
+        self
+    }
+
+    /// Returns a request result for getting one page of instances of the recurring event.
+    pub async fn request(&mut self) -> Result<Option<EventList>, GoogleApiError> {
+        self.make_request().await
+    }
+
+    /// Stop `stream` and `collect_all` after this many instances in total.
+    pub fn max_items(mut self, max: usize) -> Self {
+        self.request.max_items = Some(max);
+        self
+    }
+
+    /// Returns a stream of all instances matching the filters, following `nextPageToken`
+    /// until the last page or until `max_items` instances have been yielded.
+    pub fn stream(self) -> impl Stream<Item = Result<Event, GoogleApiError>> + 'a {
+        paginate::<EventList>(self.request)
+    }
+
+    /// Fetches every page and returns all instances matching the filters.
+    pub async fn collect_all(self) -> Result<Vec<Event>, GoogleApiError> {
+        self.stream().try_collect().await
+    }
+}
+
+impl Page for EventList {
+    type Item = Event;
+
+    fn next_page_token(&self) -> Option<&str> {
+        (!self.next_page_token.is_empty()).then_some(self.next_page_token.as_str())
+    }
+
+    fn into_items(self) -> Vec<Event> {
+        self.items
+    }
 }
 
 impl<'a, T> CalendarEventsClient<'a, T> {
-    pub(super) async fn make_delete_request(&mut self) -> Result<bool, Error> {
-        self.request.client.refresh_access_token_check().await?;
-        let res = self
-            .request
-            .client
-            .req_client
-            .delete(&self.request.url)
-            .query(&self.request.params)
-            .send()
-            .await?;
-
-        if res.status().is_success() {
-            Ok(true)
-        } else {
-            let status = res.status();
-            let body = res.text().await.unwrap_or_default();
-            Err(anyhow!("Delete request failed with status {}: {}", status, body))
-        }
+    pub(super) async fn make_delete_request(&mut self) -> Result<(), GoogleApiError> {
+        self.request.execute_empty().await
     }
-    pub(super) async fn make_request<R>(&mut self) -> Result<Option<R>, Error>
+    pub(super) async fn make_request<R>(&mut self) -> Result<Option<R>, GoogleApiError>
     where
         R: DeserializeOwned,
     {
-        self.request.client.refresh_access_token_check().await?;
-        match self.request.method {
-            Method::GET => {
-                let res = self
-                    .request
-                    .client
-                    .req_client
-                    .get(&self.request.url)
-                    .query(&self.request.params)
-                    .send()
-                    .await?;
-
-                if res.status().is_success() {
-                    Ok(Some(res.json().await?))
-                } else {
-                    let status = res.status();
-                    let body = res.text().await.unwrap_or_default();
-                    Err(anyhow!("GET request failed with status {}: {}", status, body))
-                }
-            }
-
-            Method::POST => {
-                let res = self
-                    .request
-                    .client
-                    .req_client
-                    .post(&self.request.url)
-                    .body(serde_json::to_string(&self.event).unwrap())
-                    .query(&self.request.params)
-                    .send()
-                    .await?;
-
-                if res.status().is_success() {
-                    Ok(Some(res.json().await?))
-                } else {
-                    let status = res.status();
-                    let body = res.text().await.unwrap_or_default();
-                    Err(anyhow!("POST request failed with status {}: {}", status, body))
-                }
-            }
-
-            Method::PATCH => {
-                let res = self
-                    .request
-                    .client
-                    .req_client
-                    .patch(&self.request.url)
-                    .body(serde_json::to_string(&self.event).unwrap())
-                    .query(&self.request.params)
-                    .send()
-                    .await?;
-
-                if res.status().is_success() {
-                    Ok(Some(res.json().await?))
-                } else {
-                    let status = res.status();
-                    let body = res.text().await.unwrap_or_default();
-                    Err(anyhow!("PATCH request failed with status {}: {}", status, body))
-                }
-            }
-
-            _ => Err(anyhow!("Unsupported HTTP method")),
+        if let Some(event) = &self.event {
+            event.validate()?;
+            self.request.set_json_body(event)?;
         }
+        self.request.execute().await.map(Some)
     }
 }
 
 impl<'a> CalendarEventsClient<'a, EventInsertMode> {
+    /// Replaces the whole event being created, for example one parsed with
+    /// `ical::parse_ics`. Setters called afterwards modify this event.
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - The event to create, including its start and end
+    pub fn set_event(self, event: CreateEventRequest) -> Self {
+        self.modify_event(|current| *current = event)
+    }
+
     /// Sets the summary (title) of the event being created.
     ///
     /// # Arguments
@@ -491,16 +1020,8 @@ impl<'a> CalendarEventsClient<'a, EventInsertMode> {
     /// # Arguments
     ///
     /// * `type_` - The EventType to set for the event
-    ///
-    /// pub enum EventType {
-    ///    Birthday,
-    ///    Default,
-    ///    FocusTime,
-    ///    FromGmail,
-    ///    OutOfOffice,
-    /// }
     pub fn set_type(self, type_: EventType) -> Self {
-        self.modify_event(|event| event.event_type = Some(type_.as_str().to_string()))
+        self.modify_event(|event| event.event_type = Some(type_))
     }
 
     /// Sets the birthday properties for the event.
@@ -588,13 +1109,26 @@ impl<'a> CalendarEventsClient<'a, EventInsertMode> {
         self.modify_event(|event| event.recurrence = recurrence)
     }
 
+    /// Sets the recurrence of the event from a typed `Recurrence`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// .set_recurrence_rules(&Recurrence::new(
+    ///     RRule::new(Frequency::Weekly).by_weekdays(&[Weekday::Mon]).count(10),
+    /// ))
+    /// ```
+    pub fn set_recurrence_rules(self, recurrence: &Recurrence) -> Self {
+        self.set_recurrence(recurrence.to_lines())
+    }
+
     /// Sets the transparency of the event (whether it blocks time on the calendar).
     ///
     /// # Arguments
     ///
-    /// * `transparency` - Either "opaque" (blocks time) or "transparent" (does not block time)
-    pub fn set_transparency(self, transparency: &str) -> Self {
-        self.modify_event(|event| event.transparency = Some(transparency.to_string()))
+    /// * `transparency` - Either `Opaque` (blocks time) or `Transparent` (does not block time)
+    pub fn set_transparency(self, transparency: Transparency) -> Self {
+        self.modify_event(|event| event.transparency = Some(transparency))
     }
 
     /// Sets the reminder settings for the event.
@@ -648,14 +1182,56 @@ impl<'a> CalendarEventsClient<'a, EventInsertMode> {
         self.modify_event(|event| event.extended_properties = Some(extended_properties))
     }
 
+    /// Set the query parameter sendUpdates, which guests receive an invitation.
+    pub fn set_send_updates(mut self, send: SendUpdates) -> Self {
+        self.request
+            .params
+            .insert("sendUpdates".to_string(), send.as_str().to_string());
+        self
+    }
+
+    /// Sets the conference data of the event being created, for example copied from another event with
+    /// `ConferenceData::from`. Sets `conferenceDataVersion=1`, without it Google ignores
+    /// conference data.
+    pub fn set_conference_data(mut self, conference_data: ConferenceData) -> Self {
+        self.request
+            .params
+            .insert("conferenceDataVersion".to_string(), "1".to_string());
+        self.modify_event(|event| event.conference_data = Some(conference_data))
+    }
+
+    /// Requests a new Google Meet link for the event being created. Google creates the conference
+    /// asynchronously, the returned event has its entry points once
+    /// `Event::conference_pending` is false.
+    ///
+    /// # Arguments
+    ///
+    /// * `request_id` - A unique ID for this request, retrying with the same ID doesn't create
+    ///   another conference
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let event = CalendarEventsClient::new(&client)
+    ///     .insert_event("primary", start, end)
+    ///     .set_summary("Standup")
+    ///     .with_new_meet("standup-2025-06-02")
+    ///     .request()
+    ///     .await?
+    ///     .unwrap();
+    /// let link = event.video_entry_point().map(|entry| entry.uri.clone());
+    /// ```
+    pub fn with_new_meet(self, request_id: &str) -> Self {
+        self.set_conference_data(ConferenceData::new_meet(request_id))
+    }
+
     /// Executes the request to create the event.
     ///
     /// # Returns
     ///
     /// * `Ok(Some(Event))` - The created event if successful
-    /// * `Ok(None)` - If the request was unsuccessful
-    /// * `Err` - If there was an error making the request
-    pub async fn request(&mut self) -> Result<Option<Event>, Error> {
+    /// * `Err(GoogleApiError)` - If the request failed or Google returned a non-2xx status
+    pub async fn request(&mut self) -> Result<Option<Event>, GoogleApiError> {
         self.make_request().await
     }
 
@@ -733,16 +1309,8 @@ impl<'a> CalendarEventsClient<'a, EventPatchMode> {
     /// # Arguments
     ///
     /// * `event_type` - the new EventType
-    ///  
-    /// pub enum EventType {
-    ///    Birthday,
-    ///    Default,
-    ///    FocusTime,
-    ///    FromGmail,
-    ///    OutOfOffice,
-    /// }
     pub fn set_event_type(self, event_type: EventType) -> Self {
-        self.modify_event(|event| event.event_type = Some(event_type.as_str().to_string()))
+        self.modify_event(|event| event.event_type = Some(event_type))
     }
 
     /// Patch the guests_can_invite_others field
@@ -821,6 +1389,11 @@ impl<'a> CalendarEventsClient<'a, EventPatchMode> {
         self.modify_event(|event| event.recurrence = recurrence)
     }
 
+    /// Patch the recurrence field from a typed `Recurrence`, replacing the existing rules.
+    pub fn set_recurrence_rules(self, recurrence: &Recurrence) -> Self {
+        self.set_recurrence(recurrence.to_lines())
+    }
+
     /// Patch the reminders field
     ///
     /// # Arguments
@@ -855,35 +1428,30 @@ impl<'a> CalendarEventsClient<'a, EventPatchMode> {
     ///
     /// # Arguments
     ///
-    /// * `status` - &str
-    ///
-    /// Options are "confirmed", "tentative", "cancelled"
-    pub fn set_status(self, status: &str) -> Self {
-        self.modify_event(|event| event.status = Some(status.to_string()))
+    /// * `status` - `Confirmed`, `Tentative` or `Cancelled`
+    pub fn set_status(self, status: EventStatus) -> Self {
+        self.modify_event(|event| event.status = Some(status))
     }
 
-    /// Patch the transparancy field
+    /// Patch the transparency field
     ///
     /// # Arguments
     ///
-    /// * `transparancy` - &str
-    ///
-    /// Options are "opaque" (does block time on the calendar) or "transparant" (does not block
-    /// time on the calendar)"
-    pub fn set_transparancy(self, transparancy: &str) -> Self {
-        self.modify_event(|event| event.transparency = Some(transparancy.to_string()))
+    /// * `transparency` - `Opaque` (does block time on the calendar) or `Transparent` (does not
+    ///   block time on the calendar)
+    pub fn set_transparency(self, transparency: Transparency) -> Self {
+        self.modify_event(|event| event.transparency = Some(transparency))
     }
 
     /// Patch the visibility field
     ///
     /// # Arguments
     ///
-    /// * `visibility` - &str
-    ///
-    /// Options are "default", "public" (all readers of the calendar), "private" (only attendees
-    /// may view event details), "confidential" (same as private added compatibility reasons)
-    pub fn set_visibility(self, visibility: &str) -> Self {
-        self.modify_event(|event| event.visibility = Some(visibility.to_string()))
+    /// * `visibility` - `Default`, `Public` (all readers of the calendar), `Private` (only
+    ///   attendees may view event details) or `Confidential` (same as private, added for
+    ///   compatibility reasons)
+    pub fn set_visibility(self, visibility: Visibility) -> Self {
+        self.modify_event(|event| event.visibility = Some(visibility))
     }
 
     /// Patch the working location properties field
@@ -912,13 +1480,11 @@ impl<'a> CalendarEventsClient<'a, EventPatchMode> {
     ///
     /// # Arguments
     ///
-    /// * `send` - &str
-    ///
-    /// options are "all", "externalOnly", "none"
-    pub fn set_send_updates(mut self, send: &str) -> Self {
+    /// * `send` - Which guests receive notifications
+    pub fn set_send_updates(mut self, send: SendUpdates) -> Self {
         self.request
             .params
-            .insert("sendUpdates".to_string(), send.to_string());
+            .insert("sendUpdates".to_string(), send.as_str().to_string());
         self
     }
 
@@ -934,6 +1500,41 @@ impl<'a> CalendarEventsClient<'a, EventPatchMode> {
             .insert("conferenceDataVersion".to_string(), v.to_string());
         self
     }
+
+    /// Sets the conference data of the patched event, for example copied from another event with
+    /// `ConferenceData::from`. Sets `conferenceDataVersion=1`, without it Google ignores
+    /// conference data.
+    pub fn set_conference_data(mut self, conference_data: ConferenceData) -> Self {
+        self.request
+            .params
+            .insert("conferenceDataVersion".to_string(), "1".to_string());
+        self.modify_event(|event| event.conference_data = Some(conference_data))
+    }
+
+    /// Requests a new Google Meet link for the patched event. Google creates the conference
+    /// asynchronously, the returned event has its entry points once
+    /// `Event::conference_pending` is false.
+    ///
+    /// # Arguments
+    ///
+    /// * `request_id` - A unique ID for this request, retrying with the same ID doesn't create
+    ///   another conference
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // Add a Meet link to an existing event
+    /// let event = CalendarEventsClient::new(&client)
+    ///     .patch_event("primary", &event_id)
+    ///     .with_new_meet("standup-2025-06-02")
+    ///     .request()
+    ///     .await?
+    ///     .unwrap();
+    /// let link = event.video_entry_point().map(|entry| entry.uri.clone());
+    /// ```
+    pub fn with_new_meet(self, request_id: &str) -> Self {
+        self.set_conference_data(ConferenceData::new_meet(request_id))
+    }
     /// Set the maxAttendees query parameter
     ///  
     ///`Whether API client performing operation supports event attachments.
@@ -972,9 +1573,362 @@ impl<'a> CalendarEventsClient<'a, EventPatchMode> {
     /// # Returns
     ///
     /// * `Ok(Some(Event))` - The patched event if successful
-    /// * `Ok(None)` - If the request was unsuccessful
-    /// * `Err` - If there was an error making the request
-    pub async fn request(&mut self) -> Result<Option<Event>, Error> {
+    /// * `Err(GoogleApiError)` - If the request failed or Google returned a non-2xx status
+    pub async fn request(&mut self) -> Result<Option<Event>, GoogleApiError> {
+        self.make_request().await
+    }
+}
+
+impl<'a> CalendarEventsClient<'a, EventUpdateMode> {
+    /// Replace the summary of the event
+    pub fn set_summary(self, summary: &str) -> Self {
+        self.modify_event(|event| event.summary = summary.to_string())
+    }
+
+    /// Replace the description of the event
+    pub fn set_description(self, descr: &str) -> Self {
+        self.modify_event(|event| event.description = descr.to_string())
+    }
+
+    /// Replace the location of the event
+    pub fn set_location(self, location: &str) -> Self {
+        self.modify_event(|event| event.location = location.to_string())
+    }
+
+    /// Replace the start of the event
+    pub fn set_start(self, start: EventDateTime) -> Self {
+        self.modify_event(|event| event.start = Some(start))
+    }
+
+    /// Replace the end of the event
+    pub fn set_end(self, end: EventDateTime) -> Self {
+        self.modify_event(|event| event.end = Some(end))
+    }
+
+    /// Replace the attendees of the event
+    pub fn set_attendees(self, attendees: Vec<EventAttendee>) -> Self {
+        self.modify_event(|event| event.attendees = attendees)
+    }
+
+    /// Set the query parameter sendUpdates, which guests receive notifications.
+    pub fn set_send_updates(mut self, send: SendUpdates) -> Self {
+        self.request
+            .params
+            .insert("sendUpdates".to_string(), send.as_str().to_string());
+        self
+    }
+
+    /// Set the conference data version query parameter, use 1 to keep or change the
+    /// conference data of the event.
+    pub fn set_conference_data_version(mut self, v: i8) -> Self {
+        self.request
+            .params
+            .insert("conferenceDataVersion".to_string(), v.to_string());
+        self
+    }
+
+    /// Whether the client supports event attachments.
+    pub fn support_attachments(mut self, support: bool) -> Self {
+        self.request
+            .params
+            .insert("supportAttachments".to_string(), support.to_string());
+        self
+    }
+
+    /// Set the maxAttendees query parameter
+    pub fn set_max_attendees(mut self, v: i16) -> Self {
+        self.request
+            .params
+            .insert("maxAttendees".to_string(), v.to_string());
+        self
+    }
+
+    fn modify_event<F>(mut self, modifier: F) -> Self
+    where
+        F: FnOnce(&mut Event),
+    {
+        if let Some(EventRequest::Update(ref mut event)) = self.event {
+            modifier(event);
+        }
+        self
+    }
+
+    /// Executes the request to replace the event.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(Event))` - The updated event if successful
+    /// * `Err(GoogleApiError)` - If the request failed or Google returned a non-2xx status
+    pub async fn request(&mut self) -> Result<Option<Event>, GoogleApiError> {
+        self.make_request().await
+    }
+}
+
+impl<'a> CalendarEventsClient<'a, EventMoveMode> {
+    /// Set the query parameter sendUpdates, which guests receive notifications.
+    pub fn set_send_updates(mut self, send: SendUpdates) -> Self {
+        self.request
+            .params
+            .insert("sendUpdates".to_string(), send.as_str().to_string());
+        self
+    }
+
+    /// Executes the request to move the event.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(Event))` - The event in its new calendar if successful
+    /// * `Err(GoogleApiError)` - If the request failed or Google returned a non-2xx status
+    pub async fn request(&mut self) -> Result<Option<Event>, GoogleApiError> {
+        self.make_request().await
+    }
+}
+
+impl<'a> CalendarEventsClient<'a, EventQuickAddMode> {
+    /// Set the query parameter sendUpdates, which guests receive notifications.
+    pub fn set_send_updates(mut self, send: SendUpdates) -> Self {
+        self.request
+            .params
+            .insert("sendUpdates".to_string(), send.as_str().to_string());
+        self
+    }
+
+    /// Executes the request to create the event.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(Event))` - The created event if successful
+    /// * `Err(GoogleApiError)` - If the request failed or Google returned a non-2xx status
+    pub async fn request(&mut self) -> Result<Option<Event>, GoogleApiError> {
+        self.make_request().await
+    }
+}
+
+impl<'a> CalendarEventsClient<'a, EventImportMode> {
+    /// Replaces the whole imported event, for example one parsed with `ical::parse_ics`. The
+    /// `ical_uid` passed to `import_event` is kept when the event has none.
+    pub fn set_event(self, event: CreateEventRequest) -> Self {
+        self.modify_event(|current| {
+            let ical_uid = current.ical_uid.take();
+            *current = event;
+            if current.ical_uid.is_none() {
+                current.ical_uid = ical_uid;
+            }
+        })
+    }
+
+    /// Sets the summary (title) of the imported event.
+    pub fn set_summary(self, summary: &str) -> Self {
+        self.modify_event(|event| event.summary = Some(summary.to_string()))
+    }
+
+    /// Sets the description of the imported event.
+    pub fn set_description(self, descr: &str) -> Self {
+        self.modify_event(|event| event.description = Some(descr.to_string()))
+    }
+
+    /// Sets the location of the imported event.
+    pub fn set_location(self, location: &str) -> Self {
+        self.modify_event(|event| event.location = Some(location.to_string()))
+    }
+
+    /// Sets the attendees of the imported event, they don't receive an invitation.
+    pub fn set_attendees(self, attendees: Vec<EventAttendee>) -> Self {
+        self.modify_event(|event| event.attendees = attendees)
+    }
+
+    /// Sets the recurrence rules of the imported event in iCalendar RFC 5545 format.
+    pub fn set_recurrence(self, recurrence: Vec<String>) -> Self {
+        self.modify_event(|event| event.recurrence = recurrence)
+    }
+
+    /// Sets the iCalendar sequence number, an import with a lower sequence than the existing
+    /// event is ignored.
+    pub fn set_sequence(self, sequence: i32) -> Self {
+        self.modify_event(|event| event.sequence = Some(sequence))
+    }
+
+    /// Sets the status of the imported event.
+    pub fn set_status(self, status: EventStatus) -> Self {
+        self.modify_event(|event| event.status = Some(status))
+    }
+
+    /// Sets the transparency of the imported event, `Opaque` blocks time on the calendar.
+    pub fn set_transparency(self, transparency: Transparency) -> Self {
+        self.modify_event(|event| event.transparency = Some(transparency))
+    }
+
+    /// Sets the visibility of the imported event.
+    pub fn set_visibility(self, visibility: Visibility) -> Self {
+        self.modify_event(|event| event.visibility = Some(visibility))
+    }
+
+    /// Sets the extended properties of the imported event, e.g. the ID in the source system.
+    pub fn set_extended_properties(self, extended_properties: ExtendedProperties) -> Self {
+        self.modify_event(|event| event.extended_properties = Some(extended_properties))
+    }
+
+    /// Set the conference data version query parameter, use 1 to import conference data.
+    pub fn set_conference_data_version(mut self, v: i8) -> Self {
+        self.request
+            .params
+            .insert("conferenceDataVersion".to_string(), v.to_string());
+        self
+    }
+
+    /// Whether the client supports event attachments.
+    pub fn support_attachments(mut self, support: bool) -> Self {
+        self.request
+            .params
+            .insert("supportAttachments".to_string(), support.to_string());
+        self
+    }
+
+    fn modify_event<F>(mut self, modifier: F) -> Self
+    where
+        F: FnOnce(&mut CreateEventRequest),
+    {
+        if let Some(EventRequest::Create(ref mut event)) = self.event {
+            modifier(event);
+        }
+        self
+    }
+
+    /// Executes the request to import the event.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(Event))` - The imported event if successful
+    /// * `Err(GoogleApiError)` - If the request failed or Google returned a non-2xx status
+    pub async fn request(&mut self) -> Result<Option<Event>, GoogleApiError> {
+        self.make_request().await
+    }
+}
+
+impl<'a> CalendarEventsClient<'a, EventRespondMode> {
+    /// Adds a comment to the response, like the reason to decline.
+    pub fn comment(self, comment: &str) -> Self {
+        self.modify_response(|attendee| attendee.comment = comment.to_string())
+    }
+
+    /// The number of extra guests the user brings.
+    pub fn additional_guests(self, guests: i64) -> Self {
+        self.modify_response(|attendee| attendee.additional_guests = guests)
+    }
+
+    /// Guests who should receive notifications about the response.
+    pub fn set_send_updates(mut self, send: SendUpdates) -> Self {
+        self.request
+            .params
+            .insert("sendUpdates".to_string(), send.as_str().to_string());
+        self
+    }
+
+    fn modify_response<F>(mut self, modifier: F) -> Self
+    where
+        F: FnOnce(&mut EventAttendee),
+    {
+        if let Some(EventRequest::Patch(ref mut event)) = self.event {
+            if let Some(attendee) = event.attendees.iter_mut().find(|a| a.self_ == Some(true)) {
+                modifier(attendee);
+            }
+        }
+        self
+    }
+
+    /// Fetches the event and patches its attendees with the response of the user.
+    ///
+    /// The patch is only applied if the event is unchanged since it was fetched (`If-Match`
+    /// with its etag), so attendee changes made in the meantime are never overwritten.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(Event))` - The event with the new response if successful
+    /// * `Err(GoogleApiError::PreconditionFailed)` - If the event changed after it was fetched,
+    ///   sending the request again retries with the new attendees
+    /// * `Err(GoogleApiError)` - If the user is not an attendee, the request failed or Google
+    ///   returned a non-2xx status
+    pub async fn request(&mut self) -> Result<Option<Event>, GoogleApiError> {
+        let mut get = Request::new(self.request.client);
+        get.url = self.request.url.clone();
+        let current: Event = get.execute().await?;
+        if !current.etag.is_empty() {
+            let etag = HeaderValue::from_str(&current.etag).map_err(|_| {
+                GoogleApiError::InvalidRequest(format!("Invalid etag {}", current.etag))
+            })?;
+            self.request.headers.insert(IF_MATCH, etag);
+        }
+
+        let Some(EventRequest::Patch(ref mut patch)) = self.event else {
+            return Ok(None);
+        };
+        let response = patch
+            .attendees
+            .iter()
+            .find(|a| a.self_ == Some(true))
+            .cloned()
+            .ok_or_else(|| GoogleApiError::InvalidRequest("No response to send".to_string()))?;
+        let mut attendees = current.attendees;
+        let attendee = attendees
+            .iter_mut()
+            .find(|a| a.self_ == Some(true))
+            .ok_or_else(|| {
+                GoogleApiError::InvalidRequest(
+                    "The authenticated user is not an attendee of the event".to_string(),
+                )
+            })?;
+        attendee.response_status = response.response_status;
+        if !response.comment.is_empty() {
+            attendee.comment = response.comment;
+        }
+        if response.additional_guests > 0 {
+            attendee.additional_guests = response.additional_guests;
+        }
+        patch.attendees = attendees;
+        self.make_request().await
+    }
+}
+
+impl<'a> CalendarEventsClient<'a, EventWatchMode> {
+    /// Set a token that is sent back with every notification, to verify its origin.
+    pub fn set_token(self, token: &str) -> Self {
+        self.modify_channel(|channel| channel.token = token.to_string())
+    }
+
+    /// Request the channel to expire after this many seconds.
+    pub fn set_ttl(self, seconds: u64) -> Self {
+        self.modify_channel(|channel| {
+            channel
+                .params
+                .insert("ttl".to_string(), seconds.to_string());
+        })
+    }
+
+    /// Only notify about events of this type, call it again to include more types.
+    pub fn event_type(mut self, type_: EventType) -> Self {
+        self.request
+            .params
+            .append("eventTypes".to_string(), type_.as_str().to_string());
+        self
+    }
+
+    fn modify_channel<F>(mut self, modifier: F) -> Self
+    where
+        F: FnOnce(&mut Channel),
+    {
+        if let Some(EventRequest::Watch(ref mut channel)) = self.event {
+            modifier(channel);
+        }
+        self
+    }
+
+    /// Executes the request to create the channel.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(Channel))` - The created channel with its `resource_id` and expiration
+    /// * `Err(GoogleApiError)` - If the request failed or Google returned a non-2xx status
+    pub async fn request(&mut self) -> Result<Option<Channel>, GoogleApiError> {
         self.make_request().await
     }
 }
@@ -984,25 +1938,19 @@ impl<'a> CalendarEventsClient<'a, EventDeleteMode> {
     ///
     /// # Returns
     ///
-    /// * `Result<bool, Error>` - A result indicating whether the deletion was successful.
-    pub async fn request(&mut self) -> Result<(), Error> {
-        match self.make_delete_request().await {
-            Ok(true) => Ok(()),
-            Ok(false) => Err(anyhow::anyhow!("Failed to delete event")),
-            Err(e) => Err(e),
-        }
+    /// * `Result<(), GoogleApiError>` - `Ok(())` if the event was deleted, for example
+    ///   `GoogleApiError::NotFound` if it doesn't exist.
+    pub async fn request(&mut self) -> Result<(), GoogleApiError> {
+        self.make_delete_request().await
     }
 
     /// Guests who should receive notifications about the deletion of the event.
-    /// Acceptable values are:
-    ///
-    /// "all": Notifications are sent to all guests.
-    /// "externalOnly": Notifications are sent to non-Google Calendar guests only.
-    /// "none": No notifications are sent. For calendar migration tasks, consider using the Events.import method instead.
-    pub fn send_updates(mut self, send: &str) -> Self {
+    /// For calendar migration tasks, consider using the Events.import method instead of
+    /// `SendUpdates::None`.
+    pub fn send_updates(mut self, send: SendUpdates) -> Self {
         self.request
             .params
-            .insert("sendUpdates".to_string(), send.to_string());
+            .insert("sendUpdates".to_string(), send.as_str().to_string());
         self
     }
 }
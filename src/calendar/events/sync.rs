@@ -0,0 +1,152 @@
+use crate::{
+    auth::client::GoogleClient, error::GoogleApiError, utils::request::PaginationRequestTrait,
+};
+
+use super::{
+    requests::CalendarEventsClient,
+    types::{Event, EventStatus},
+};
+
+/// Keeps a local copy of a calendar in sync using sync tokens.
+///
+/// The first call to `sync` lists every event of the calendar and stores the `nextSyncToken`
+/// of the last page. Later calls only return the events that changed since the previous
+/// call. When Google expires the token (410 Gone) the sync falls back to a full sync, which
+/// is signalled by `EventChanges::full_sync`.
+///
+/// # Example
+/// ```rust
+/// // Restore the token stored by the previous run, if any
+/// let mut sync = match db.load_sync_token("primary").await? {
+///     Some(token) => EventSync::with_sync_token("primary", &token),
+///     None => EventSync::new("primary"),
+/// };
+/// let changes = sync.sync(&client).await?;
+/// if changes.full_sync {
+///     db.delete_all_events("primary").await?;
+/// }
+/// db.upsert_events(&changes.changed).await?;
+/// db.delete_events(changes.deleted.iter().map(|e| &e.id)).await?;
+/// db.save_sync_token("primary", sync.sync_token().unwrap()).await?;
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventSync {
+    calendar_id: String,
+    sync_token: Option<String>,
+    single_events: bool,
+    max_results: Option<i64>,
+}
+
+/// The events returned by one call to `EventSync::sync`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct EventChanges {
+    /// True if every event of the calendar was fetched, either because there was no sync token
+    /// yet or because it expired. Events missing from `changed` no longer exist.
+    pub full_sync: bool,
+    /// Events that were created or changed.
+    pub changed: Vec<Event>,
+    /// Events that were deleted, with status "cancelled". Only the `id` is guaranteed to be set.
+    pub deleted: Vec<Event>,
+}
+
+impl EventSync {
+    /// Starts with a full sync of the calendar.
+    pub fn new(calendar_id: &str) -> Self {
+        Self {
+            calendar_id: calendar_id.to_string(),
+            sync_token: None,
+            single_events: false,
+            max_results: None,
+        }
+    }
+
+    /// Continues from a sync token stored by a previous run.
+    pub fn with_sync_token(calendar_id: &str, sync_token: &str) -> Self {
+        Self {
+            sync_token: Some(sync_token.to_string()),
+            ..Self::new(calendar_id)
+        }
+    }
+
+    /// Expand recurring events into their instances. A sync token is only valid for the
+    /// setting it was created with, so don't change this between runs.
+    pub fn single_events(mut self, single: bool) -> Self {
+        self.single_events = single;
+        self
+    }
+
+    /// Maximum number of events fetched per page.
+    pub fn max_results(mut self, max: i64) -> Self {
+        self.max_results = Some(max);
+        self
+    }
+
+    /// The ID of the synced calendar.
+    pub fn calendar_id(&self) -> &str {
+        &self.calendar_id
+    }
+
+    /// The token of the last successful sync, store it to continue after a restart.
+    pub fn sync_token(&self) -> Option<&str> {
+        self.sync_token.as_deref()
+    }
+
+    /// Fetches the events that changed since the last sync, or every event on the first call.
+    ///
+    /// The sync token is only updated once every page was fetched, so a failed sync can
+    /// simply be retried.
+    pub async fn sync(&mut self, client: &GoogleClient) -> Result<EventChanges, GoogleApiError> {
+        let mut changes = EventChanges {
+            full_sync: self.sync_token.is_none(),
+            ..Default::default()
+        };
+        let mut sync_token = self.sync_token.clone();
+        let mut page_token: Option<String> = None;
+
+        loop {
+            let mut builder = CalendarEventsClient::new(client)
+                .get_events(&self.calendar_id)
+                .single_events(self.single_events);
+            if let Some(max) = self.max_results {
+                builder = builder.max_results(max);
+            }
+            if let Some(token) = &sync_token {
+                builder = builder.sync_token(token);
+            }
+            if let Some(token) = &page_token {
+                builder = builder.page_token(token);
+            }
+
+            let page = match builder.request().await {
+                Ok(Some(page)) => page,
+                Ok(None) => return Ok(changes),
+                Err(GoogleApiError::Gone(_)) if sync_token.is_some() => {
+                    // The token expired, start over with a full sync
+                    sync_token = None;
+                    page_token = None;
+                    changes = EventChanges {
+                        full_sync: true,
+                        ..Default::default()
+                    };
+                    continue;
+                }
+                Err(e) => return Err(e),
+            };
+
+            let (deleted, changed): (Vec<_>, Vec<_>) = page
+                .items
+                .into_iter()
+                .partition(|event| event.status == Some(EventStatus::Cancelled));
+            changes.changed.extend(changed);
+            changes.deleted.extend(deleted);
+
+            if !page.next_page_token.is_empty() {
+                page_token = Some(page.next_page_token);
+                continue;
+            }
+            // Without a token the next call falls back to a full sync
+            self.sync_token = (!page.next_sync_token.is_empty()).then_some(page.next_sync_token);
+            return Ok(changes);
+        }
+    }
+}
@@ -0,0 +1,118 @@
+#![allow(clippy::unwrap_used)]
+
+use mockito::Matcher;
+
+use super::sync::EventSync;
+use crate::{error::GoogleApiError, utils::test_support::client};
+
+const EVENTS_PATH: &str = "/calendar/v3/calendars/primary/events";
+
+#[tokio::test]
+async fn first_sync_fetches_every_page_and_stores_token() {
+    let mut server = mockito::Server::new_async().await;
+    let first = server
+        .mock("GET", EVENTS_PATH)
+        .match_query(Matcher::Exact("singleEvents=true".into()))
+        .with_status(200)
+        .with_body(r#"{"items": [{"id": "a", "status": "confirmed"}], "nextPageToken": "page2"}"#)
+        .create_async()
+        .await;
+    let second = server
+        .mock("GET", EVENTS_PATH)
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("singleEvents".into(), "true".into()),
+            Matcher::UrlEncoded("pageToken".into(), "page2".into()),
+        ]))
+        .with_status(200)
+        .with_body(r#"{"items": [{"id": "b", "status": "confirmed"}], "nextSyncToken": "sync1"}"#)
+        .create_async()
+        .await;
+
+    let gc = client(&server.url());
+    let mut sync = EventSync::new("primary").single_events(true);
+    let changes = sync.sync(&gc).await.unwrap();
+
+    assert!(changes.full_sync);
+    let ids: Vec<_> = changes.changed.iter().map(|e| e.id.as_str()).collect();
+    assert_eq!(ids, vec!["a", "b"]);
+    assert!(changes.deleted.is_empty());
+    assert_eq!(sync.sync_token(), Some("sync1"));
+    first.assert_async().await;
+    second.assert_async().await;
+}
+
+#[tokio::test]
+async fn incremental_sync_splits_changed_and_deleted_events() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", EVENTS_PATH)
+        .match_query(Matcher::UrlEncoded("syncToken".into(), "sync1".into()))
+        .with_status(200)
+        .with_body(
+            r#"{"items": [{"id": "a", "status": "confirmed"}, {"id": "b", "status": "cancelled"}],
+                "nextSyncToken": "sync2"}"#,
+        )
+        .create_async()
+        .await;
+
+    let gc = client(&server.url());
+    let mut sync = EventSync::with_sync_token("primary", "sync1");
+    let changes = sync.sync(&gc).await.unwrap();
+
+    assert!(!changes.full_sync);
+    assert_eq!(changes.changed.len(), 1);
+    assert_eq!(changes.deleted[0].id, "b");
+    assert_eq!(sync.sync_token(), Some("sync2"));
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn expired_token_falls_back_to_full_sync() {
+    let mut server = mockito::Server::new_async().await;
+    let gone = server
+        .mock("GET", EVENTS_PATH)
+        .match_query(Matcher::UrlEncoded("syncToken".into(), "expired".into()))
+        .with_status(410)
+        .with_body(
+            r#"{"error": {"code": 410, "message": "Sync token is no longer valid, a full sync is required.",
+                "errors": [{"domain": "calendar", "reason": "fullSyncRequired"}]}}"#,
+        )
+        .create_async()
+        .await;
+    let full = server
+        .mock("GET", EVENTS_PATH)
+        .match_query(Matcher::Exact("singleEvents=false".into()))
+        .with_status(200)
+        .with_body(r#"{"items": [{"id": "a"}], "nextSyncToken": "fresh"}"#)
+        .create_async()
+        .await;
+
+    let gc = client(&server.url());
+    let mut sync = EventSync::with_sync_token("primary", "expired");
+    let changes = sync.sync(&gc).await.unwrap();
+
+    assert!(changes.full_sync);
+    assert_eq!(changes.changed[0].id, "a");
+    assert_eq!(sync.sync_token(), Some("fresh"));
+    gone.assert_async().await;
+    full.assert_async().await;
+}
+
+#[tokio::test]
+async fn failed_sync_keeps_previous_token() {
+    let mut server = mockito::Server::new_async().await;
+    server
+        .mock("GET", EVENTS_PATH)
+        .match_query(Matcher::Any)
+        .with_status(403)
+        .with_body(r#"{"error": {"code": 403, "message": "Forbidden"}}"#)
+        .create_async()
+        .await;
+
+    let gc = client(&server.url());
+    let mut sync = EventSync::with_sync_token("primary", "sync1");
+    let err = sync.sync(&gc).await.unwrap_err();
+
+    assert!(matches!(err, GoogleApiError::Forbidden(_)));
+    assert_eq!(sync.sync_token(), Some("sync1"));
+}
@@ -1,6 +1,12 @@
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::error::GoogleApiError;
+
+use super::recurrence::Recurrence;
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EventDefaultReminder {
     /**
@@ -12,14 +18,14 @@ pub struct EventDefaultReminder {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
     )]
-    method: String,
+    pub method: String,
 
     #[serde(
         default,
         skip_serializing_if = "crate::utils::validation::zero_i64",
         deserialize_with = "crate::utils::deserialize::deserialize_nullable_i64::deserialize"
     )]
-    minutes: i64,
+    pub minutes: i64,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -57,12 +63,8 @@ pub struct Event {
     /**
      * Status of the event. Optional. Possible values are: "confirmed", "tentative", "cancelled".
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
-    )]
-    pub status: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<EventStatus>,
 
     /**
      * HTML link to the event in the Google Calendar web UI.
@@ -204,22 +206,14 @@ pub struct Event {
     /**
      * Transparency of the event. Optional. Possible values are: "opaque", "transparent".
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
-    )]
-    pub transparency: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transparency: Option<Transparency>,
 
     /**
      * Visibility of the event. Optional. Possible values are: "default", "public", "private", "confidential".
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
-    )]
-    pub visibility: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<Visibility>,
 
     /**
      * Event unique identifier as defined in RFC5545.
@@ -420,13 +414,8 @@ pub struct Event {
     /**
      * Event type. The possible values are: "default", "outOfOffice", "focusTime", "workingLocation".
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize",
-        rename = "eventType"
-    )]
-    pub event_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "eventType")]
+    pub event_type: Option<EventType>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -459,16 +448,23 @@ pub struct EventPerson {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
 pub struct EventDateTime {
+    /**
+     * The date of an all-day event, "yyyy-mm-dd" in JSON.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub date: Option<String>,
+    pub date: Option<NaiveDate>,
 
+    /**
+     * The start or end of a timed event, with the UTC offset it was sent with.
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::deserialize::deserialize_date_time_format::deserialize",
+        deserialize_with = "crate::utils::deserialize::deserialize_date_time_offset_format::deserialize",
+        serialize_with = "crate::utils::serialize::serialize_date_time_offset_format::serialize",
         rename = "dateTime"
     )]
-    pub date_time: Option<chrono::DateTime<chrono::Utc>>,
+    pub date_time: Option<DateTime<FixedOffset>>,
 
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "timeZone")]
     pub time_zone: Option<String>,
@@ -512,11 +508,10 @@ pub struct EventAttendee {
 
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "responseStatus"
     )]
-    pub response_status: String,
+    pub response_status: Option<ResponseStatus>,
 
     #[serde(
         default,
@@ -1147,7 +1142,7 @@ pub struct PatchEventRequest {
 
     /// Event type (default, focusTime, etc)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_type: Option<String>,
+    pub event_type: Option<EventType>,
 
     /// Extended properties
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1203,7 +1198,7 @@ pub struct PatchEventRequest {
 
     /// Event status
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<EventStatus>,
 
     /// Event summary/title
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1211,11 +1206,11 @@ pub struct PatchEventRequest {
 
     /// Whether the event blocks time on the calendar
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub transparency: Option<String>,
+    pub transparency: Option<Transparency>,
 
     /// Visibility of the event
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub visibility: Option<String>,
+    pub visibility: Option<Visibility>,
 
     /// Working location properties
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1257,7 +1252,7 @@ pub struct CreateEventRequest {
 
     /// Event type (default, focusTime, etc)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_type: Option<String>,
+    pub event_type: Option<EventType>,
 
     /// Extended properties
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1295,6 +1290,16 @@ pub struct CreateEventRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub location: Option<String>,
 
+    /// The organizer, only used by the import API
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organizer: Option<EventPerson>,
+
+    /// For an override of one instance of a recurring event, the start of the instance
+    /// according to the recurrence. Only used by the import API, which attaches the override
+    /// to the recurring event with the same `ical_uid`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub original_start_time: Option<EventDateTime>,
+
     /// Out of office properties
     #[serde(skip_serializing_if = "Option::is_none")]
     pub out_of_office_properties: Option<OutOfOfficeProperties>,
@@ -1317,7 +1322,7 @@ pub struct CreateEventRequest {
 
     /// Event status
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<EventStatus>,
 
     /// Event summary/title
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1325,19 +1330,24 @@ pub struct CreateEventRequest {
 
     /// Whether the event blocks time on the calendar
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub transparency: Option<String>,
+    pub transparency: Option<Transparency>,
 
     /// Visibility of the event
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub visibility: Option<String>,
+    pub visibility: Option<Visibility>,
 
     /// Working location properties
     #[serde(skip_serializing_if = "Option::is_none")]
     pub working_location_properties: Option<WorkingLocationProperties>,
 }
 
-#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Default, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
 pub struct ConferenceData {
+    /// Request to create a new conference, like a Google Meet
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create_request: Option<ConferenceRequestStatus>,
+
     /// Conference solution
     #[serde(skip_serializing_if = "Option::is_none")]
     pub conference_solution: Option<ConferenceSolution>,
@@ -1345,6 +1355,10 @@ pub struct ConferenceData {
     /// Entry points
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub entry_points: Vec<EntryPoint>,
+
+    /// The ID of an existing conference
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conference_id: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
@@ -1358,6 +1372,369 @@ pub struct ExtendedProperties {
     pub shared: Option<std::collections::HashMap<String, String>>,
 }
 
+/// The status of an event.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum EventStatus {
+    Confirmed,
+    Tentative,
+    /// The event is deleted, or a single instance of a recurring event is removed
+    Cancelled,
+    /// A status that was added to the API after this version of the crate
+    #[serde(untagged)]
+    Unknown(String),
+}
+impl EventStatus {
+    pub fn as_str(&self) -> &str {
+        match self {
+            EventStatus::Confirmed => "confirmed",
+            EventStatus::Tentative => "tentative",
+            EventStatus::Cancelled => "cancelled",
+            EventStatus::Unknown(status) => status,
+        }
+    }
+}
+
+/// Whether an event blocks time on the calendar.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum Transparency {
+    /// The event blocks time, the user is busy
+    Opaque,
+    /// The event doesn't block time, the user is available
+    Transparent,
+    /// A value that was added to the API after this version of the crate
+    #[serde(untagged)]
+    Unknown(String),
+}
+impl Transparency {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Transparency::Opaque => "opaque",
+            Transparency::Transparent => "transparent",
+            Transparency::Unknown(transparency) => transparency,
+        }
+    }
+}
+
+/// Who can see the details of an event.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum Visibility {
+    /// The default visibility of the calendar
+    Default,
+    Public,
+    Private,
+    /// Same as `Private`, kept for compatibility
+    Confidential,
+    /// A visibility that was added to the API after this version of the crate
+    #[serde(untagged)]
+    Unknown(String),
+}
+impl Visibility {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Visibility::Default => "default",
+            Visibility::Public => "public",
+            Visibility::Private => "private",
+            Visibility::Confidential => "confidential",
+            Visibility::Unknown(visibility) => visibility,
+        }
+    }
+}
+
+/** Event types for Google Calendar events.
+* These are used to filter events when making requests.
+* See [Google Calendar API
+* documentation](https://developers.google.com/calendar/api/v3/reference/events
+*/
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum EventType {
+    Birthday,
+    Default,
+    FocusTime,
+    FromGmail,
+    OutOfOffice,
+    WorkingLocation,
+    /// An event type that was added to the API after this version of the crate
+    #[serde(untagged)]
+    Unknown(String),
+}
+impl EventType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            EventType::Birthday => "birthday",
+            EventType::Default => "default",
+            EventType::FocusTime => "focusTime",
+            EventType::FromGmail => "fromGmail",
+            EventType::OutOfOffice => "outOfOffice",
+            EventType::WorkingLocation => "workingLocation",
+            EventType::Unknown(event_type) => event_type,
+        }
+    }
+}
+
+/// The response of an attendee to an invitation.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum ResponseStatus {
+    NeedsAction,
+    Declined,
+    Tentative,
+    Accepted,
+    /// A response that was added to the API after this version of the crate
+    #[serde(untagged)]
+    Unknown(String),
+}
+impl ResponseStatus {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ResponseStatus::NeedsAction => "needsAction",
+            ResponseStatus::Declined => "declined",
+            ResponseStatus::Tentative => "tentative",
+            ResponseStatus::Accepted => "accepted",
+            ResponseStatus::Unknown(response) => response,
+        }
+    }
+}
+
+impl Event {
+    /// The typed `recurrence` of the event. Events that don't recur have no rules.
+    pub fn parse_recurrence(&self) -> Result<Recurrence, GoogleApiError> {
+        Recurrence::parse(&self.recurrence)
+    }
+
+    /// The entry point to join the conference by video, like the Google Meet link.
+    pub fn video_entry_point(&self) -> Option<&EntryPoint> {
+        self.entry_points()
+            .find(|entry| entry.entry_point_type == "video")
+    }
+
+    /// The entry points to dial in to the conference by phone.
+    pub fn phone_entry_points(&self) -> Vec<&EntryPoint> {
+        self.entry_points()
+            .filter(|entry| entry.entry_point_type == "phone")
+            .collect()
+    }
+
+    /// Whether a requested conference is still being created, get the event again later to
+    /// see its entry points.
+    pub fn conference_pending(&self) -> bool {
+        self.conference_data
+            .as_ref()
+            .and_then(|data| data.create_request.as_ref())
+            .and_then(|request| request.status.as_ref())
+            .is_some_and(|status| status.status_code == "pending")
+    }
+
+    /// The attendee entry of the authenticated user, `None` if the user isn't invited.
+    pub fn self_attendee(&self) -> Option<&EventAttendee> {
+        self.attendees.iter().find(|a| a.self_ == Some(true))
+    }
+
+    /// The number of guests per response. Rooms and other resources are not counted.
+    pub fn response_counts(&self) -> ResponseCounts {
+        let mut counts = ResponseCounts::default();
+        for guest in self.guests() {
+            match guest.response_status {
+                Some(ResponseStatus::Accepted) => counts.accepted += 1,
+                Some(ResponseStatus::Declined) => counts.declined += 1,
+                Some(ResponseStatus::Tentative) => counts.tentative += 1,
+                _ => counts.needs_action += 1,
+            }
+        }
+        counts
+    }
+
+    /// The guests whose attendance is required, without rooms and other resources.
+    pub fn required_attendees(&self) -> Vec<&EventAttendee> {
+        self.guests().filter(|a| a.optional != Some(true)).collect()
+    }
+
+    /// The guests whose attendance is optional.
+    pub fn optional_attendees(&self) -> Vec<&EventAttendee> {
+        self.guests().filter(|a| a.optional == Some(true)).collect()
+    }
+
+    fn guests(&self) -> impl Iterator<Item = &EventAttendee> {
+        self.attendees.iter().filter(|a| a.resource != Some(true))
+    }
+
+    fn entry_points(&self) -> impl Iterator<Item = &EntryPoint> {
+        self.conference_data
+            .iter()
+            .flat_map(|data| data.entry_points.iter())
+    }
+}
+
+/// The number of guests of an event per response, see `Event::response_counts`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ResponseCounts {
+    pub accepted: usize,
+    pub declined: usize,
+    pub tentative: usize,
+    /// Guests who haven't responded yet
+    pub needs_action: usize,
+}
+
+impl ConferenceData {
+    /// Requests a new Google Meet conference. Retrying with the same `request_id` doesn't
+    /// create another conference.
+    pub fn new_meet(request_id: &str) -> Self {
+        ConferenceData {
+            create_request: Some(ConferenceRequestStatus {
+                request_id: request_id.to_string(),
+                conference_solution_key: Some(ConferenceSolutionKey {
+                    r#type: "hangoutsMeet".to_string(),
+                }),
+                status: None,
+            }),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<EventConferenceData> for ConferenceData {
+    /// Copies the conference of an existing event.
+    fn from(data: EventConferenceData) -> Self {
+        ConferenceData {
+            create_request: None,
+            conference_solution: data.conference_solution,
+            entry_points: data.entry_points,
+            conference_id: Some(data.conference_id).filter(|id| !id.is_empty()),
+        }
+    }
+}
+
+impl EventDateTime {
+    /// An all-day date. The end date of an all-day event is exclusive, so a single day event
+    /// ends on the next day.
+    pub fn all_day(date: NaiveDate) -> Self {
+        EventDateTime {
+            date: Some(date),
+            ..Default::default()
+        }
+    }
+
+    /// A date-time in an IANA time zone. Google uses the time zone to expand recurring events,
+    /// so they keep the same local time across DST changes.
+    pub fn zoned(date_time: DateTime<Tz>) -> Self {
+        EventDateTime {
+            date_time: Some(date_time.fixed_offset()),
+            time_zone: Some(date_time.timezone().name().to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// A date-time without a time zone, it is shown in the time zone of the calendar.
+    pub fn utc(date_time: DateTime<Utc>) -> Self {
+        Self::with_offset(date_time.fixed_offset())
+    }
+
+    /// A date-time with a UTC offset but without a time zone, it is shown in the time zone of
+    /// the calendar.
+    pub fn with_offset(date_time: DateTime<FixedOffset>) -> Self {
+        EventDateTime {
+            date_time: Some(date_time),
+            ..Default::default()
+        }
+    }
+
+    /// A wall clock time in `time_zone`. An ambiguous time, when the clock goes back, is the
+    /// first of the two. A time skipped when the clock goes forward is an error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let start = EventDateTime::from_local(
+    ///     NaiveDate::from_ymd_opt(2025, 3, 31).unwrap().and_hms_opt(9, 0, 0).unwrap(),
+    ///     chrono_tz::Europe::Amsterdam,
+    /// )?;
+    /// ```
+    pub fn from_local(local: NaiveDateTime, time_zone: Tz) -> Result<Self, GoogleApiError> {
+        let date_time = time_zone
+            .from_local_datetime(&local)
+            .earliest()
+            .ok_or_else(|| {
+                GoogleApiError::InvalidRequest(format!(
+                    "{local} does not exist in {}",
+                    time_zone.name()
+                ))
+            })?;
+        Ok(Self::zoned(date_time))
+    }
+
+    /// Whether this is the date of an all-day event.
+    pub fn is_all_day(&self) -> bool {
+        self.date.is_some()
+    }
+
+    /// The date of an all-day event, `None` for timed events.
+    pub fn naive_date(&self) -> Option<NaiveDate> {
+        self.date
+    }
+
+    /// The IANA time zone, `None` if it is not set or unknown.
+    pub fn tz(&self) -> Option<Tz> {
+        self.time_zone.as_deref()?.parse().ok()
+    }
+
+    /// The date-time of a timed event in its IANA time zone. `None` when `time_zone` is not
+    /// set or unknown, `date_time` still has the UTC offset of the event.
+    pub fn zoned_date_time(&self) -> Option<DateTime<Tz>> {
+        Some(self.date_time?.with_timezone(&self.tz()?))
+    }
+
+    /// The moment in the time zone of the user. All-day dates have no time zone, they start at
+    /// midnight in every time zone.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let local = event.start.unwrap().to_local(&chrono::Local);
+    /// ```
+    pub fn to_local<T: TimeZone>(&self, time_zone: &T) -> Option<DateTime<T>> {
+        if let Some(date) = self.naive_date() {
+            let midnight = date.and_time(NaiveTime::MIN);
+            return time_zone
+                .from_local_datetime(&midnight)
+                .earliest()
+                // Midnight is skipped by DST in some time zones, the day starts an hour later
+                .or_else(|| {
+                    time_zone
+                        .from_local_datetime(&(midnight + Duration::hours(1)))
+                        .earliest()
+                });
+        }
+        self.date_time
+            .map(|date_time| date_time.with_timezone(time_zone))
+    }
+
+    /// The calendar date in the time zone of the user.
+    pub fn local_date<T: TimeZone>(&self, time_zone: &T) -> Option<NaiveDate> {
+        self.naive_date()
+            .or_else(|| self.to_local(time_zone).map(|local| local.date_naive()))
+    }
+
+    /// Checks that exactly one of `date` and `date_time` is set and that `time_zone` is a
+    /// known IANA time zone. Invalid dates are already rejected when deserializing.
+    pub fn validate(&self) -> Result<(), GoogleApiError> {
+        let invalid = |message: String| Err(GoogleApiError::InvalidRequest(message));
+        match (&self.date, &self.date_time) {
+            (Some(_), Some(_)) => return invalid("both date and dateTime are set".to_string()),
+            (None, None) => return invalid("one of date or dateTime must be set".to_string()),
+            _ => {}
+        }
+        match &self.time_zone {
+            Some(time_zone) if self.tz().is_none() => {
+                invalid(format!("unknown time zone {time_zone}"))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
 impl CreateEventRequest {
     /// Creates a new instance of `ExtendedProperties` with empty maps
     pub fn new(start: EventDateTime, end: EventDateTime) -> Self {
@@ -1380,6 +1757,8 @@ impl CreateEventRequest {
             id: None,
             ical_uid: None,
             location: None,
+            organizer: None,
+            original_start_time: None,
             out_of_office_properties: None,
             recurrence: vec![],
             reminders: None,
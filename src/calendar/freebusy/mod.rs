@@ -0,0 +1,4 @@
+#[cfg(test)]
+mod request_tests;
+pub mod requests;
+pub mod types;
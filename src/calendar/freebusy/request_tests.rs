@@ -0,0 +1,169 @@
+#![allow(clippy::unwrap_used)]
+
+use chrono::{DateTime, Duration, FixedOffset, NaiveTime, Utc, Weekday};
+
+use super::{
+    requests::FreeBusyClient,
+    types::{
+        free_slots, free_slots_in_working_hours, merge_busy, FreeBusyResponse, TimePeriod,
+        WorkingHours,
+    },
+};
+use crate::{
+    error::GoogleApiError,
+    utils::{request::TimeRequestTrait, test_support::client},
+};
+
+fn at(s: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+}
+
+fn period(start: &str, end: &str) -> TimePeriod {
+    TimePeriod::new(at(start), at(end))
+}
+
+#[tokio::test]
+async fn query_posts_items_and_range_and_merges_calendars() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/calendar/v3/freeBusy")
+        .match_body(mockito::Matcher::Json(serde_json::json!({
+            "timeMin": "2025-03-03T09:00:00Z",
+            "timeMax": "2025-03-03T17:00:00Z",
+            "items": [{"id": "primary"}, {"id": "jane@example.com"}, {"id": "team@example.com"}]
+        })))
+        .with_status(200)
+        .with_body(
+            r#"{
+                "kind": "calendar#freeBusy",
+                "timeMin": "2025-03-03T09:00:00.000Z",
+                "timeMax": "2025-03-03T17:00:00.000Z",
+                "groups": {"team@example.com": {"calendars": ["jane@example.com"]}},
+                "calendars": {
+                    "primary": {"busy": [{"start": "2025-03-03T10:00:00Z", "end": "2025-03-03T11:00:00Z"}]},
+                    "jane@example.com": {"busy": [{"start": "2025-03-03T10:30:00Z", "end": "2025-03-03T12:00:00Z"}]},
+                    "missing@example.com": {"errors": [{"domain": "global", "reason": "notFound"}]}
+                }
+            }"#,
+        )
+        .create_async()
+        .await;
+
+    let gc = client(&server.url());
+    let response = FreeBusyClient::new(&gc)
+        .query()
+        .calendars(&["primary", "jane@example.com"])
+        .groups(&["team@example.com"])
+        .time_min(at("2025-03-03T09:00:00Z"))
+        .time_max(at("2025-03-03T17:00:00Z"))
+        .request()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(
+        response.groups["team@example.com"].calendars,
+        vec!["jane@example.com"]
+    );
+    assert_eq!(
+        response.calendars["missing@example.com"].errors[0].reason,
+        "notFound"
+    );
+    assert_eq!(
+        response.merged_busy(),
+        vec![period("2025-03-03T10:00:00Z", "2025-03-03T12:00:00Z")]
+    );
+    assert_eq!(
+        response.failures(),
+        vec![(
+            "missing@example.com".to_string(),
+            vec!["notFound".to_string()]
+        )]
+    );
+    match response.free_slots(Duration::minutes(30)) {
+        Err(GoogleApiError::PartialFailure(failures)) => {
+            assert_eq!(failures, response.failures())
+        }
+        other => panic!("expected PartialFailure, got {other:?}"),
+    }
+    mock.assert_async().await;
+}
+
+#[test]
+fn response_free_slots_cover_the_queried_range() {
+    let response: FreeBusyResponse = serde_json::from_str(
+        r#"{
+            "timeMin": "2025-03-03T09:00:00Z",
+            "timeMax": "2025-03-03T17:00:00Z",
+            "calendars": {
+                "primary": {"busy": [{"start": "2025-03-03T10:00:00Z", "end": "2025-03-03T11:00:00Z"}]},
+                "jane@example.com": {"busy": [{"start": "2025-03-03T10:30:00Z", "end": "2025-03-03T12:00:00Z"}]}
+            }
+        }"#,
+    )
+    .unwrap();
+
+    assert!(response.failures().is_empty());
+    assert_eq!(
+        response.free_slots(Duration::minutes(30)).unwrap(),
+        vec![
+            period("2025-03-03T09:00:00Z", "2025-03-03T10:00:00Z"),
+            period("2025-03-03T12:00:00Z", "2025-03-03T17:00:00Z"),
+        ]
+    );
+}
+
+#[test]
+fn merge_busy_joins_overlapping_and_adjacent_periods() {
+    let merged = merge_busy(vec![
+        period("2025-03-03T13:00:00Z", "2025-03-03T14:00:00Z"),
+        period("2025-03-03T09:00:00Z", "2025-03-03T10:00:00Z"),
+        period("2025-03-03T10:00:00Z", "2025-03-03T10:30:00Z"),
+        period("2025-03-03T09:15:00Z", "2025-03-03T09:45:00Z"),
+    ]);
+
+    assert_eq!(
+        merged,
+        vec![
+            period("2025-03-03T09:00:00Z", "2025-03-03T10:30:00Z"),
+            period("2025-03-03T13:00:00Z", "2025-03-03T14:00:00Z"),
+        ]
+    );
+}
+
+#[test]
+fn free_slots_drop_gaps_shorter_than_min_duration() {
+    let busy = [
+        period("2025-03-03T08:00:00Z", "2025-03-03T09:10:00Z"),
+        period("2025-03-03T09:30:00Z", "2025-03-03T11:00:00Z"),
+    ];
+    let range = period("2025-03-03T09:00:00Z", "2025-03-03T12:00:00Z");
+
+    assert_eq!(
+        free_slots(&busy, range, Duration::minutes(30)),
+        vec![period("2025-03-03T11:00:00Z", "2025-03-03T12:00:00Z")]
+    );
+}
+
+#[test]
+fn free_slots_respect_working_hours_and_time_zone() {
+    // 9:00 to 17:00 in UTC+2, so 7:00 to 15:00 UTC, on Monday and Tuesday only
+    let working_hours = WorkingHours::new(
+        NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+        NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+        FixedOffset::east_opt(2 * 3600).unwrap(),
+    )
+    .days(vec![Weekday::Mon, Weekday::Tue]);
+    let busy = [period("2025-03-03T08:00:00Z", "2025-03-03T14:00:00Z")];
+    // Monday 2025-03-03 until Thursday
+    let range = period("2025-03-03T00:00:00Z", "2025-03-06T00:00:00Z");
+
+    assert_eq!(
+        free_slots_in_working_hours(&busy, range, Duration::minutes(45), &working_hours),
+        vec![
+            period("2025-03-03T07:00:00Z", "2025-03-03T08:00:00Z"),
+            period("2025-03-03T14:00:00Z", "2025-03-03T15:00:00Z"),
+            period("2025-03-04T07:00:00Z", "2025-03-04T15:00:00Z"),
+        ]
+    );
+}
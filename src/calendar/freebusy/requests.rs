@@ -0,0 +1,138 @@
+use chrono::DateTime;
+use reqwest::Method;
+
+use crate::{
+    auth::client::GoogleClient,
+    error::GoogleApiError,
+    utils::request::{Request, TimeRequestTrait},
+};
+
+use super::types::{FreeBusyRequest, FreeBusyRequestItem, FreeBusyResponse};
+
+/// Indicates that the request builder is not yet initialized with a specific mode.
+pub struct Uninitialized;
+/// Indicates that the request builder is initialized for a free/busy query.
+/// This struct determines which filters can be applied to the request.
+pub struct FreeBusyQueryMode;
+
+/// Client for the free/busy information of calendars.
+///
+/// The generic type parameter `T` determines the mode of operation for this client,
+/// which affects which methods are available and what parameters can be set.
+pub struct FreeBusyClient<'a, T = Uninitialized> {
+    pub(super) request: Request<'a>,
+    pub(super) query: Option<FreeBusyRequest>,
+    pub(super) _mode: std::marker::PhantomData<T>,
+}
+
+impl<'a> FreeBusyClient<'a, Uninitialized> {
+    /// Creates a new free/busy client using the provided Google client for authentication.
+    pub fn new(client: &'a GoogleClient) -> Self {
+        Self {
+            request: Request::new(client),
+            query: None,
+            _mode: std::marker::PhantomData,
+        }
+    }
+
+    /// Query when the given calendars are busy.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let start = chrono::Utc::now();
+    /// let response = FreeBusyClient::new(&client)
+    ///     .query()
+    ///     .calendars(&["primary", "jane@example.com"])
+    ///     .time_min(start)
+    ///     .time_max(start + chrono::Duration::days(7))
+    ///     .request()
+    ///     .await?
+    ///     .unwrap();
+    ///
+    /// // Half hour slots in which both calendars are free
+    /// let slots = response.free_slots(chrono::Duration::minutes(30))?;
+    /// ```
+    pub fn query(self) -> FreeBusyClient<'a, FreeBusyQueryMode> {
+        let mut builder = FreeBusyClient {
+            request: self.request,
+            query: Some(FreeBusyRequest::default()),
+            _mode: std::marker::PhantomData,
+        };
+        builder.request.url = format!("{}/freeBusy", builder.request.client.endpoints.calendar);
+        builder.request.method = Method::POST;
+        builder
+    }
+}
+
+impl<'a> TimeRequestTrait for FreeBusyClient<'a, FreeBusyQueryMode> {
+    /// The start of the interval to query, required.
+    fn time_min(self, time_min: DateTime<chrono::Utc>) -> Self {
+        self.modify_query(|query| query.time_min = Some(time_min))
+    }
+
+    /// The end of the interval to query, required.
+    fn time_max(self, time_max: DateTime<chrono::Utc>) -> Self {
+        self.modify_query(|query| query.time_max = Some(time_max))
+    }
+}
+
+impl<'a> FreeBusyClient<'a, FreeBusyQueryMode> {
+    /// Add calendars to the query, "primary" can be used for the calendar of the user.
+    pub fn calendars(self, calendar_ids: &[&str]) -> Self {
+        self.add_items(calendar_ids)
+    }
+
+    /// Add groups to the query. Google expands them into their member calendars, listed in
+    /// `FreeBusyResponse::groups`.
+    pub fn groups(self, group_ids: &[&str]) -> Self {
+        self.add_items(group_ids)
+    }
+
+    /// Time zone used in the response, defaults to UTC.
+    pub fn time_zone(self, time_zone: &str) -> Self {
+        self.modify_query(|query| query.time_zone = Some(time_zone.to_string()))
+    }
+
+    /// Maximal number of calendars returned for a single group, at most 100.
+    pub fn group_expansion_max(self, max: i64) -> Self {
+        self.modify_query(|query| query.group_expansion_max = Some(max))
+    }
+
+    /// Maximal number of calendars for which information is returned, at most 50.
+    pub fn calendar_expansion_max(self, max: i64) -> Self {
+        self.modify_query(|query| query.calendar_expansion_max = Some(max))
+    }
+
+    /// Executes the free/busy query.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(FreeBusyResponse))` - The busy periods per calendar if successful
+    /// * `Err(GoogleApiError)` - If the request failed or Google returned a non-2xx status
+    pub async fn request(&mut self) -> Result<Option<FreeBusyResponse>, GoogleApiError> {
+        if let Some(query) = &self.query {
+            self.request.set_json_body(query)?;
+        }
+        self.request.execute().await.map(Some)
+    }
+
+    fn add_items(self, ids: &[&str]) -> Self {
+        self.modify_query(|query| {
+            query.items.extend(
+                ids.iter()
+                    .map(|id| FreeBusyRequestItem { id: id.to_string() }),
+            )
+        })
+    }
+
+    fn modify_query<F>(mut self, modifier: F) -> Self
+    where
+        F: FnOnce(&mut FreeBusyRequest),
+    {
+        if let Some(ref mut query) = self.query {
+            modifier(query);
+        }
+        self
+    }
+}
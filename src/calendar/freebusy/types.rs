@@ -0,0 +1,377 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, NaiveTime, TimeZone, Utc, Weekday};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::GoogleApiError;
+
+/// Body of a free/busy query.
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FreeBusyRequest {
+    /// The start of the interval for the query
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_min: Option<DateTime<Utc>>,
+
+    /// The end of the interval for the query
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_max: Option<DateTime<Utc>>,
+
+    /// Time zone used in the response, defaults to UTC
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_zone: Option<String>,
+
+    /// Maximal number of calendar identifiers to be provided for a single group
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_expansion_max: Option<i64>,
+
+    /// Maximal number of calendars for which free/busy information is to be provided
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub calendar_expansion_max: Option<i64>,
+
+    /// Calendars and/or groups to query
+    #[serde(default)]
+    pub items: Vec<FreeBusyRequestItem>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Clone, PartialEq)]
+pub struct FreeBusyRequestItem {
+    /// The identifier of a calendar or a group
+    pub id: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
+pub struct FreeBusyResponse {
+    /**
+     * Type of the resource ("calendar#freeBusy").
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
+    )]
+    pub kind: String,
+
+    /**
+     * The start of the interval.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::deserialize::deserialize_date_time_format::deserialize",
+        serialize_with = "crate::utils::serialize::deserialize_date_time_format::serialize",
+        rename = "timeMin"
+    )]
+    pub time_min: Option<DateTime<Utc>>,
+
+    /**
+     * The end of the interval.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::deserialize::deserialize_date_time_format::deserialize",
+        serialize_with = "crate::utils::serialize::deserialize_date_time_format::serialize",
+        rename = "timeMax"
+    )]
+    pub time_max: Option<DateTime<Utc>>,
+
+    /**
+     * Expansion of groups, keyed by group ID.
+     */
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub groups: HashMap<String, FreeBusyGroup>,
+
+    /**
+     * List of free/busy information for calendars, keyed by calendar ID.
+     */
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub calendars: HashMap<String, FreeBusyCalendar>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
+pub struct FreeBusyGroup {
+    /**
+     * Optional error(s) if computation for the group failed.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_vec::deserialize"
+    )]
+    pub errors: Vec<FreeBusyError>,
+
+    /**
+     * List of calendars' identifiers within a group.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_vec::deserialize"
+    )]
+    pub calendars: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
+pub struct FreeBusyCalendar {
+    /**
+     * Optional error(s) if computation for the calendar failed, e.g. "notFound".
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_vec::deserialize"
+    )]
+    pub errors: Vec<FreeBusyError>,
+
+    /**
+     * List of time ranges during which this calendar should be regarded as busy.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_vec::deserialize"
+    )]
+    pub busy: Vec<TimePeriod>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
+pub struct FreeBusyError {
+    /**
+     * Domain, or broad category, of the error.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
+    )]
+    pub domain: String,
+
+    /**
+     * Specific reason for the error, e.g. "notFound", "groupTooBig", "tooManyCalendarsRequested".
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
+    )]
+    pub reason: String,
+}
+
+/// A time range, the start is inclusive and the end exclusive.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, Copy, JsonSchema)]
+pub struct TimePeriod {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+impl TimePeriod {
+    pub fn new(start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        Self { start, end }
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.end - self.start
+    }
+}
+
+/// The hours in which free slots may be proposed, e.g. 9:00 to 17:00 on weekdays.
+///
+/// The hours are interpreted in `time_zone`, which can be any chrono time zone like `Utc`,
+/// `FixedOffset` or `Local`.
+#[derive(Debug, Clone)]
+pub struct WorkingHours<Tz: TimeZone> {
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+    pub days: Vec<Weekday>,
+    pub time_zone: Tz,
+}
+
+impl<Tz: TimeZone> WorkingHours<Tz> {
+    /// Working hours from `start` to `end` on Monday to Friday.
+    pub fn new(start: NaiveTime, end: NaiveTime, time_zone: Tz) -> Self {
+        Self {
+            start,
+            end,
+            days: vec![
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Fri,
+            ],
+            time_zone,
+        }
+    }
+
+    /// Use other working days than Monday to Friday.
+    pub fn days(mut self, days: Vec<Weekday>) -> Self {
+        self.days = days;
+        self
+    }
+
+    /// Returns the working hour windows that overlap `range`, clipped to it.
+    fn windows(&self, range: TimePeriod) -> Vec<TimePeriod> {
+        let first_day = range.start.with_timezone(&self.time_zone).date_naive();
+        let last_day = range.end.with_timezone(&self.time_zone).date_naive();
+        first_day
+            .iter_days()
+            .take_while(|day| *day <= last_day)
+            .filter(|day| self.days.contains(&chrono::Datelike::weekday(day)))
+            .filter_map(|day| {
+                // Days where a boundary falls in a DST gap are skipped
+                let start = self
+                    .time_zone
+                    .from_local_datetime(&day.and_time(self.start))
+                    .earliest()?
+                    .with_timezone(&Utc);
+                let end = self
+                    .time_zone
+                    .from_local_datetime(&day.and_time(self.end))
+                    .latest()?
+                    .with_timezone(&Utc);
+                let window = TimePeriod::new(start.max(range.start), end.min(range.end));
+                (window.start < window.end).then_some(window)
+            })
+            .collect()
+    }
+}
+
+/// Sorts the busy periods and merges the ones that overlap or touch.
+pub fn merge_busy(periods: impl IntoIterator<Item = TimePeriod>) -> Vec<TimePeriod> {
+    let mut periods: Vec<TimePeriod> = periods.into_iter().filter(|p| p.start < p.end).collect();
+    periods.sort_by_key(|p| p.start);
+
+    let mut merged: Vec<TimePeriod> = Vec::with_capacity(periods.len());
+    for period in periods {
+        match merged.last_mut() {
+            Some(last) if period.start <= last.end => last.end = last.end.max(period.end),
+            _ => merged.push(period),
+        }
+    }
+    merged
+}
+
+/// Returns the free periods of at least `min_duration` within `range`, given the busy periods
+/// of one or more calendars.
+pub fn free_slots(
+    busy: &[TimePeriod],
+    range: TimePeriod,
+    min_duration: Duration,
+) -> Vec<TimePeriod> {
+    let mut free = Vec::new();
+    let mut cursor = range.start;
+    for period in merge_busy(busy.iter().copied()) {
+        if period.end <= cursor {
+            continue;
+        }
+        if period.start >= range.end {
+            break;
+        }
+        if period.start > cursor {
+            free.push(TimePeriod::new(cursor, period.start));
+        }
+        cursor = period.end;
+    }
+    if cursor < range.end {
+        free.push(TimePeriod::new(cursor, range.end));
+    }
+    free.retain(|slot| slot.duration() >= min_duration);
+    free
+}
+
+/// Like `free_slots`, but only returns free periods within the working hours.
+pub fn free_slots_in_working_hours<Tz: TimeZone>(
+    busy: &[TimePeriod],
+    range: TimePeriod,
+    min_duration: Duration,
+    working_hours: &WorkingHours<Tz>,
+) -> Vec<TimePeriod> {
+    working_hours
+        .windows(range)
+        .into_iter()
+        .flat_map(|window| free_slots(busy, window, min_duration))
+        .collect()
+}
+
+impl FreeBusyResponse {
+    /// The busy periods of all calendars in the response, merged into one timeline.
+    pub fn merged_busy(&self) -> Vec<TimePeriod> {
+        merge_busy(
+            self.calendars
+                .values()
+                .flat_map(|calendar| calendar.busy.iter().copied()),
+        )
+    }
+
+    /// The calendars and groups whose free/busy information could not be computed, with the
+    /// reasons of their errors, sorted by id.
+    pub fn failures(&self) -> Vec<(String, Vec<String>)> {
+        let mut failures: Vec<_> = self
+            .calendars
+            .iter()
+            .map(|(id, calendar)| (id, &calendar.errors))
+            .chain(self.groups.iter().map(|(id, group)| (id, &group.errors)))
+            .filter(|(_, errors)| !errors.is_empty())
+            .map(|(id, errors)| {
+                let reasons = errors.iter().map(|e| e.reason.clone()).collect();
+                (id.clone(), reasons)
+            })
+            .collect();
+        failures.sort();
+        failures
+    }
+
+    /// The periods of at least `min_duration` in which all queried calendars are free,
+    /// within the queried time range.
+    ///
+    /// Returns `GoogleApiError::PartialFailure` if the busy periods of any calendar or group are
+    /// unknown, see `failures`. Use `merged_busy` with the `free_slots` function to compute the
+    /// slots of the remaining calendars anyway.
+    pub fn free_slots(&self, min_duration: Duration) -> Result<Vec<TimePeriod>, GoogleApiError> {
+        self.check_failures()?;
+        Ok(match self.range() {
+            Some(range) => free_slots(&self.merged_busy(), range, min_duration),
+            None => Vec::new(),
+        })
+    }
+
+    /// Like `free_slots`, but only returns free periods within the working hours.
+    ///
+    /// # Example
+    /// ```rust
+    /// let working_hours = WorkingHours::new(
+    ///     NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+    ///     NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+    ///     FixedOffset::east_opt(2 * 3600).unwrap(),
+    /// );
+    /// let slots = response.free_slots_in_working_hours(Duration::minutes(30), &working_hours)?;
+    /// ```
+    pub fn free_slots_in_working_hours<Tz: TimeZone>(
+        &self,
+        min_duration: Duration,
+        working_hours: &WorkingHours<Tz>,
+    ) -> Result<Vec<TimePeriod>, GoogleApiError> {
+        self.check_failures()?;
+        Ok(match self.range() {
+            Some(range) => {
+                free_slots_in_working_hours(&self.merged_busy(), range, min_duration, working_hours)
+            }
+            None => Vec::new(),
+        })
+    }
+
+    fn check_failures(&self) -> Result<(), GoogleApiError> {
+        let failures = self.failures();
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(GoogleApiError::PartialFailure(failures))
+        }
+    }
+
+    fn range(&self) -> Option<TimePeriod> {
+        Some(TimePeriod::new(self.time_min?, self.time_max?))
+    }
+}
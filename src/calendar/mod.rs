@@ -1,8 +1,19 @@
+pub mod acl;
+pub mod calendar_list;
+pub mod calendars;
+pub mod channels;
+pub mod colors;
 pub mod events;
+pub mod freebusy;
 pub mod requests;
+pub mod settings;
 pub mod prelude {
+    pub use crate::calendar::calendar_list::requests::AccessRole;
     pub use crate::calendar::events::requests::EventOrderBy;
     pub use crate::calendar::events::requests::EventType;
+    pub use crate::calendar::events::requests::ResponseStatus;
+    pub use crate::calendar::events::requests::SendUpdates;
+    pub use crate::calendar::events::types::{EventStatus, Transparency, Visibility};
     pub use crate::utils::request::PaginationRequestTrait;
     pub use crate::utils::request::TimeRequestTrait;
 }
@@ -0,0 +1,132 @@
+#![allow(clippy::unwrap_used)]
+
+use chrono::{Duration, Weekday};
+
+use super::{
+    requests::{SettingId, SettingsClient},
+    types::{Setting, UserSettings},
+};
+use crate::utils::test_support::client;
+
+fn setting(id: &str, value: &str) -> Setting {
+    Setting {
+        id: id.into(),
+        value: value.into(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn well_known_settings_are_typed() {
+    let settings = UserSettings::from_settings(&[
+        setting("timezone", "Europe/Amsterdam"),
+        setting("weekStart", "1"),
+        setting("defaultEventLength", "30"),
+        setting("format24HourTime", "true"),
+        setting("hideWeekends", "not a bool"),
+        setting("somethingNew", "x"),
+    ]);
+
+    assert_eq!(settings.timezone.as_deref(), Some("Europe/Amsterdam"));
+    assert_eq!(settings.week_start, Some(Weekday::Mon));
+    assert_eq!(settings.default_event_length, Some(Duration::minutes(30)));
+    assert_eq!(settings.format_24_hour_time, Some(true));
+    assert_eq!(settings.hide_weekends, None);
+    assert_eq!(settings.locale, None);
+    assert_eq!(settings.other.get("somethingNew").unwrap(), "x");
+}
+
+#[tokio::test]
+async fn list_follows_pages() {
+    let mut server = mockito::Server::new_async().await;
+    let first = server
+        .mock("GET", "/calendar/v3/users/me/settings")
+        .match_query(mockito::Matcher::Missing)
+        .with_status(200)
+        .with_body(
+            r#"{"items": [{"id": "timezone", "value": "Europe/Amsterdam"}], "nextPageToken": "page2"}"#,
+        )
+        .create_async()
+        .await;
+    let second = server
+        .mock("GET", "/calendar/v3/users/me/settings")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "pageToken".into(),
+            "page2".into(),
+        ))
+        .with_status(200)
+        .with_body(r#"{"items": [{"id": "weekStart", "value": "0"}]}"#)
+        .create_async()
+        .await;
+
+    let gc = client(&server.url());
+    let settings = SettingsClient::new(&gc)
+        .get_settings()
+        .collect_all()
+        .await
+        .unwrap();
+    let settings = UserSettings::from_settings(&settings);
+
+    assert_eq!(settings.timezone.as_deref(), Some("Europe/Amsterdam"));
+    assert_eq!(settings.week_start, Some(Weekday::Sun));
+    first.assert_async().await;
+    second.assert_async().await;
+}
+
+#[tokio::test]
+async fn list_stops_at_max_items() {
+    let mut server = mockito::Server::new_async().await;
+    let first = server
+        .mock("GET", "/calendar/v3/users/me/settings")
+        .match_query(mockito::Matcher::Missing)
+        .with_status(200)
+        .with_body(
+            r#"{"items": [{"id": "timezone", "value": "Europe/Amsterdam"}, {"id": "locale", "value": "nl"}], "nextPageToken": "page2"}"#,
+        )
+        .create_async()
+        .await;
+    let second = server
+        .mock("GET", "/calendar/v3/users/me/settings")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "pageToken".into(),
+            "page2".into(),
+        ))
+        .expect(0)
+        .create_async()
+        .await;
+
+    let gc = client(&server.url());
+    let settings = SettingsClient::new(&gc)
+        .get_settings()
+        .max_items(1)
+        .collect_all()
+        .await
+        .unwrap();
+
+    assert_eq!(settings.len(), 1);
+    assert_eq!(settings[0].id, "timezone");
+    first.assert_async().await;
+    second.assert_async().await;
+}
+
+#[tokio::test]
+async fn get_setting_by_id() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/calendar/v3/users/me/settings/weekStart")
+        .with_status(200)
+        .with_body(r#"{"kind": "calendar#setting", "id": "weekStart", "value": "6"}"#)
+        .create_async()
+        .await;
+
+    let gc = client(&server.url());
+    let setting = SettingsClient::new(&gc)
+        .get_setting(SettingId::WeekStart)
+        .request()
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(setting.as_weekday(), Some(Weekday::Sat));
+    mock.assert_async().await;
+}
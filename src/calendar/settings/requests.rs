@@ -0,0 +1,184 @@
+use futures::{Stream, TryStreamExt};
+use reqwest::Method;
+
+use crate::{
+    auth::client::GoogleClient,
+    error::GoogleApiError,
+    utils::{
+        pagination::{paginate, Page},
+        request::{PaginationRequestTrait, Request},
+    },
+};
+
+use super::types::{Setting, Settings};
+
+/// Indicates that the request builder is not yet initialized with a specific mode.
+pub struct Uninitialized;
+/// Indicates that the request builder is initialized for listing the settings of the user.
+pub struct SettingsListMode;
+/// Indicates that the request builder is initialized for retrieving a single setting.
+pub struct SettingsGetMode;
+
+/** The settings documented by Google.
+* See [Google Calendar API
+* documentation](https://developers.google.com/calendar/api/v3/reference/settings)
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingId {
+    AutoAddHangouts,
+    DateFieldOrder,
+    DefaultEventLength,
+    Format24HourTime,
+    HideInvitations,
+    HideWeekends,
+    Locale,
+    RemindOnRespondedEventsOnly,
+    ShowDeclinedEvents,
+    Timezone,
+    UseKeyboardShortcuts,
+    WeekStart,
+}
+
+impl SettingId {
+    pub fn as_str(&self) -> &str {
+        match self {
+            SettingId::AutoAddHangouts => "autoAddHangouts",
+            SettingId::DateFieldOrder => "dateFieldOrder",
+            SettingId::DefaultEventLength => "defaultEventLength",
+            SettingId::Format24HourTime => "format24HourTime",
+            SettingId::HideInvitations => "hideInvitations",
+            SettingId::HideWeekends => "hideWeekends",
+            SettingId::Locale => "locale",
+            SettingId::RemindOnRespondedEventsOnly => "remindOnRespondedEventsOnly",
+            SettingId::ShowDeclinedEvents => "showDeclinedEvents",
+            SettingId::Timezone => "timezone",
+            SettingId::UseKeyboardShortcuts => "useKeyboardShortcuts",
+            SettingId::WeekStart => "weekStart",
+        }
+    }
+}
+
+/// Client for the Google Calendar settings of the authenticated user, like the time zone and
+/// the first day of the week.
+///
+/// The generic type parameter `T` determines the mode of operation for this client,
+/// which affects which methods are available and what parameters can be set.
+pub struct SettingsClient<'a, T = Uninitialized> {
+    pub(super) request: Request<'a>,
+    pub(super) _mode: std::marker::PhantomData<T>,
+}
+
+impl<'a> SettingsClient<'a, Uninitialized> {
+    /// Creates a new settings client using the provided Google client for authentication.
+    pub fn new(client: &'a GoogleClient) -> Self {
+        Self {
+            request: Request::new(client),
+            _mode: std::marker::PhantomData,
+        }
+    }
+
+    /// Get all settings of the user.
+    pub fn get_settings(self) -> SettingsClient<'a, SettingsListMode> {
+        self.into_mode(None)
+    }
+
+    /// Get a single setting.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let week_start = SettingsClient::new(&client)
+    ///     .get_setting(SettingId::WeekStart)
+    ///     .request()
+    ///     .await?
+    ///     .and_then(|setting| setting.as_weekday());
+    /// ```
+    pub fn get_setting(self, setting: SettingId) -> SettingsClient<'a, SettingsGetMode> {
+        self.into_mode(Some(setting.as_str()))
+    }
+
+    fn into_mode<M>(self, setting_id: Option<&str>) -> SettingsClient<'a, M> {
+        let mut builder = SettingsClient {
+            request: self.request,
+            _mode: std::marker::PhantomData,
+        };
+        builder.request.url = match setting_id {
+            Some(id) => format!(
+                "{}/users/me/settings/{id}",
+                builder.request.client.endpoints.calendar
+            ),
+            None => format!(
+                "{}/users/me/settings",
+                builder.request.client.endpoints.calendar
+            ),
+        };
+        builder.request.method = Method::GET;
+        builder
+    }
+}
+
+impl<'a> PaginationRequestTrait for SettingsClient<'a, SettingsListMode> {
+    /// Maximum number of settings to return per page.
+    fn max_results(mut self, max: i64) -> Self {
+        self.request
+            .params
+            .insert("maxResults".to_string(), max.to_string());
+        self
+    }
+
+    /// Page token for pagination. Works with `max_results`.
+    fn page_token(mut self, token: &str) -> Self {
+        self.request
+            .params
+            .insert("pageToken".to_string(), token.to_string());
+        self
+    }
+}
+
+impl<'a> SettingsClient<'a, SettingsListMode> {
+    /// Returns a request result for getting one page of settings.
+    pub async fn request(&mut self) -> Result<Option<Settings>, GoogleApiError> {
+        self.request.execute().await.map(Some)
+    }
+
+    /// Stop `stream` and `collect_all` after this many settings in total.
+    pub fn max_items(mut self, max: usize) -> Self {
+        self.request.max_items = Some(max);
+        self
+    }
+
+    /// Returns a stream of all settings, following `nextPageToken` until the last page or
+    /// until `max_items` settings have been yielded.
+    pub fn stream(self) -> impl Stream<Item = Result<Setting, GoogleApiError>> + 'a {
+        paginate::<Settings>(self.request)
+    }
+
+    /// Fetches every page and returns all settings.
+    pub async fn collect_all(self) -> Result<Vec<Setting>, GoogleApiError> {
+        self.stream().try_collect().await
+    }
+}
+
+impl<'a> SettingsClient<'a, SettingsGetMode> {
+    /// Executes the request to get the setting.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(Setting))` - The setting if successful
+    /// * `Err(GoogleApiError)` - If the request failed or Google returned a non-2xx status
+    pub async fn request(&mut self) -> Result<Option<Setting>, GoogleApiError> {
+        self.request.execute().await.map(Some)
+    }
+}
+
+impl Page for Settings {
+    type Item = Setting;
+
+    fn next_page_token(&self) -> Option<&str> {
+        (!self.next_page_token.is_empty()).then_some(self.next_page_token.as_str())
+    }
+
+    fn into_items(self) -> Vec<Setting> {
+        self.items
+    }
+}
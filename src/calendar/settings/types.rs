@@ -0,0 +1,204 @@
+use std::collections::HashMap;
+
+use chrono::{Duration, Weekday};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
+pub struct Settings {
+    /**
+     * Type of the collection ("calendar#settings").
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
+    )]
+    pub kind: String,
+
+    /**
+     * ETag of the collection.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
+    )]
+    pub etag: String,
+
+    /**
+     * Token used to access the next page of this result.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize",
+        rename = "nextPageToken"
+    )]
+    pub next_page_token: String,
+
+    /**
+     * Token used at a later point in time to retrieve only the entries that have changed since
+     * this result was returned.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize",
+        rename = "nextSyncToken"
+    )]
+    pub next_sync_token: String,
+
+    /**
+     * List of user settings.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_vec::deserialize"
+    )]
+    pub items: Vec<Setting>,
+}
+
+/// A single user setting. The value is always a string, use the typed accessors or
+/// `UserSettings` to interpret it.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
+pub struct Setting {
+    /**
+     * Type of the resource ("calendar#setting").
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
+    )]
+    pub kind: String,
+
+    /**
+     * ETag of the resource.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
+    )]
+    pub etag: String,
+
+    /**
+     * The ID of the setting, e.g. "timezone".
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
+    )]
+    pub id: String,
+
+    /**
+     * Value of the setting.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
+    )]
+    pub value: String,
+}
+
+impl Setting {
+    /// The value of a "true"/"false" setting.
+    pub fn as_bool(&self) -> Option<bool> {
+        self.value.parse().ok()
+    }
+
+    /// The value of the `weekStart` setting, "0" is Sunday, "1" Monday and "6" Saturday.
+    pub fn as_weekday(&self) -> Option<Weekday> {
+        match self.value.as_str() {
+            "0" => Some(Weekday::Sun),
+            "1" => Some(Weekday::Mon),
+            "6" => Some(Weekday::Sat),
+            _ => None,
+        }
+    }
+
+    /// The value of a setting given in minutes, like `defaultEventLength`.
+    pub fn as_minutes(&self) -> Option<Duration> {
+        self.value.parse().ok().map(Duration::minutes)
+    }
+}
+
+/// The settings of the user that are documented by Google, with typed values.
+///
+/// Settings that are missing, or have a value that can't be parsed, are `None`.
+///
+/// # Example
+/// ```rust
+/// let settings = SettingsClient::new(&client).get_settings().collect_all().await?;
+/// let settings = UserSettings::from_settings(&settings);
+/// let week_start = settings.week_start.unwrap_or(chrono::Weekday::Mon);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct UserSettings {
+    /// `timezone`, the IANA time zone of the primary calendar, e.g. "Europe/Amsterdam".
+    pub timezone: Option<String>,
+    /// `weekStart`
+    pub week_start: Option<Weekday>,
+    /// `defaultEventLength`, the length of new events.
+    pub default_event_length: Option<Duration>,
+    /// `format24HourTime`
+    pub format_24_hour_time: Option<bool>,
+    /// `locale`, e.g. "en" or "nl".
+    pub locale: Option<String>,
+    /// `dateFieldOrder`, e.g. "MDY" or "DMY".
+    pub date_field_order: Option<String>,
+    /// `hideWeekends`
+    pub hide_weekends: Option<bool>,
+    /// `showDeclinedEvents`
+    pub show_declined_events: Option<bool>,
+    /// `hideInvitations`
+    pub hide_invitations: Option<bool>,
+    /// `remindOnRespondedEventsOnly`
+    pub remind_on_responded_events_only: Option<bool>,
+    /// `autoAddHangouts`
+    pub auto_add_hangouts: Option<bool>,
+    /// `useKeyboardShortcuts`
+    pub use_keyboard_shortcuts: Option<bool>,
+    /// Settings that aren't one of the fields above, by ID.
+    pub other: HashMap<String, String>,
+}
+
+impl UserSettings {
+    pub fn from_settings(settings: &[Setting]) -> Self {
+        let mut user = Self::default();
+        for setting in settings {
+            let text = Some(setting.value.clone());
+            match setting.id.as_str() {
+                "timezone" => user.timezone = text,
+                "weekStart" => user.week_start = setting.as_weekday(),
+                "defaultEventLength" => user.default_event_length = setting.as_minutes(),
+                "format24HourTime" => user.format_24_hour_time = setting.as_bool(),
+                "locale" => user.locale = text,
+                "dateFieldOrder" => user.date_field_order = text,
+                "hideWeekends" => user.hide_weekends = setting.as_bool(),
+                "showDeclinedEvents" => user.show_declined_events = setting.as_bool(),
+                "hideInvitations" => user.hide_invitations = setting.as_bool(),
+                "remindOnRespondedEventsOnly" => {
+                    user.remind_on_responded_events_only = setting.as_bool()
+                }
+                "autoAddHangouts" => user.auto_add_hangouts = setting.as_bool(),
+                "useKeyboardShortcuts" => user.use_keyboard_shortcuts = setting.as_bool(),
+                id => {
+                    user.other.insert(id.to_string(), setting.value.clone());
+                }
+            }
+        }
+        user
+    }
+}
+
+impl Settings {
+    /// The typed settings of this page, see `UserSettings`.
+    pub fn user_settings(&self) -> UserSettings {
+        UserSettings::from_settings(&self.items)
+    }
+}
@@ -0,0 +1,320 @@
+use std::{fmt, time::Duration};
+
+use reqwest::{header::HeaderMap, StatusCode};
+use serde::Deserialize;
+
+/// Error returned by every request made through this crate.
+///
+/// Non-2xx responses are mapped to a variant based on their status code, so callers can
+/// branch on the kind of failure instead of matching on error messages.
+/// The parsed Google error body is available through [`GoogleApiError::details`].
+#[derive(Debug)]
+pub enum GoogleApiError {
+    /// The access token is expired and could not be refreshed,
+    /// for example because the refresh token was revoked (`invalid_grant`).
+    AuthExpired(GoogleErrorDetails),
+    /// 401: the request did not carry valid credentials.
+    Unauthorized(GoogleErrorDetails),
+    /// 403: the credentials are valid but lack access to the resource.
+    Forbidden(GoogleErrorDetails),
+    /// 404: the resource does not exist.
+    NotFound(GoogleErrorDetails),
+    /// 409: the resource already exists or was modified concurrently.
+    Conflict(GoogleErrorDetails),
+    /// 410: the resource is gone, e.g. an expired sync token (`fullSyncRequired`) that
+    /// requires a full sync without the token.
+    Gone(GoogleErrorDetails),
+    /// 412: an `If-Match` precondition failed.
+    PreconditionFailed(GoogleErrorDetails),
+    /// 429, or a 403 with a `rateLimitExceeded`/`userRateLimitExceeded` reason.
+    /// `retry_after` is parsed from the `Retry-After` header when present.
+    RateLimited {
+        retry_after: Option<Duration>,
+        details: GoogleErrorDetails,
+    },
+    /// 5xx: Google failed to handle the request.
+    Server {
+        status: u16,
+        details: GoogleErrorDetails,
+    },
+    /// Any other non-2xx status, such as 400 Bad Request.
+    Status {
+        status: u16,
+        details: GoogleErrorDetails,
+    },
+    /// The response was successful but the body could not be decoded.
+    /// The raw body is kept for debugging.
+    Decode {
+        source: serde_json::Error,
+        body: String,
+    },
+    /// The request could not be sent or the response could not be read.
+    Request(reqwest::Error),
+    /// The request was not valid and was never sent.
+    InvalidRequest(String),
+    /// The credentials could not be loaded or used, e.g. a malformed service account key.
+    Credentials(String),
+    /// A `TokenStore` failed to load, save or delete a token.
+    Storage(String),
+    /// The response was successful, but Google could not provide the data for some of the
+    /// requested items, e.g. free/busy information of a calendar that does not exist.
+    /// Holds the id of every failed item with the reasons of its errors.
+    PartialFailure(Vec<(String, Vec<String>)>),
+}
+
+/// The `error` object Google returns in the body of failed requests.
+///
+/// ```json
+/// {"error": {"code": 404, "message": "Not Found", "errors": [{"domain": "global", "reason": "notFound"}]}}
+/// ```
+///
+/// The OAuth token endpoint uses a flat format instead (`{"error": "invalid_grant",
+/// "error_description": "..."}`), which is mapped to the same struct with the error code as
+/// the only reason.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct GoogleErrorDetails {
+    #[serde(default)]
+    pub code: u16,
+    #[serde(default)]
+    pub message: String,
+    #[serde(default)]
+    pub status: String,
+    #[serde(default)]
+    pub errors: Vec<GoogleErrorReason>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct GoogleErrorReason {
+    #[serde(default)]
+    pub domain: String,
+    #[serde(default)]
+    pub reason: String,
+    #[serde(default)]
+    pub message: String,
+}
+
+impl GoogleErrorDetails {
+    /// Parses the error body of a failed response.
+    /// Bodies that aren't a Google error object are kept as the message.
+    pub fn parse(status: u16, body: &str) -> Self {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum ErrorBody {
+            Api {
+                error: GoogleErrorDetails,
+            },
+            OAuth {
+                error: String,
+                #[serde(default)]
+                error_description: String,
+            },
+        }
+
+        match serde_json::from_str::<ErrorBody>(body) {
+            Ok(ErrorBody::Api { mut error }) => {
+                if error.code == 0 {
+                    error.code = status;
+                }
+                error
+            }
+            Ok(ErrorBody::OAuth {
+                error,
+                error_description,
+            }) => GoogleErrorDetails {
+                code: status,
+                message: error_description,
+                status: error.clone(),
+                errors: vec![GoogleErrorReason {
+                    reason: error,
+                    ..Default::default()
+                }],
+            },
+            Err(_) => GoogleErrorDetails {
+                code: status,
+                message: body.to_string(),
+                ..Default::default()
+            },
+        }
+    }
+
+    /// The `reason` of every entry in `errors`, e.g. `notFound` or `rateLimitExceeded`.
+    pub fn reasons(&self) -> Vec<&str> {
+        self.errors.iter().map(|e| e.reason.as_str()).collect()
+    }
+
+    pub fn has_reason(&self, reason: &str) -> bool {
+        self.errors.iter().any(|e| e.reason == reason)
+    }
+}
+
+impl GoogleApiError {
+    /// Maps a non-2xx response to the matching error variant.
+    pub fn from_status(status: StatusCode, headers: &HeaderMap, body: &str) -> Self {
+        let details = GoogleErrorDetails::parse(status.as_u16(), body);
+        match status.as_u16() {
+            401 => GoogleApiError::Unauthorized(details),
+            403 if details.has_reason("rateLimitExceeded")
+                || details.has_reason("userRateLimitExceeded") =>
+            {
+                GoogleApiError::RateLimited {
+                    retry_after: parse_retry_after(headers),
+                    details,
+                }
+            }
+            403 => GoogleApiError::Forbidden(details),
+            404 => GoogleApiError::NotFound(details),
+            409 => GoogleApiError::Conflict(details),
+            410 => GoogleApiError::Gone(details),
+            412 => GoogleApiError::PreconditionFailed(details),
+            429 => GoogleApiError::RateLimited {
+                retry_after: parse_retry_after(headers),
+                details,
+            },
+            code @ 500..=599 => GoogleApiError::Server {
+                status: code,
+                details,
+            },
+            code => GoogleApiError::Status {
+                status: code,
+                details,
+            },
+        }
+    }
+
+    /// Reads the body of a failed response and maps it to the matching error variant.
+    pub(crate) async fn from_response(res: reqwest::Response) -> Self {
+        let status = res.status();
+        let headers = res.headers().clone();
+        match res.text().await {
+            Ok(body) => Self::from_status(status, &headers, &body),
+            Err(e) => GoogleApiError::Request(e),
+        }
+    }
+
+    /// The HTTP status code of the failed response, if the request got that far.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            GoogleApiError::AuthExpired(details)
+            | GoogleApiError::Unauthorized(details)
+            | GoogleApiError::Forbidden(details)
+            | GoogleApiError::NotFound(details)
+            | GoogleApiError::Conflict(details)
+            | GoogleApiError::Gone(details)
+            | GoogleApiError::PreconditionFailed(details)
+            | GoogleApiError::RateLimited { details, .. } => Some(details.code),
+            GoogleApiError::Server { status, .. } | GoogleApiError::Status { status, .. } => {
+                Some(*status)
+            }
+            GoogleApiError::Request(e) => e.status().map(|s| s.as_u16()),
+            GoogleApiError::Decode { .. }
+            | GoogleApiError::InvalidRequest(_)
+            | GoogleApiError::Credentials(_)
+            | GoogleApiError::Storage(_)
+            | GoogleApiError::PartialFailure(_) => None,
+        }
+    }
+
+    /// The parsed Google error body, if the error came from a non-2xx response.
+    pub fn details(&self) -> Option<&GoogleErrorDetails> {
+        match self {
+            GoogleApiError::AuthExpired(details)
+            | GoogleApiError::Unauthorized(details)
+            | GoogleApiError::Forbidden(details)
+            | GoogleApiError::NotFound(details)
+            | GoogleApiError::Conflict(details)
+            | GoogleApiError::Gone(details)
+            | GoogleApiError::PreconditionFailed(details)
+            | GoogleApiError::RateLimited { details, .. }
+            | GoogleApiError::Server { details, .. }
+            | GoogleApiError::Status { details, .. } => Some(details),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for GoogleApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GoogleApiError::AuthExpired(d) => {
+                write!(
+                    f,
+                    "access token expired and could not be refreshed: {}",
+                    d.message
+                )
+            }
+            GoogleApiError::Unauthorized(d) => write!(f, "unauthorized (401): {}", d.message),
+            GoogleApiError::Forbidden(d) => write!(f, "forbidden (403): {}", d.message),
+            GoogleApiError::NotFound(d) => write!(f, "not found (404): {}", d.message),
+            GoogleApiError::Conflict(d) => write!(f, "conflict (409): {}", d.message),
+            GoogleApiError::Gone(d) => write!(f, "gone (410): {}", d.message),
+            GoogleApiError::PreconditionFailed(d) => {
+                write!(f, "precondition failed (412): {}", d.message)
+            }
+            GoogleApiError::RateLimited {
+                retry_after,
+                details,
+            } => match retry_after {
+                Some(after) => write!(
+                    f,
+                    "rate limited, retry after {}s: {}",
+                    after.as_secs(),
+                    details.message
+                ),
+                None => write!(f, "rate limited: {}", details.message),
+            },
+            GoogleApiError::Server { status, details } => {
+                write!(f, "server error ({status}): {}", details.message)
+            }
+            GoogleApiError::Status { status, details } => {
+                write!(f, "request failed ({status}): {}", details.message)
+            }
+            GoogleApiError::Decode { source, .. } => {
+                write!(f, "failed to decode response body: {source}")
+            }
+            GoogleApiError::Request(e) => write!(f, "request error: {e}"),
+            GoogleApiError::InvalidRequest(msg) => write!(f, "invalid request: {msg}"),
+            GoogleApiError::Credentials(msg) => write!(f, "invalid credentials: {msg}"),
+            GoogleApiError::Storage(msg) => write!(f, "token store error: {msg}"),
+            GoogleApiError::PartialFailure(failures) => {
+                write!(f, "no data for")?;
+                for (i, (id, reasons)) in failures.iter().enumerate() {
+                    let sep = if i == 0 { "" } else { "," };
+                    write!(f, "{sep} {id} ({})", reasons.join(", "))?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for GoogleApiError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            GoogleApiError::Decode { source, .. } => Some(source),
+            GoogleApiError::Request(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for GoogleApiError {
+    fn from(e: reqwest::Error) -> Self {
+        GoogleApiError::Request(e)
+    }
+}
+
+/// Parses a `Retry-After` header given in seconds or as an HTTP date.
+fn parse_retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+    let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    (date.with_timezone(&chrono::Utc) - chrono::Utc::now())
+        .to_std()
+        .ok()
+}
@@ -0,0 +1,115 @@
+#![allow(clippy::unwrap_used)]
+
+use futures::TryStreamExt;
+
+use super::requests::GmailClient;
+use crate::{error::GoogleApiError, utils::test_support::client};
+
+#[tokio::test]
+async fn missing_message_is_not_found() {
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server
+        .mock("GET", "/gmail/v1/users/me/messages/missing")
+        .with_status(404)
+        .with_body(r#"{"error": {"code": 404, "message": "Requested entity was not found."}}"#)
+        .create_async()
+        .await;
+
+    let gc = client(&server.url());
+    let res = GmailClient::new(&gc)
+        .get_email("me", "missing")
+        .request()
+        .await;
+
+    match res {
+        Err(GoogleApiError::NotFound(details)) => {
+            assert_eq!(details.message, "Requested entity was not found.")
+        }
+        other => panic!("expected NotFound, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn rejected_token_is_unauthorized() {
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server
+        .mock("POST", "/gmail/v1/users/me/messages/m1/trash")
+        .with_status(401)
+        .with_body(r#"{"error": {"code": 401, "message": "Invalid Credentials"}}"#)
+        .create_async()
+        .await;
+
+    let gc = client(&server.url());
+    let res = GmailClient::new(&gc)
+        .trash_email("me", "m1")
+        .request()
+        .await;
+
+    assert!(
+        matches!(res, Err(GoogleApiError::Unauthorized(_))),
+        "{res:?}"
+    );
+}
+
+#[tokio::test]
+async fn collect_all_follows_next_page_token() {
+    let mut server = mockito::Server::new_async().await;
+    let first = server
+        .mock("GET", "/gmail/v1/users/me/messages")
+        .match_query(mockito::Matcher::UrlEncoded("q".into(), "is:unread".into()))
+        .with_status(200)
+        .with_body(
+            r#"{"messages": [{"id": "m1", "threadId": "t1"}], "nextPageToken": "page2", "resultSizeEstimate": 2}"#,
+        )
+        .create_async()
+        .await;
+    let second = server
+        .mock("GET", "/gmail/v1/users/me/messages")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("q".into(), "is:unread".into()),
+            mockito::Matcher::UrlEncoded("pageToken".into(), "page2".into()),
+        ]))
+        .with_status(200)
+        .with_body(r#"{"messages": [{"id": "m2", "threadId": "t1"}], "resultSizeEstimate": 2}"#)
+        .create_async()
+        .await;
+
+    let gc = client(&server.url());
+    let messages = GmailClient::new(&gc)
+        .get_emails("me")
+        .query("is:unread")
+        .collect_all()
+        .await
+        .unwrap();
+
+    let ids: Vec<_> = messages.iter().map(|m| m.id.as_str()).collect();
+    assert_eq!(ids, ["m1", "m2"]);
+    first.assert_async().await;
+    second.assert_async().await;
+}
+
+#[tokio::test]
+async fn stream_stops_at_max_items() {
+    let mut server = mockito::Server::new_async().await;
+    let first = server
+        .mock("GET", "/gmail/v1/users/me/messages")
+        .with_status(200)
+        .with_body(
+            r#"{"messages": [{"id": "m1"}, {"id": "m2"}, {"id": "m3"}], "nextPageToken": "page2"}"#,
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let gc = client(&server.url());
+    let messages: Vec<_> = GmailClient::new(&gc)
+        .get_emails("me")
+        .max_items(2)
+        .stream()
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(messages.len(), 2);
+    first.assert_async().await;
+}
@@ -1,8 +1,14 @@
-use anyhow::{anyhow, Error};
-use reqwest::Method;
+use futures::{Stream, TryStreamExt};
 use serde::de::DeserializeOwned;
 
-use crate::{auth::client::GoogleClient, utils::request::Request};
+use crate::{
+    auth::client::GoogleClient,
+    error::GoogleApiError,
+    utils::{
+        pagination::{paginate, Page},
+        request::Request,
+    },
+};
 
 use super::types::{Message, MessageList};
 
@@ -18,7 +24,7 @@ pub struct GmailClient<'a, T> {
 }
 
 impl<'a> GmailClient<'a, ()> {
-    pub fn new(client: &'a mut GoogleClient) -> Self {
+    pub fn new(client: &'a GoogleClient) -> Self {
         GmailClient {
             request: Request::new(client),
             message: None,
@@ -35,10 +41,9 @@ impl<'a> GmailClient<'a, ()> {
     ///  
     /// ``` rust
     /// pub async fn get_emails(State(state): State<AppState>) -> Json<MessageList> {
-    ///     //GoogleClient is stored in the AppState wrapped in a Arc<Mutex>
-    ///     let google_client_guard = state.google_client.lock().await;
-    ///     let client = google_client_guard.as_ref().unwrap();
-    ///     let res = GmailClient::new(client)
+    ///     //GoogleClient is stored in the AppState, cloning it is cheap
+    ///     let client = state.google_client.read().await.clone().unwrap();
+    ///     let res = GmailClient::new(&client)
     ///         // "me" is a special value that refers to the authenticated user when used as user_id
     ///         .get_emails("me")
     ///         .max_results(10)
@@ -55,8 +60,10 @@ impl<'a> GmailClient<'a, ()> {
             message: None,
             _mode: std::marker::PhantomData,
         };
-        builder.request.url =
-            format!("https://gmail.googleapis.com/gmail/v1/users/{user_id}/messages");
+        builder.request.url = format!(
+            "{}/users/{user_id}/messages",
+            builder.request.client.endpoints.gmail
+        );
         builder.request.method = reqwest::Method::GET;
         builder
     }
@@ -71,9 +78,8 @@ impl<'a> GmailClient<'a, ()> {
     /// ```rust
     /// pub async fn get_email(State(state): State<AppState>, Path((user_id, email_id)):
     /// Path<(String, String)>) -> Json<Message> {
-    ///   let google_client_guard = state.google_client.lock().await;
-    ///   let client = google_client_guard.as_ref().unwrap();
-    ///   let res = GmailClient::new(client)
+    ///   let client = state.google_client.read().await.clone().unwrap();
+    ///   let res = GmailClient::new(&client)
     ///   // "me" is a special value that refers to the authenticated user when used as user_id
     ///   .get_email(user_id, &email_id)
     ///   .request()
@@ -88,8 +94,10 @@ impl<'a> GmailClient<'a, ()> {
             message: None,
             _mode: std::marker::PhantomData,
         };
-        builder.request.url =
-            format!("https://gmail.googleapis.com/gmail/v1/users/{user_id}/messages/{email_id}");
+        builder.request.url = format!(
+            "{}/users/{user_id}/messages/{email_id}",
+            builder.request.client.endpoints.gmail
+        );
         builder.request.method = reqwest::Method::GET;
         builder
     }
@@ -107,10 +115,9 @@ impl<'a> GmailClient<'a, ()> {
     /// pub async fn delete_email(State(state): State<AppState>, Path((user_id, email_id)):
     /// Path<(String, String)>) -> Json<()> {
     ///
-    ///   let google_client_guard = state.google_client.lock().await;
-    ///   let client = google_client_guard.as_ref().unwrap();
+    ///   let client = state.google_client.read().await.clone().unwrap();
     ///    
-    ///   let res = GmailClient::new(client)
+    ///   let res = GmailClient::new(&client)
     ///   // "me" is a special value that refers to the authenticated user when used as user_id
     ///   .delete_email(&user_id, &email_id)
     ///   .request().await.unwrap();
@@ -122,8 +129,10 @@ impl<'a> GmailClient<'a, ()> {
             message: None,
             _mode: std::marker::PhantomData,
         };
-        builder.request.url =
-            format!("https://gmail.googleapis.com/gmail/v1/users/{user_id}/messages/{email_id}");
+        builder.request.url = format!(
+            "{}/users/{user_id}/messages/{email_id}",
+            builder.request.client.endpoints.gmail
+        );
         builder.request.method = reqwest::Method::DELETE;
         builder
     }
@@ -139,10 +148,9 @@ impl<'a> GmailClient<'a, ()> {
     /// pub async fn trash_email(State(state): State<AppState>, Path((user_id, email_id)):
     /// Path<(String, String)>) -> Json<()> {
     ///
-    ///   let google_client_guard = state.google_client.lock().await;
-    ///   let client = google_client_guard.as_ref().unwrap();
+    ///   let client = state.google_client.read().await.clone().unwrap();
     ///    
-    ///   let res = GmailClient::new(client)
+    ///   let res = GmailClient::new(&client)
     ///   // "me" is a special value that refers to the authenticated user when used as user_id
     ///   .trash_email(&user_id, &email_id)
     ///   .request().await.unwrap();
@@ -155,7 +163,8 @@ impl<'a> GmailClient<'a, ()> {
             _mode: std::marker::PhantomData,
         };
         builder.request.url = format!(
-            "https://gmail.googleapis.com/gmail/v1/users/{user_id}/messages/{email_id}/trash"
+            "{}/users/{user_id}/messages/{email_id}/trash",
+            builder.request.client.endpoints.gmail
         );
         builder.request.method = reqwest::Method::POST;
         builder
@@ -173,10 +182,9 @@ impl<'a> GmailClient<'a, ()> {
     /// pub async fn untrash_email(State(state): State<AppState>, Path((user_id, email_id)):
     /// Path<(String, String)>) -> Json<()> {
     ///
-    ///   let google_client_guard = state.google_client.lock().await;
-    ///   let client = google_client_guard.as_ref().unwrap();
+    ///   let client = state.google_client.read().await.clone().unwrap();
     ///    
-    ///   let res = GmailClient::new(client)
+    ///   let res = GmailClient::new(&client)
     ///   // "me" is a special value that refers to the authenticated user when used as user_id
     ///   .untrash_email(&user_id, &email_id)
     ///   .request().await.unwrap();
@@ -189,7 +197,8 @@ impl<'a> GmailClient<'a, ()> {
             _mode: std::marker::PhantomData,
         };
         builder.request.url = format!(
-            "https://gmail.googleapis.com/gmail/v1/users/{user_id}/messages/{email_id}/untrash"
+            "{}/users/{user_id}/messages/{email_id}/untrash",
+            builder.request.client.endpoints.gmail
         );
         builder.request.method = reqwest::Method::POST;
         builder
@@ -197,110 +206,48 @@ impl<'a> GmailClient<'a, ()> {
 }
 
 impl<'a, T> GmailClient<'a, T> {
-    pub(super) async fn delete_request(&mut self) -> Result<(), Error> {
-        self.request.client.refresh_access_token_check().await?;
-        let res = self
-            .request
-            .client
-            .req_client
-            .delete(&self.request.url)
-            .query(&self.request.params)
-            .send()
-            .await?;
-
-        if res.status().is_success() {
-            Ok(())
-        } else {
-            Err(anyhow!("Failed to delete email: {}", res.status()))
-        }
+    pub(super) async fn delete_request(&mut self) -> Result<(), GoogleApiError> {
+        self.request.execute_empty().await
     }
 
-    pub(super) async fn trash_request(&mut self) -> Result<(), Error> {
-        self.request.client.refresh_access_token_check().await?;
-        let res = self
-            .request
-            .client
-            .req_client
-            .post(&self.request.url)
-            .query(&self.request.params)
-            .send()
-            .await?;
-
-        if res.status().is_success() {
-            Ok(())
-        } else {
-            Err(anyhow!("Failed to trash email: {}", res.status()))
-        }
+    pub(super) async fn trash_request(&mut self) -> Result<(), GoogleApiError> {
+        self.request.execute_empty().await
     }
 
-    pub(super) async fn make_request<R>(&mut self) -> Result<Option<R>, Error>
+    pub(super) async fn make_request<R>(&mut self) -> Result<Option<R>, GoogleApiError>
     where
         R: DeserializeOwned,
     {
-        self.request.client.refresh_access_token_check().await?;
-        match self.request.method {
-            Method::GET => {
-                let res = self
-                    .request
-                    .client
-                    .req_client
-                    .get(&self.request.url)
-                    .query(&self.request.params)
-                    .send()
-                    .await?;
-
-                if res.status().is_success() {
-                    Ok(Some(res.json().await?))
-                } else {
-                    Ok(None)
-                }
-            }
-
-            Method::POST => {
-                let res = self
-                    .request
-                    .client
-                    .req_client
-                    .post(&self.request.url)
-                    .body(serde_json::to_string(&self.message).unwrap())
-                    .query(&self.request.params)
-                    .send()
-                    .await?;
-
-                if res.status().is_success() {
-                    Ok(Some(res.json().await?))
-                } else {
-                    Ok(None)
-                }
-            }
-
-            Method::PATCH => {
-                let res = self
-                    .request
-                    .client
-                    .req_client
-                    .patch(&self.request.url)
-                    .body(serde_json::to_string(&self.message).unwrap())
-                    .query(&self.request.params)
-                    .send()
-                    .await?;
-
-                if res.status().is_success() {
-                    Ok(Some(res.json().await?))
-                } else {
-                    Ok(None)
-                }
-            }
-            _ => Err(anyhow!("Unsupported HTTP method")),
+        if let Some(message) = &self.message {
+            self.request.set_json_body(message)?;
         }
+        self.request.execute().await.map(Some)
     }
 }
 
 impl<'a> GmailClient<'a, EmailListMode> {
-    pub async fn request(mut self) -> Result<Option<MessageList>, Error> {
+    pub async fn request(mut self) -> Result<Option<MessageList>, GoogleApiError> {
         self.make_request().await
     }
 
+    /// Stop `stream` and `collect_all` after this many messages in total.
+    pub fn max_items(mut self, max: usize) -> Self {
+        self.request.max_items = Some(max);
+        self
+    }
+
+    /// Returns a stream of all messages matching the filters, following `nextPageToken`
+    /// until the last page or until `max_items` messages have been yielded.
+    /// Like `request`, the messages only contain their `id` and `thread_id`.
+    pub fn stream(self) -> impl Stream<Item = Result<Message, GoogleApiError>> + 'a {
+        paginate::<MessageList>(self.request)
+    }
+
+    /// Fetches every page and returns all messages matching the filters.
+    pub async fn collect_all(self) -> Result<Vec<Message>, GoogleApiError> {
+        self.stream().try_collect().await
+    }
+
     /// Include messages from SPAM and TRASH in the results.
     pub fn include_spam_trash(mut self, incl: bool) -> Self {
         self.request
@@ -338,19 +285,31 @@ impl<'a> GmailClient<'a, EmailListMode> {
 }
 
 impl<'a> GmailClient<'a, EmailGetMode> {
-    pub async fn request(mut self) -> Result<Option<Message>, Error> {
+    pub async fn request(mut self) -> Result<Option<Message>, GoogleApiError> {
         self.make_request().await
     }
 }
 
 impl<'a> GmailClient<'a, EmailDeleteMode> {
-    pub async fn request(mut self) -> Result<(), Error> {
+    pub async fn request(mut self) -> Result<(), GoogleApiError> {
         self.delete_request().await
     }
 }
 
 impl<'a> GmailClient<'a, TrashEmailMode> {
-    pub async fn request(mut self) -> Result<(), Error> {
+    pub async fn request(mut self) -> Result<(), GoogleApiError> {
         self.trash_request().await
     }
 }
+
+impl Page for MessageList {
+    type Item = Message;
+
+    fn next_page_token(&self) -> Option<&str> {
+        (!self.next_page_token.is_empty()).then_some(self.next_page_token.as_str())
+    }
+
+    fn into_items(self) -> Vec<Message> {
+        self.messages
+    }
+}
@@ -21,6 +21,9 @@ pub mod tasks;
 
 /// Module for authentication and authorization
 pub mod auth;
+
+/// Error type returned by all API clients
+pub mod error;
 #[cfg(feature = "gmail")]
 pub mod gmail;
 
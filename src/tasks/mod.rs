@@ -1,3 +1,5 @@
+#[cfg(test)]
+mod request_tests;
 pub mod requests;
 pub mod tasklist;
 pub mod types;
@@ -0,0 +1,113 @@
+#![allow(clippy::unwrap_used)]
+
+use super::requests::TasksClient;
+use crate::{error::GoogleApiError, utils::test_support::client};
+
+#[tokio::test]
+async fn missing_task_list_is_not_found() {
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server
+        .mock("GET", "/tasks/v1/lists/missing/tasks")
+        .with_status(404)
+        .with_body(r#"{"error": {"code": 404, "message": "Task list not found."}}"#)
+        .create_async()
+        .await;
+
+    let gc = client(&server.url());
+    let res = TasksClient::new(&gc).get_tasks("missing").request().await;
+
+    match res {
+        Err(GoogleApiError::NotFound(details)) => {
+            assert_eq!(details.message, "Task list not found.")
+        }
+        other => panic!("expected NotFound, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn rejected_token_is_unauthorized() {
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server
+        .mock("DELETE", "/tasks/v1/lists/list1/tasks/task1")
+        .with_status(401)
+        .with_body(r#"{"error": {"code": 401, "message": "Invalid Credentials"}}"#)
+        .create_async()
+        .await;
+
+    let gc = client(&server.url());
+    let res = TasksClient::new(&gc)
+        .delete_task("task1", "list1")
+        .request()
+        .await;
+
+    assert!(
+        matches!(res, Err(GoogleApiError::Unauthorized(_))),
+        "{res:?}"
+    );
+}
+
+#[tokio::test]
+async fn collect_all_follows_next_page_token() {
+    let mut server = mockito::Server::new_async().await;
+    let first = server
+        .mock("GET", "/tasks/v1/lists/list1/tasks")
+        .match_query(mockito::Matcher::Missing)
+        .with_status(200)
+        .with_body(r#"{"items": [{"id": "t1", "title": "One"}], "nextPageToken": "page2"}"#)
+        .create_async()
+        .await;
+    let second = server
+        .mock("GET", "/tasks/v1/lists/list1/tasks")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "pageToken".into(),
+            "page2".into(),
+        ))
+        .with_status(200)
+        .with_body(r#"{"items": [{"id": "t2", "title": "Two"}, {"id": "t3", "title": "Three"}]}"#)
+        .create_async()
+        .await;
+
+    let gc = client(&server.url());
+    let tasks = TasksClient::new(&gc)
+        .get_tasks("list1")
+        .collect_all()
+        .await
+        .unwrap();
+
+    let ids: Vec<_> = tasks.iter().map(|t| t.id.as_str()).collect();
+    assert_eq!(ids, ["t1", "t2", "t3"]);
+    first.assert_async().await;
+    second.assert_async().await;
+}
+
+#[tokio::test]
+async fn task_lists_stream_follows_next_page_token() {
+    let mut server = mockito::Server::new_async().await;
+    let _first = server
+        .mock("GET", "/tasks/v1/users/@me/lists")
+        .match_query(mockito::Matcher::Missing)
+        .with_status(200)
+        .with_body(r#"{"items": [{"id": "l1", "title": "Work"}], "nextPageToken": "page2"}"#)
+        .create_async()
+        .await;
+    let _second = server
+        .mock("GET", "/tasks/v1/users/@me/lists")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "pageToken".into(),
+            "page2".into(),
+        ))
+        .with_status(200)
+        .with_body(r#"{"items": [{"id": "l2", "title": "Home"}]}"#)
+        .create_async()
+        .await;
+
+    let gc = client(&server.url());
+    let lists = TasksClient::new(&gc)
+        .get_task_lists()
+        .collect_all()
+        .await
+        .unwrap();
+
+    let titles: Vec<_> = lists.iter().map(|l| l.title.as_str()).collect();
+    assert_eq!(titles, ["Work", "Home"]);
+}
@@ -1,14 +1,17 @@
-use anyhow::{anyhow, Error};
-use reqwest::Method;
+use futures::{Stream, TryStreamExt};
 use serde::de::DeserializeOwned;
 
 use crate::{
     auth::client::GoogleClient,
-    utils::request::{PaginationRequestTrait, Request},
+    error::GoogleApiError,
+    utils::{
+        pagination::{paginate, Page},
+        request::{PaginationRequestTrait, Request, RequestBody},
+    },
 };
 
 use super::{
-    tasklist::types::TaskLists,
+    tasklist::types::{TaskList, TaskLists},
     types::{Task, TaskLink, Tasks},
 };
 
@@ -35,7 +38,7 @@ pub struct TasksClient<'a, T = Uninitialized> {
 }
 
 impl<'a> TasksClient<'a, Uninitialized> {
-    pub fn new(client: &'a mut GoogleClient) -> Self {
+    pub fn new(client: &'a GoogleClient) -> Self {
         Self {
             request: Request::new(client),
             task: None,
@@ -52,9 +55,8 @@ impl<'a> TasksClient<'a, Uninitialized> {
     ///``` rust
     /// pub async fn get_tasks(State(state): State<AppState>,
     /// Path((task_list_id): Path<(String, String) -> Json<TaskLists> {
-    ///     let google_client_guard = state.google_client.lock().await;
-    ///     let client = google_client_guard.as_ref().unwrap();
-    ///     let res = TasksClient::new(client)
+    ///     let client = state.google_client.read().await.clone().unwrap();
+    ///     let res = TasksClient::new(&client)
     ///         .get_tasks(&task_list_id)
     ///         .max_results(10)
     ///         .page_token(1)
@@ -71,7 +73,7 @@ impl<'a> TasksClient<'a, Uninitialized> {
             task: None,
             _mode: std::marker::PhantomData,
         };
-        builder.request.url = "https://tasks.googleapis.com/tasks/v1/users/@me/lists".to_string();
+        builder.request.url = format!("{}/users/@me/lists", builder.request.client.endpoints.tasks);
         builder.request.method = reqwest::Method::GET;
         builder
     }
@@ -86,9 +88,8 @@ impl<'a> TasksClient<'a, Uninitialized> {
     ///``` rust
     /// pub async fn get_tasks(State(state): State<AppState>,
     /// Path((task_list_id): Path<(String, String) -> Json<Tasks> {
-    ///     let google_client_guard = state.google_client.lock().await;
-    ///     let client = google_client_guard.as_ref().unwrap();
-    ///     let res = TasksClient::new(client)
+    ///     let client = state.google_client.read().await.clone().unwrap();
+    ///     let res = TasksClient::new(&client)
     ///         .get_tasks(&task_list_id)
     ///         .max_results(10)
     ///         .page_token(1)
@@ -105,8 +106,10 @@ impl<'a> TasksClient<'a, Uninitialized> {
             task: None,
             _mode: std::marker::PhantomData,
         };
-        builder.request.url =
-            format!("https://tasks.googleapis.com/tasks/v1/lists/{task_list_id}/tasks");
+        builder.request.url = format!(
+            "{}/lists/{task_list_id}/tasks",
+            builder.request.client.endpoints.tasks
+        );
         builder.request.method = reqwest::Method::GET;
         builder
     }
@@ -121,9 +124,8 @@ impl<'a> TasksClient<'a, Uninitialized> {
     ///``` rust
     /// pub async fn get_tasks(State(state): State<AppState>,
     /// Path((task_id, task_list_id): Path<(String, String) {
-    ///     let google_client_guard = state.google_client.lock().await;
-    ///     let client = google_client_guard.as_ref().unwrap();
-    ///     let res = TasksClient::new(client)
+    ///     let client = state.google_client.read().await.clone().unwrap();
+    ///     let res = TasksClient::new(&client)
     ///         .insert_task(&task_list_id)
     ///         .set_task_notes("This is a new task")
     ///         .set_task_title("New Task")
@@ -136,8 +138,10 @@ impl<'a> TasksClient<'a, Uninitialized> {
             task: Some(Task::new()),
             _mode: std::marker::PhantomData,
         };
-        builder.request.url =
-            format!("https://tasks.googleapis.com/tasks/v1/lists/{task_list_id}/tasks");
+        builder.request.url = format!(
+            "{}/lists/{task_list_id}/tasks",
+            builder.request.client.endpoints.tasks
+        );
         builder.request.method = reqwest::Method::POST;
         builder
     }
@@ -152,9 +156,8 @@ impl<'a> TasksClient<'a, Uninitialized> {
     ///``` rust
     /// pub async fn get_tasks(State(state): State<AppState>,
     /// Path((task_id, task_list_id): Path<(String, String) {
-    ///     let google_client_guard = state.google_client.lock().await;
-    ///     let client = google_client_guard.as_ref().unwrap();
-    ///     let res = TasksClient::new(client)
+    ///     let client = state.google_client.read().await.clone().unwrap();
+    ///     let res = TasksClient::new(&client)
     ///         .complete_task(&task_list_id, &task_id)
     ///         .request()
     ///         .await.unwrap();
@@ -169,13 +172,15 @@ impl<'a> TasksClient<'a, Uninitialized> {
             task: None,
             _mode: std::marker::PhantomData,
         };
-        builder.request.url =
-            format!("https://tasks.googleapis.com/tasks/v1/lists/{task_list_id}/tasks/{task_id}");
+        builder.request.url = format!(
+            "{}/lists/{task_list_id}/tasks/{task_id}",
+            builder.request.client.endpoints.tasks
+        );
         builder.request.method = reqwest::Method::PATCH;
         let payload = serde_json::json!({
             "status": "completed"
         });
-        builder.request.body = Some(serde_json::to_string(&payload).unwrap());
+        builder.request.body = Some(RequestBody::Json(payload.to_string()));
         builder
     }
 
@@ -189,9 +194,8 @@ impl<'a> TasksClient<'a, Uninitialized> {
     ///``` rust
     /// pub async fn get_tasks(State(state): State<AppState>,
     /// Path((task_id, task_list_id): Path<(String, String) {
-    ///     let google_client_guard = state.google_client.lock().await;
-    ///     let client = google_client_guard.as_ref().unwrap();
-    ///     let res = TasksClient::new(client)
+    ///     let client = state.google_client.read().await.clone().unwrap();
+    ///     let res = TasksClient::new(&client)
     ///         .delete_task(&task_list_id, &task_id)
     ///         .request()
     ///         .await.unwrap();
@@ -203,91 +207,28 @@ impl<'a> TasksClient<'a, Uninitialized> {
             task: None,
             _mode: std::marker::PhantomData,
         };
-        builder.request.url =
-            format!("https://tasks.googleapis.com/tasks/v1/lists/{task_list_id}/tasks/{task_id}");
+        builder.request.url = format!(
+            "{}/lists/{task_list_id}/tasks/{task_id}",
+            builder.request.client.endpoints.tasks
+        );
         builder.request.method = reqwest::Method::DELETE;
         builder
     }
 }
 
 impl<'a, T> TasksClient<'a, T> {
-    pub(super) async fn make_delete_request(&mut self) -> Result<bool, Error> {
-        self.request.client.refresh_access_token_check().await?;
-        let res = self
-            .request
-            .client
-            .req_client
-            .delete(&self.request.url)
-            .query(&self.request.params)
-            .send()
-            .await?;
-
-        if res.status().is_success() {
-            Ok(true)
-        } else {
-            Ok(false)
-        }
+    pub(super) async fn make_delete_request(&mut self) -> Result<bool, GoogleApiError> {
+        self.request.execute_empty().await?;
+        Ok(true)
     }
-    async fn make_request<R>(&mut self) -> Result<Option<R>, Error>
+    async fn make_request<R>(&mut self) -> Result<Option<R>, GoogleApiError>
     where
         R: DeserializeOwned,
     {
-        self.request.client.refresh_access_token_check().await?;
-        match self.request.method {
-            Method::GET => {
-                let res = self
-                    .request
-                    .client
-                    .req_client
-                    .get(&self.request.url)
-                    .query(&self.request.params)
-                    .send()
-                    .await?;
-
-                if res.status().is_success() {
-                    Ok(Some(res.json().await?))
-                } else {
-                    Ok(None)
-                }
-            }
-
-            Method::POST => {
-                let res = self
-                    .request
-                    .client
-                    .req_client
-                    .post(&self.request.url)
-                    .body(serde_json::to_string(&self.task).unwrap())
-                    .query(&self.request.params)
-                    .send()
-                    .await?;
-
-                if res.status().is_success() {
-                    Ok(Some(res.json().await?))
-                } else {
-                    Ok(None)
-                }
-            }
-
-            Method::PATCH => {
-                let res = self
-                    .request
-                    .client
-                    .req_client
-                    .patch(&self.request.url)
-                    .body(self.request.body.clone().unwrap_or_default())
-                    .query(&self.request.params)
-                    .send()
-                    .await?;
-
-                if res.status().is_success() {
-                    Ok(Some(res.json().await?))
-                } else {
-                    Ok(None)
-                }
-            }
-            _ => Err(anyhow!("Unsupported HTTP method")),
+        if let Some(task) = &self.task {
+            self.request.set_json_body(task)?;
         }
+        self.request.execute().await.map(Some)
     }
 }
 
@@ -311,9 +252,50 @@ impl<'a, T: InitializedGetMode> PaginationRequestTrait for TasksClient<'a, T> {
 
 impl<'a> TasksClient<'a, TaskListMode> {
     /// Makes a request to retrieve the task lists.
-    pub async fn request(&mut self) -> Result<Option<TaskLists>, Error> {
+    pub async fn request(&mut self) -> Result<Option<TaskLists>, GoogleApiError> {
         self.make_request().await
     }
+
+    /// Stop `stream` and `collect_all` after this many task lists in total.
+    pub fn max_items(mut self, max: usize) -> Self {
+        self.request.max_items = Some(max);
+        self
+    }
+
+    /// Returns a stream of all task lists, following `nextPageToken` until the last page
+    /// or until `max_items` task lists have been yielded.
+    pub fn stream(self) -> impl Stream<Item = Result<TaskList, GoogleApiError>> + 'a {
+        paginate::<TaskLists>(self.request)
+    }
+
+    /// Fetches every page and returns all task lists.
+    pub async fn collect_all(self) -> Result<Vec<TaskList>, GoogleApiError> {
+        self.stream().try_collect().await
+    }
+}
+
+impl Page for TaskLists {
+    type Item = TaskList;
+
+    fn next_page_token(&self) -> Option<&str> {
+        (!self.next_page_token.is_empty()).then_some(self.next_page_token.as_str())
+    }
+
+    fn into_items(self) -> Vec<TaskList> {
+        self.items
+    }
+}
+
+impl Page for Tasks {
+    type Item = Task;
+
+    fn next_page_token(&self) -> Option<&str> {
+        (!self.next_page_token.is_empty()).then_some(self.next_page_token.as_str())
+    }
+
+    fn into_items(self) -> Vec<Task> {
+        self.items
+    }
 }
 
 /// A client for interacting with the Google Tasks API in retrieval mode.
@@ -323,18 +305,36 @@ impl<'a> TasksClient<'a, TaskListMode> {
 ///
 /// # Example
 /// ```
-/// let client = TasksClient::new(client);
+/// let client = TasksClient::new(&client);
 /// let tasks = client.show_completed(true).get_due_min(some_date).request().await?;
 /// ```
 impl<'a> TasksClient<'a, TasksMode> {
     /// Makes a request to retrieve the tasks from the specified task list.
     ///
     /// # Returns
-    /// * `Result<Option<Tasks>, Error>` - A result containing the tasks if successful,
+    /// * `Result<Option<Tasks>, GoogleApiError>` - A result containing the tasks if successful,
     ///   or an error if the request failed. Returns `None` if no tasks were found.
-    pub async fn request(&mut self) -> Result<Option<Tasks>, Error> {
+    pub async fn request(&mut self) -> Result<Option<Tasks>, GoogleApiError> {
         self.make_request().await
     }
+
+    /// Stop `stream` and `collect_all` after this many tasks in total.
+    pub fn max_items(mut self, max: usize) -> Self {
+        self.request.max_items = Some(max);
+        self
+    }
+
+    /// Returns a stream of all tasks matching the filters, following `nextPageToken`
+    /// until the last page or until `max_items` tasks have been yielded.
+    pub fn stream(self) -> impl Stream<Item = Result<Task, GoogleApiError>> + 'a {
+        paginate::<Tasks>(self.request)
+    }
+
+    /// Fetches every page and returns all tasks matching the filters.
+    pub async fn collect_all(self) -> Result<Vec<Task>, GoogleApiError> {
+        self.stream().try_collect().await
+    }
+
     /// Filter tasks by completion date to include only tasks completed before the specified date.
     ///
     /// # Arguments
@@ -469,16 +469,16 @@ impl<'a> TasksClient<'a, TasksMode> {
 ///
 /// # Example
 /// ```
-/// let client = TasksClient::new(client);
+/// let client = TasksClient::new(&client);
 /// let task = client.set_task_title("New Task").set_task_notes("Details").request().await?;
 /// ```
 impl<'a> TasksClient<'a, TaskInsertMode> {
     /// Makes a request to create a task with the specified properties.
     ///
     /// # Returns
-    /// * `Result<Option<Tasks>, Error>` - A result containing the created task if successful,
+    /// * `Result<Option<Tasks>, GoogleApiError>` - A result containing the created task if successful,
     ///   or an error if the request failed.
-    pub async fn request(&mut self) -> Result<Option<Tasks>, Error> {
+    pub async fn request(&mut self) -> Result<Option<Tasks>, GoogleApiError> {
         self.make_request().await
     }
 
@@ -614,9 +614,9 @@ impl<'a> TasksClient<'a, TaskPatchMode> {
     /// Makes a request to update the task with the specified properties.
     ///
     /// # Returns
-    /// * `Result<Option<Tasks>, Error>` - A result containing the updated task if successful,
+    /// * `Result<Option<Tasks>, GoogleApiError>` - A result containing the updated task if successful,
     ///   or an error if the request failed.
-    pub async fn request(&mut self) -> Result<Option<Task>, Error> {
+    pub async fn request(&mut self) -> Result<Option<Task>, GoogleApiError> {
         self.make_request().await
     }
 }
@@ -625,8 +625,8 @@ impl<'a> TasksClient<'a, TaskDeleteMode> {
     /// Makes a request to delete the specified task.
     ///
     /// # Returns
-    /// * `Result<bool, Error>` - A result indicating whether the deletion was successful.
-    pub async fn request(&mut self) -> Result<bool, Error> {
+    /// * `Result<bool, GoogleApiError>` - A result indicating whether the deletion was successful.
+    pub async fn request(&mut self) -> Result<bool, GoogleApiError> {
         self.make_delete_request().await
     }
 }
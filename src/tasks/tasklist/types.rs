@@ -15,6 +15,16 @@ pub struct TaskLists {
         deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize"
     )]
     pub etag: String,
+    /**
+     * Token that can be used to request the next page of this result.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize::deserialize_nullable_string::deserialize",
+        rename = "nextPageToken"
+    )]
+    pub next_page_token: String,
     /**
      * List of tasklists of the authenticated user.
      */
@@ -0,0 +1,4 @@
+/// Compares every byte, so the time taken doesn't leak how much of a secret matched.
+pub(crate) fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
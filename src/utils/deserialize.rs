@@ -58,3 +58,21 @@ pub mod deserialize_date_time_format {
         }
     }
 }
+
+pub mod deserialize_date_time_offset_format {
+    use chrono::{DateTime, FixedOffset};
+    use serde::{self, Deserialize, Deserializer};
+
+    /// Like `deserialize_date_time_format`, but keeps the offset of the value.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<DateTime<FixedOffset>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match Option::<String>::deserialize(deserializer)? {
+            Some(s) if !s.is_empty() => DateTime::parse_from_rfc3339(&s)
+                .map(Some)
+                .map_err(|_| serde::de::Error::custom(format!("Invalid datetime format: {s}"))),
+            _ => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,56 @@
+/// Base URLs used by a `GoogleClient` for every API.
+///
+/// The defaults point at Google. Override them to route requests through a private API
+/// gateway, or to point every request builder and the token endpoint at a local mock server
+/// in tests.
+///
+/// # Example
+/// ```rust
+/// let mut server = mockito::Server::new_async().await;
+/// let mut client = GoogleClient::new(client_credentials, access_token, true);
+/// client.set_endpoints(ApiEndpoints::with_base_url(&server.url()));
+///
+/// // Requests https://127.0.0.1:1234/calendar/v3/calendars/primary/events
+/// CalendarEventsClient::new(&client).get_events("primary").request().await?;
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiEndpoints {
+    /// Base URL of the Calendar API, without trailing slash.
+    pub calendar: String,
+    /// Base URL of the Tasks API, without trailing slash.
+    pub tasks: String,
+    /// Base URL of the Gmail API, without trailing slash.
+    pub gmail: String,
+    /// OAuth 2.0 consent screen URL.
+    pub oauth_auth: String,
+    /// OAuth 2.0 token endpoint, used to exchange codes and refresh tokens.
+    pub oauth_token: String,
+}
+
+impl Default for ApiEndpoints {
+    fn default() -> Self {
+        Self {
+            calendar: "https://www.googleapis.com/calendar/v3".to_string(),
+            tasks: "https://tasks.googleapis.com/tasks/v1".to_string(),
+            gmail: "https://gmail.googleapis.com/gmail/v1".to_string(),
+            oauth_auth: "https://accounts.google.com/o/oauth2/auth".to_string(),
+            oauth_token: "https://oauth2.googleapis.com/token".to_string(),
+        }
+    }
+}
+
+impl ApiEndpoints {
+    /// Points every API at the same server, keeping Google's paths:
+    /// `{base_url}/calendar/v3`, `{base_url}/tasks/v1`, `{base_url}/gmail/v1`,
+    /// `{base_url}/o/oauth2/auth` and `{base_url}/token`.
+    pub fn with_base_url(base_url: &str) -> Self {
+        let base_url = base_url.trim_end_matches('/');
+        Self {
+            calendar: format!("{base_url}/calendar/v3"),
+            tasks: format!("{base_url}/tasks/v1"),
+            gmail: format!("{base_url}/gmail/v1"),
+            oauth_auth: format!("{base_url}/o/oauth2/auth"),
+            oauth_token: format!("{base_url}/token"),
+        }
+    }
+}
@@ -1,6 +1,14 @@
+pub(crate) mod compare;
 pub mod default_builder;
 pub mod deserialize;
+pub mod endpoints;
 pub mod format;
+pub mod pagination;
 pub mod request;
+#[cfg(test)]
+mod request_tests;
+pub mod retry;
 pub mod serialize;
+#[cfg(all(test, any(feature = "calendar", feature = "tasks", feature = "gmail")))]
+pub(crate) mod test_support;
 pub mod validation;
@@ -0,0 +1,54 @@
+use futures::{stream, Stream, StreamExt, TryStreamExt};
+use serde::de::DeserializeOwned;
+
+use crate::error::GoogleApiError;
+
+use super::request::Request;
+
+/// A single page of a list response, such as `EventList` or `Tasks`.
+pub trait Page: DeserializeOwned {
+    type Item;
+
+    /// Token of the next page, `None` on the last page.
+    fn next_page_token(&self) -> Option<&str>;
+
+    /// Consumes the page and returns its items.
+    fn into_items(self) -> Vec<Self::Item>;
+}
+
+/// Sends `request` and follows `nextPageToken` until the last page, yielding every item.
+///
+/// Pages are only fetched when the previous page has been consumed, so dropping the stream
+/// (or reaching `request.max_items`) stops further requests.
+pub fn paginate<'a, P>(
+    request: Request<'a>,
+) -> impl Stream<Item = Result<P::Item, GoogleApiError>> + 'a
+where
+    P: Page + 'a,
+    P::Item: 'a,
+{
+    let max_items = request.max_items;
+    let items = stream::try_unfold(Some(request), |state| async move {
+        let Some(mut request) = state else {
+            return Ok::<_, GoogleApiError>(None);
+        };
+        let page: P = request.execute().await?;
+        let next = match page.next_page_token() {
+            Some(token) => {
+                request
+                    .params
+                    .insert("pageToken".to_string(), token.to_string());
+                Some(request)
+            }
+            None => None,
+        };
+        let items = stream::iter(page.into_items().into_iter().map(Ok));
+        Ok(Some((items, next)))
+    })
+    .try_flatten();
+
+    match max_items {
+        Some(max) => items.take(max).left_stream(),
+        None => items.right_stream(),
+    }
+}
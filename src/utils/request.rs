@@ -1,25 +1,157 @@
-use std::collections::HashMap;
-
 use chrono::DateTime;
+use reqwest::header::{HeaderMap, CONTENT_TYPE};
+use serde::{de::DeserializeOwned, Serialize};
 
-use crate::auth::client::GoogleClient;
+use crate::{auth::client::GoogleClient, error::GoogleApiError};
 
+/// A request to one of the Google Workspace APIs.
+///
+/// The API clients fill in the url, method, query parameters and body,
+/// after which `execute` takes care of refreshing the access token, sending the request
+/// with retries, mapping errors and decoding the response.
+/// New APIs only have to build a `Request`, the transport is shared.
 pub struct Request<'a> {
-    pub client: &'a mut GoogleClient,
+    pub client: &'a GoogleClient,
     pub url: String,
     pub method: reqwest::Method,
-    pub params: HashMap<String, String>,
-    pub body: Option<String>,
+    pub params: QueryParams,
+    /// Extra headers, e.g. `If-Match` for a conditional update.
+    pub headers: HeaderMap,
+    pub body: Option<RequestBody>,
+    /// Maximum number of items yielded when paginating over a list endpoint.
+    pub max_items: Option<usize>,
+}
+
+/// Query parameters of a `Request`, in the order they were added.
+///
+/// Most parameters have a single value that `insert` replaces. Parameters that Google accepts
+/// multiple times, like `eventTypes`, get a value per `append`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct QueryParams(Vec<(String, String)>);
+
+impl QueryParams {
+    /// Sets the value of a parameter, replacing all its values. Returns the previous value.
+    pub fn insert(&mut self, key: String, value: String) -> Option<String> {
+        let previous = self.remove(&key);
+        self.0.push((key, value));
+        previous
+    }
+
+    /// Adds a value to a parameter, keeping its existing values.
+    pub fn append(&mut self, key: String, value: String) {
+        self.0.push((key, value));
+    }
+
+    /// The first value of a parameter.
+    pub fn get(&self, key: &str) -> Option<&String> {
+        self.0.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+    }
+
+    /// All values of a parameter.
+    pub fn get_all<'a>(&'a self, key: &'a str) -> impl Iterator<Item = &'a String> {
+        self.0.iter().filter(move |(k, _)| k == key).map(|(_, v)| v)
+    }
+
+    /// Removes all values of a parameter. Returns the first value.
+    pub fn remove(&mut self, key: &str) -> Option<String> {
+        let mut removed = None;
+        self.0.retain(|(k, v)| {
+            if k != key {
+                return true;
+            }
+            removed.get_or_insert_with(|| v.clone());
+            false
+        });
+        removed
+    }
+
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// All parameters as key-value pairs, a repeated parameter once for every value.
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &String)> {
+        self.0.iter().map(|(k, v)| (k, v))
+    }
+}
+
+/// Body of a `Request`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RequestBody {
+    /// Serialized JSON, sent as `application/json`.
+    Json(String),
+    /// Raw bytes sent with the given content type, e.g. `message/rfc822`.
+    Raw { content_type: String, data: Vec<u8> },
 }
 
 impl<'a> Request<'a> {
-    pub fn new(client: &'a mut GoogleClient) -> Self {
+    pub fn new(client: &'a GoogleClient) -> Self {
         Self {
             client,
             url: "".to_string(),
             method: reqwest::Method::GET,
-            params: HashMap::new(),
+            params: QueryParams::default(),
+            headers: HeaderMap::new(),
             body: None,
+            max_items: None,
+        }
+    }
+
+    /// Serializes `body` as the JSON body of the request.
+    pub fn set_json_body<B>(&mut self, body: &B) -> Result<(), GoogleApiError>
+    where
+        B: Serialize + ?Sized,
+    {
+        let json = serde_json::to_string(body).map_err(|e| {
+            GoogleApiError::InvalidRequest(format!("Failed to serialize request body: {e}"))
+        })?;
+        self.body = Some(RequestBody::Json(json));
+        Ok(())
+    }
+
+    /// Sets a raw body with the given content type.
+    pub fn set_raw_body(&mut self, content_type: &str, data: Vec<u8>) {
+        self.body = Some(RequestBody::Raw {
+            content_type: content_type.to_string(),
+            data,
+        });
+    }
+
+    /// Sends the request and decodes the JSON response body.
+    pub async fn execute<R>(&mut self) -> Result<R, GoogleApiError>
+    where
+        R: DeserializeOwned,
+    {
+        let res = self.client.send(self.build()).await?;
+        decode_response(res).await
+    }
+
+    /// Sends the request and discards the response body,
+    /// for endpoints that return no content such as deletes.
+    pub async fn execute_empty(&mut self) -> Result<(), GoogleApiError> {
+        self.client.send(self.build()).await?;
+        Ok(())
+    }
+
+    fn build(&self) -> reqwest::RequestBuilder {
+        let builder = self
+            .client
+            .req_client
+            .request(self.method.clone(), &self.url)
+            .query(&self.params.0)
+            .headers(self.headers.clone());
+        match &self.body {
+            Some(RequestBody::Json(json)) => builder
+                .header(CONTENT_TYPE, "application/json")
+                .body(json.clone()),
+            Some(RequestBody::Raw { content_type, data }) => builder
+                .header(CONTENT_TYPE, content_type.as_str())
+                .body(data.clone()),
+            None => builder,
         }
     }
 }
@@ -33,3 +165,23 @@ pub trait TimeRequestTrait {
     fn time_min(self, max: DateTime<chrono::Utc>) -> Self;
     fn time_max(self, token: DateTime<chrono::Utc>) -> Self;
 }
+
+/// Returns the response if it has a 2xx status, otherwise maps it to a `GoogleApiError`.
+pub(crate) async fn check_response(
+    res: reqwest::Response,
+) -> Result<reqwest::Response, GoogleApiError> {
+    if res.status().is_success() {
+        Ok(res)
+    } else {
+        Err(GoogleApiError::from_response(res).await)
+    }
+}
+
+/// Decodes the JSON body of a 2xx response, mapping non-2xx responses to a `GoogleApiError`.
+pub(crate) async fn decode_response<R>(res: reqwest::Response) -> Result<R, GoogleApiError>
+where
+    R: DeserializeOwned,
+{
+    let body = check_response(res).await?.text().await?;
+    serde_json::from_str(&body).map_err(|source| GoogleApiError::Decode { source, body })
+}
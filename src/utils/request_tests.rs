@@ -0,0 +1,277 @@
+#![allow(clippy::unwrap_used)]
+
+use std::time::Duration;
+
+use reqwest::{header::HeaderMap, StatusCode};
+
+use crate::{
+    auth::client::{AccessToken, ClientCredentials, GoogleClient},
+    error::GoogleApiError,
+};
+
+use super::{
+    request::{decode_response, QueryParams},
+    retry::RetryPolicy,
+};
+
+const NOT_FOUND_BODY: &str = r#"{
+  "error": {
+    "code": 404,
+    "message": "Not Found",
+    "errors": [{ "domain": "global", "reason": "notFound", "message": "Not Found" }]
+  }
+}"#;
+
+#[test]
+fn status_codes_map_to_error_variants() {
+    let headers = HeaderMap::new();
+    let cases = [
+        (StatusCode::UNAUTHORIZED, "Unauthorized"),
+        (StatusCode::FORBIDDEN, "Forbidden"),
+        (StatusCode::NOT_FOUND, "NotFound"),
+        (StatusCode::CONFLICT, "Conflict"),
+        (StatusCode::GONE, "Gone"),
+        (StatusCode::PRECONDITION_FAILED, "PreconditionFailed"),
+        (StatusCode::TOO_MANY_REQUESTS, "RateLimited"),
+        (StatusCode::SERVICE_UNAVAILABLE, "Server"),
+        (StatusCode::BAD_REQUEST, "Status"),
+    ];
+
+    for (status, expected) in cases {
+        let err = GoogleApiError::from_status(status, &headers, "");
+        assert!(
+            format!("{err:?}").starts_with(expected),
+            "{status} mapped to {err:?}"
+        );
+        assert_eq!(err.status(), Some(status.as_u16()));
+    }
+}
+
+#[test]
+fn google_error_reasons_are_parsed() {
+    let err = GoogleApiError::from_status(StatusCode::NOT_FOUND, &HeaderMap::new(), NOT_FOUND_BODY);
+
+    let details = err.details().unwrap();
+    assert_eq!(details.code, 404);
+    assert_eq!(details.message, "Not Found");
+    assert_eq!(details.reasons(), vec!["notFound"]);
+}
+
+#[test]
+fn forbidden_rate_limit_reason_is_rate_limited() {
+    let body = r#"{"error": {"code": 403, "message": "Rate Limit Exceeded",
+        "errors": [{"domain": "usageLimits", "reason": "rateLimitExceeded"}]}}"#;
+    let mut headers = HeaderMap::new();
+    headers.insert(reqwest::header::RETRY_AFTER, "7".parse().unwrap());
+
+    let err = GoogleApiError::from_status(StatusCode::FORBIDDEN, &headers, body);
+
+    match err {
+        GoogleApiError::RateLimited {
+            retry_after,
+            details,
+        } => {
+            assert_eq!(retry_after, Some(Duration::from_secs(7)));
+            assert!(details.has_reason("rateLimitExceeded"));
+        }
+        other => panic!("expected RateLimited, got {other:?}"),
+    }
+}
+
+#[test]
+fn oauth_error_body_is_parsed() {
+    let body =
+        r#"{"error": "invalid_grant", "error_description": "Token has been expired or revoked."}"#;
+    let err = GoogleApiError::from_status(StatusCode::BAD_REQUEST, &HeaderMap::new(), body);
+
+    let details = err.details().unwrap();
+    assert_eq!(details.message, "Token has been expired or revoked.");
+    assert!(details.has_reason("invalid_grant"));
+}
+
+#[tokio::test]
+async fn decode_response_maps_non_success_and_bad_bodies() {
+    let mut server = mockito::Server::new_async().await;
+    let _not_found = server
+        .mock("GET", "/missing")
+        .with_status(404)
+        .with_body(NOT_FOUND_BODY)
+        .create_async()
+        .await;
+    let _garbage = server
+        .mock("GET", "/garbage")
+        .with_status(200)
+        .with_body("<html>")
+        .create_async()
+        .await;
+
+    let client = reqwest::Client::new();
+
+    let res = client
+        .get(format!("{}/missing", server.url()))
+        .send()
+        .await
+        .unwrap();
+    let err = decode_response::<serde_json::Value>(res).await.unwrap_err();
+    assert!(matches!(err, GoogleApiError::NotFound(_)));
+
+    let res = client
+        .get(format!("{}/garbage", server.url()))
+        .send()
+        .await
+        .unwrap();
+    match decode_response::<serde_json::Value>(res).await {
+        Err(GoogleApiError::Decode { body, .. }) => assert_eq!(body, "<html>"),
+        other => panic!("expected Decode error, got {other:?}"),
+    }
+}
+
+fn client_with_fast_retries(max_attempts: u32) -> GoogleClient {
+    let mut client = GoogleClient::new(
+        ClientCredentials::default(),
+        AccessToken {
+            access_token: "atok".into(),
+            expires_in: 3600,
+            ..Default::default()
+        },
+        false,
+    );
+    client.set_retry_policy(RetryPolicy {
+        max_attempts,
+        base_delay: Duration::from_millis(1),
+        jitter: false,
+        ..Default::default()
+    });
+    client
+}
+
+#[tokio::test]
+async fn retries_scripted_503_and_429_until_success() {
+    let mut server = mockito::Server::new_async().await;
+    let unavailable = server
+        .mock("GET", "/events")
+        .with_status(503)
+        .with_body(r#"{"error": {"code": 503, "message": "Backend Error"}}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let rate_limited = server
+        .mock("GET", "/events")
+        .with_status(429)
+        .with_header("Retry-After", "0")
+        .expect(1)
+        .create_async()
+        .await;
+    let ok = server
+        .mock("GET", "/events")
+        .with_status(200)
+        .with_body(r#"{"ok": true}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = client_with_fast_retries(3);
+    let req = client.req_client.get(format!("{}/events", server.url()));
+    let res = client.send(req).await.unwrap();
+    let body: serde_json::Value = decode_response(res).await.unwrap();
+
+    assert_eq!(body["ok"], true);
+    unavailable.assert_async().await;
+    rate_limited.assert_async().await;
+    ok.assert_async().await;
+}
+
+#[tokio::test]
+async fn gives_up_after_max_attempts() {
+    let mut server = mockito::Server::new_async().await;
+    let unavailable = server
+        .mock("GET", "/events")
+        .with_status(503)
+        .expect(2)
+        .create_async()
+        .await;
+
+    let client = client_with_fast_retries(2);
+    let req = client.req_client.get(format!("{}/events", server.url()));
+    let err = client.send(req).await.unwrap_err();
+
+    assert!(matches!(err, GoogleApiError::Server { status: 503, .. }));
+    unavailable.assert_async().await;
+}
+
+#[tokio::test]
+async fn post_is_not_retried_by_default() {
+    let mut server = mockito::Server::new_async().await;
+    let unavailable = server
+        .mock("POST", "/events")
+        .with_status(503)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = client_with_fast_retries(3);
+    let req = client
+        .req_client
+        .post(format!("{}/events", server.url()))
+        .body("{}");
+    let err = client.send(req).await.unwrap_err();
+
+    assert!(matches!(err, GoogleApiError::Server { .. }));
+    unavailable.assert_async().await;
+}
+
+#[test]
+fn retry_delay_honors_retry_after_and_backoff() {
+    let policy = RetryPolicy {
+        base_delay: Duration::from_millis(100),
+        jitter: false,
+        ..Default::default()
+    };
+    let server_error =
+        GoogleApiError::from_status(StatusCode::INTERNAL_SERVER_ERROR, &HeaderMap::new(), "");
+    let mut headers = HeaderMap::new();
+    headers.insert(reqwest::header::RETRY_AFTER, "3".parse().unwrap());
+    let rate_limited = GoogleApiError::from_status(StatusCode::TOO_MANY_REQUESTS, &headers, "");
+    let not_found = GoogleApiError::from_status(StatusCode::NOT_FOUND, &HeaderMap::new(), "");
+
+    assert_eq!(
+        policy.retry_delay(&server_error, 1),
+        Some(Duration::from_millis(100))
+    );
+    assert_eq!(
+        policy.retry_delay(&server_error, 2),
+        Some(Duration::from_millis(200))
+    );
+    assert_eq!(policy.retry_delay(&server_error, 3), None);
+    assert_eq!(
+        policy.retry_delay(&rate_limited, 1),
+        Some(Duration::from_secs(3))
+    );
+    assert_eq!(policy.retry_delay(&not_found, 1), None);
+}
+
+#[test]
+fn query_params_replace_or_repeat_values() {
+    let mut params = QueryParams::default();
+    params.insert("eventTypes".into(), "default".into());
+    assert_eq!(
+        params.insert("eventTypes".into(), "birthday".into()),
+        Some("default".to_string())
+    );
+    params.append("eventTypes".into(), "focusTime".into());
+    params.insert("q".into(), "standup".into());
+
+    assert_eq!(
+        params.get("eventTypes").map(String::as_str),
+        Some("birthday")
+    );
+    assert_eq!(
+        params.get_all("eventTypes").collect::<Vec<_>>(),
+        ["birthday", "focusTime"]
+    );
+    assert_eq!(params.iter().count(), 3);
+
+    assert_eq!(params.remove("eventTypes"), Some("birthday".to_string()));
+    assert!(!params.contains_key("eventTypes"));
+    assert!(params.contains_key("q"));
+}
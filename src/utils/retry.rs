@@ -0,0 +1,117 @@
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+
+use reqwest::Method;
+
+use crate::error::GoogleApiError;
+
+/// Retry policy applied to every request sent through a `GoogleClient`.
+///
+/// Requests that fail with a rate limit (429 or a 403 `rateLimitExceeded`), a 5xx status or a
+/// connection error are retried with exponential backoff.
+/// By default only idempotent methods (GET, PUT, DELETE, ...) are retried, since retrying a
+/// POST could for example insert the same event twice.
+///
+/// # Example
+/// ```rust
+/// let mut client = GoogleClient::new(client_credentials, access_token, true);
+/// client.set_retry_policy(RetryPolicy {
+///     max_attempts: 5,
+///     base_delay: Duration::from_secs(1),
+///     ..Default::default()
+/// });
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Total number of attempts, including the first one. `1` disables retries.
+    pub max_attempts: u32,
+    /// Delay before the first retry, doubled for every following retry.
+    pub base_delay: Duration,
+    /// Upper bound for the computed backoff delay.
+    pub max_delay: Duration,
+    /// Randomize the backoff delay between 50% and 100% of its value, so concurrent
+    /// clients don't retry in lockstep.
+    pub jitter: bool,
+    /// Wait for the duration of the `Retry-After` header instead of the backoff delay when
+    /// Google sends one.
+    pub respect_retry_after: bool,
+    /// Also retry non-idempotent methods (POST, PATCH).
+    pub retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(32),
+            jitter: true,
+            respect_retry_after: true,
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that sends every request exactly once.
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Whether requests with this method may be retried under this policy.
+    pub fn allows_method(&self, method: &Method) -> bool {
+        self.retry_non_idempotent || method.is_idempotent()
+    }
+
+    /// Returns how long to wait before the next attempt, or `None` if the error is not
+    /// retryable or `attempt` (starting at 1) was the last one.
+    pub fn retry_delay(&self, error: &GoogleApiError, attempt: u32) -> Option<Duration> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+        match error {
+            GoogleApiError::RateLimited {
+                retry_after: Some(after),
+                ..
+            } if self.respect_retry_after => Some(*after),
+            GoogleApiError::RateLimited { .. } | GoogleApiError::Server { .. } => {
+                Some(self.backoff(attempt))
+            }
+            GoogleApiError::Request(e) if e.is_timeout() || e.is_connect() => {
+                Some(self.backoff(attempt))
+            }
+            _ => None,
+        }
+    }
+
+    fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(16);
+        let delay = self
+            .base_delay
+            .saturating_mul(1 << exponent)
+            .min(self.max_delay);
+        if !self.jitter {
+            return delay;
+        }
+        let half = delay / 2;
+        half + half.mul_f64(random_fraction())
+    }
+}
+
+/// Returns a pseudo random number in `[0, 1)`, good enough to spread out retries.
+fn random_fraction() -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or_default(),
+    );
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
@@ -13,3 +13,22 @@ pub mod deserialize_date_time_format {
         }
     }
 }
+
+pub mod serialize_date_time_offset_format {
+    use chrono::{DateTime, FixedOffset, SecondsFormat};
+    use serde::Serializer;
+
+    /// Writes the date-time with its own offset, or `Z` for UTC.
+    pub fn serialize<S>(
+        date: &Option<DateTime<FixedOffset>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match date {
+            Some(dt) => serializer.serialize_str(&dt.to_rfc3339_opts(SecondsFormat::AutoSi, true)),
+            None => serializer.serialize_none(),
+        }
+    }
+}
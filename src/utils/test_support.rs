@@ -0,0 +1,19 @@
+use crate::{
+    auth::client::{AccessToken, ClientCredentials, GoogleClient},
+    utils::endpoints::ApiEndpoints,
+};
+
+/// A client with a long-lived access token that sends every request to `server_url`.
+pub(crate) fn client(server_url: &str) -> GoogleClient {
+    let mut client = GoogleClient::new(
+        ClientCredentials::default(),
+        AccessToken {
+            access_token: "atok".into(),
+            expires_in: 3600,
+            ..Default::default()
+        },
+        false,
+    );
+    client.set_endpoints(ApiEndpoints::with_base_url(server_url));
+    client
+}